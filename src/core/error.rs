@@ -23,7 +23,9 @@ use std::fmt;
 pub enum HibouCoreError {
     UnknownLifeline(usize),
     UnknownMessage(usize),
-    UnknownGate(usize)
+    UnknownGate(usize),
+    UnsupportedOperator(String),
+    InvalidWitnessStep(usize,String)
 }
 
 impl fmt::Display for HibouCoreError {
@@ -37,6 +39,12 @@ impl fmt::Display for HibouCoreError {
             },
             HibouCoreError::UnknownGate( gt_id ) => {
                 return write!(f, "{}", format!("context error ; unknown gate : {:}", gt_id));
+            },
+            HibouCoreError::UnsupportedOperator( op_name ) => {
+                return write!(f, "{}", format!("unsupported operator : {:}", op_name));
+            },
+            HibouCoreError::InvalidWitnessStep( step_id, reason ) => {
+                return write!(f, "{}", format!("invalid witness step {:} : {:}", step_id, reason));
             }
         }
     }
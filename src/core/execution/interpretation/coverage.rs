@@ -0,0 +1,164 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::interpretation::interpretation::InteractionInterpretation;
+use crate::core::language::position::position::Position;
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LoopCoverage {
+    pub position : Position,
+    // number of interpretations in which the loop was instantiated zero, one or many times
+    pub zero : u32,
+    pub one : u32,
+    pub many : u32
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractionCoverage {
+    // number of interpretations which were added
+    pub interpretations_num : u32,
+    // number of interpretations in which each action was executed
+    pub actions : Vec<(Position,u32)>,
+    // number of interpretations in which the left and right branches of each alternative were taken
+    pub alts : Vec<(Position,u32,u32)>,
+    pub loops : Vec<LoopCoverage>
+}
+
+impl InteractionCoverage {
+
+    pub fn new_from_interpretation_template(template : &InteractionInterpretation) -> InteractionCoverage {
+        let actions = template.actions.iter().map(|(pos,_)| (pos.clone(),0)).collect();
+        let alts = template.alts.iter().map(|(pos,_,_)| (pos.clone(),0,0)).collect();
+        let loops = template.loops.iter().map(|(pos,_)| LoopCoverage{position:pos.clone(),zero:0,one:0,many:0}).collect();
+        return InteractionCoverage{interpretations_num:0,actions,alts,loops};
+    }
+
+    // the interpretation must be that of the same interaction
+    pub fn add_interpretation(&mut self, interpretation : &InteractionInterpretation) {
+        self.interpretations_num += 1;
+        for (act_cov,(_,num_executions)) in self.actions.iter_mut().zip(interpretation.actions.iter()) {
+            if *num_executions > 0 {
+                act_cov.1 += 1;
+            }
+        }
+        for (alt_cov,(_,num_left,num_right)) in self.alts.iter_mut().zip(interpretation.alts.iter()) {
+            if *num_left > 0 {
+                alt_cov.1 += 1;
+            }
+            if *num_right > 0 {
+                alt_cov.2 += 1;
+            }
+        }
+        for (loop_cov,(_,num_instances)) in self.loops.iter_mut().zip(interpretation.loops.iter()) {
+            match num_instances {
+                0 => {
+                    loop_cov.zero += 1;
+                },
+                1 => {
+                    loop_cov.one += 1;
+                },
+                _ => {
+                    loop_cov.many += 1;
+                }
+            }
+        }
+    }
+
+    pub fn get_covered_actions_num(&self) -> usize {
+        self.actions.iter().filter(|(_,num)| *num > 0).count()
+    }
+
+    pub fn get_covered_alt_branches_num(&self) -> usize {
+        let mut covered = 0;
+        for (_,num_left,num_right) in &self.alts {
+            if *num_left > 0 {
+                covered += 1;
+            }
+            if *num_right > 0 {
+                covered += 1;
+            }
+        }
+        return covered;
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::core::execution::interpretation::origin::relabel_interaction_actions;
+    use crate::core::execution::interpretation::replay::replay_witness;
+    use crate::core::execution::interpretation::witness::{ExecutionWitness, WitnessStep};
+    use crate::core::execution::semantics::execute::execute_interaction;
+    use crate::core::execution::semantics::frontier::global_frontier;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use super::*;
+
+    // executes the actions one after the other, choosing the first matching frontier element each time
+    fn get_witness(interaction : &Interaction, trace : &Vec<BTreeSet<TraceAction>>) -> ExecutionWitness {
+        let mut witness = ExecutionWitness::new_empty();
+        let mut current = interaction.clone();
+        for actions in trace {
+            let frt_elt = global_frontier(&current,true).into_iter()
+                .find(|frt_elt| &frt_elt.target_actions == actions).unwrap();
+            witness = witness.add_step(WitnessStep::Execute(frt_elt.position.clone(),actions.clone()));
+            current = execute_interaction(&current,&frt_elt.position,&frt_elt.target_lf_ids,false).interaction;
+        }
+        witness
+    }
+
+    #[test]
+    fn coverage_counts_test() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"seq(alt(l1 -- m1 ->|,l1 -- m2 ->|),loopS(l2 -- m1 ->|))").unwrap();
+        let emission = |lf_id : usize, ms_id : usize| -> BTreeSet<TraceAction> {btreeset!{TraceAction::new(lf_id,TraceActionKind::Emission,ms_id)}};
+        let traces = vec![vec![emission(l1,m1)],
+                          vec![emission(l1,m1),emission(l2,m1),emission(l2,m1)]];
+        // ***
+        let origins = relabel_interaction_actions(&int).unwrap();
+        let mut coverage = InteractionCoverage::new_from_interpretation_template(&InteractionInterpretation::new_from_origins(&origins));
+        for trace in &traces {
+            let replay = replay_witness(&int,&get_witness(&int,trace)).unwrap();
+            assert!(replay.is_accepting());
+            coverage.add_interpretation(&replay.interpretation);
+        }
+        assert_eq!(coverage.interpretations_num,2);
+        // "l1 -- m2 ->|" is never executed
+        assert_eq!(coverage.actions.len(),3);
+        assert_eq!(coverage.get_covered_actions_num(),2);
+        assert_eq!(coverage.actions.iter().map(|(_,num)| *num).collect::<Vec<u32>>(),vec![2,0,1]);
+        // only the left branch of the alternative is taken
+        assert_eq!(coverage.alts.len(),1);
+        assert_eq!(coverage.get_covered_alt_branches_num(),1);
+        assert_eq!((coverage.alts[0].1,coverage.alts[0].2),(2,0));
+        // the loop is instantiated zero times in the first trace and twice in the second one
+        assert_eq!(coverage.loops.len(),1);
+        assert_eq!((coverage.loops[0].zero,coverage.loops[0].one,coverage.loops[0].many),(1,0,1));
+    }
+
+}
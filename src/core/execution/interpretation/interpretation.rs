@@ -0,0 +1,152 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+
+use crate::core::execution::interpretation::origin::InteractionOrigins;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractionInterpretation {
+    // number of times each action of the original interaction has been executed
+    pub actions : Vec<(Position,u32)>,
+    // number of actions executed in the left and right branches of each alternative
+    pub alts : Vec<(Position,u32,u32)>,
+    // number of instances of each loop
+    pub loops : Vec<(Position,u32)>
+}
+
+impl InteractionInterpretation {
+
+    pub fn new_from_origins(origins : &InteractionOrigins) -> InteractionInterpretation {
+        let actions = origins.actions.iter().map(|orig| (orig.position.clone(),0)).collect();
+        let alts = origins.alts.iter().map(|pos| (pos.clone(),0,0)).collect();
+        let loops = origins.loops.iter().map(|pos| (pos.clone(),0)).collect();
+        return InteractionInterpretation{actions,alts,loops};
+    }
+
+    /**
+     "relabelled" is the current term, as obtained from the relabelled original interaction
+     and "position" is that of the step which is executed on it
+     **/
+    pub fn record_execution(&mut self,
+                            origins : &InteractionOrigins,
+                            relabelled : &Interaction,
+                            position : &Position) {
+        let mut executed : Vec<(usize,usize)> = vec![];
+        collect_executed_origins(relabelled,position,0,&mut executed);
+        // ***
+        let mut instantiated_loops : HashSet<Position> = hashset!{};
+        for (orig_id,loops_on_path) in executed {
+            let origin = origins.actions.get(orig_id).unwrap();
+            self.actions.get_mut(orig_id).unwrap().1 += 1;
+            for (alt_pos,is_left) in &origin.alt_ancestors {
+                let alt_data = self.alts.iter_mut().find(|(pos,_,_)| pos == alt_pos).unwrap();
+                if *is_left {
+                    alt_data.1 += 1;
+                } else {
+                    alt_data.2 += 1;
+                }
+            }
+            // the loops which are traversed in the current term are the innermost enclosing loops of the action
+            for loop_pos in origin.loop_ancestors.iter().take(loops_on_path) {
+                instantiated_loops.insert(loop_pos.clone());
+            }
+        }
+        for loop_pos in instantiated_loops {
+            self.loops.iter_mut().find(|(pos,_)| pos == &loop_pos).unwrap().1 += 1;
+        }
+    }
+
+    pub fn get_action_executions(&self, position : &Position) -> u32 {
+        match self.actions.iter().find(|(pos,_)| pos == position) {
+            None => {
+                0
+            },
+            Some((_,num)) => {
+                *num
+            }
+        }
+    }
+
+}
+
+// collects the ids of the origins of the executed actions and the number of loops which are traversed to reach them
+fn collect_executed_origins(relabelled : &Interaction,
+                            position : &Position,
+                            loops_on_path : usize,
+                            executed : &mut Vec<(usize,usize)>) {
+    match (position,relabelled) {
+        (Position::Epsilon(_),Interaction::Emission(ref em_act)) => {
+            executed.push( (em_act.ms_id,loops_on_path) );
+        },
+        (Position::Epsilon(_),Interaction::Reception(ref rc_act)) => {
+            executed.push( (rc_act.ms_id,loops_on_path) );
+        },
+        (Position::Left(ref sub_pos),Interaction::Loop(_,ref i1)) => {
+            collect_executed_origins(i1,sub_pos,loops_on_path+1,executed);
+        },
+        (Position::Left(ref sub_pos),_) => {
+            let (i1,_) = get_binary_sub_interactions(relabelled);
+            collect_executed_origins(i1,sub_pos,loops_on_path,executed);
+        },
+        (Position::Right(ref sub_pos),_) => {
+            let (_,i2) = get_binary_sub_interactions(relabelled);
+            collect_executed_origins(i2,sub_pos,loops_on_path,executed);
+        },
+        (Position::Both(ref sub_pos1, ref sub_pos2),_) => {
+            let (i1,i2) = get_binary_sub_interactions(relabelled);
+            collect_executed_origins(i1,sub_pos1,loops_on_path,executed);
+            collect_executed_origins(i2,sub_pos2,loops_on_path,executed);
+        },
+        _ => {
+            panic!("position {:?} does not designate an action in {:?}", position, relabelled);
+        }
+    }
+}
+
+fn get_binary_sub_interactions(interaction : &Interaction) -> (&Interaction,&Interaction) {
+    match interaction {
+        Interaction::Strict(ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::Seq(ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::CoReg(_, ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::Alt(ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::Par(ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::And(ref i1, ref i2) => {
+            (i1,i2)
+        },
+        Interaction::Sync(_, ref i1, ref i2) => {
+            (i1,i2)
+        },
+        _ => {
+            panic!("{:?} is not a binary operator", interaction);
+        }
+    }
+}
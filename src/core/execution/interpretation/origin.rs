@@ -0,0 +1,131 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::error::HibouCoreError;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ActionOrigin {
+    // position of the action in the original interaction
+    pub position : Position,
+    // message id of the action in the original interaction
+    pub ms_id : usize,
+    // positions of the enclosing alternatives and whether or not the action is in their left branch
+    pub alt_ancestors : Vec<(Position,bool)>,
+    // positions of the enclosing loops, from the innermost to the outermost
+    pub loop_ancestors : Vec<Position>
+}
+
+/**
+ The actions of the "relabelled" interaction have their message ids replaced by unique ids
+ which correspond to the index of their origin in "actions".
+ Given that positions do not depend on message ids, executing a step on the original interaction
+ and on the relabelled one yields terms with the same structure.
+ **/
+pub struct InteractionOrigins {
+    pub relabelled : Interaction,
+    pub actions : Vec<ActionOrigin>,
+    pub alts : Vec<Position>,
+    pub loops : Vec<Position>
+}
+
+pub fn relabel_interaction_actions(interaction : &Interaction) -> Result<InteractionOrigins,HibouCoreError> {
+    let mut origins = InteractionOrigins{relabelled:Interaction::Empty,actions:vec![],alts:vec![],loops:vec![]};
+    let relabelled = relabel_rec(interaction,
+                                 Position::Epsilon(None),
+                                 &vec![],
+                                 &vec![],
+                                 &mut origins)?;
+    origins.relabelled = relabelled;
+    return Ok(origins);
+}
+
+fn relabel_rec(interaction : &Interaction,
+               position : Position,
+               alt_ancestors : &Vec<(Position,bool)>,
+               loop_ancestors : &Vec<Position>,
+               origins : &mut InteractionOrigins) -> Result<Interaction,HibouCoreError> {
+    match interaction {
+        Interaction::Empty => {
+            return Ok(Interaction::Empty);
+        },
+        Interaction::Emission(ref em_act) => {
+            let mut new_act = em_act.clone();
+            new_act.ms_id = origins.actions.len();
+            origins.actions.push( ActionOrigin{position,
+                ms_id:em_act.ms_id,
+                alt_ancestors:alt_ancestors.clone(),
+                loop_ancestors:loop_ancestors.clone()} );
+            return Ok(Interaction::Emission(new_act));
+        },
+        Interaction::Reception(ref rc_act) => {
+            let mut new_act = rc_act.clone();
+            new_act.ms_id = origins.actions.len();
+            origins.actions.push( ActionOrigin{position,
+                ms_id:rc_act.ms_id,
+                alt_ancestors:alt_ancestors.clone(),
+                loop_ancestors:loop_ancestors.clone()} );
+            return Ok(Interaction::Reception(new_act));
+        },
+        Interaction::Strict(ref i1, ref i2) => {
+            let new_i1 = relabel_rec(i1,position.get_left_child(),alt_ancestors,loop_ancestors,origins)?;
+            let new_i2 = relabel_rec(i2,position.get_right_child(),alt_ancestors,loop_ancestors,origins)?;
+            return Ok(Interaction::Strict(Box::new(new_i1),Box::new(new_i2)));
+        },
+        Interaction::Seq(ref i1, ref i2) => {
+            let new_i1 = relabel_rec(i1,position.get_left_child(),alt_ancestors,loop_ancestors,origins)?;
+            let new_i2 = relabel_rec(i2,position.get_right_child(),alt_ancestors,loop_ancestors,origins)?;
+            return Ok(Interaction::Seq(Box::new(new_i1),Box::new(new_i2)));
+        },
+        Interaction::CoReg(ref cr, ref i1, ref i2) => {
+            let new_i1 = relabel_rec(i1,position.get_left_child(),alt_ancestors,loop_ancestors,origins)?;
+            let new_i2 = relabel_rec(i2,position.get_right_child(),alt_ancestors,loop_ancestors,origins)?;
+            return Ok(Interaction::CoReg(cr.clone(),Box::new(new_i1),Box::new(new_i2)));
+        },
+        Interaction::Par(ref i1, ref i2) => {
+            let new_i1 = relabel_rec(i1,position.get_left_child(),alt_ancestors,loop_ancestors,origins)?;
+            let new_i2 = relabel_rec(i2,position.get_right_child(),alt_ancestors,loop_ancestors,origins)?;
+            return Ok(Interaction::Par(Box::new(new_i1),Box::new(new_i2)));
+        },
+        Interaction::Alt(ref i1, ref i2) => {
+            origins.alts.push(position.clone());
+            let mut left_ancestors = alt_ancestors.clone();
+            left_ancestors.push( (position.clone(),true) );
+            let mut right_ancestors = alt_ancestors.clone();
+            right_ancestors.push( (position.clone(),false) );
+            let new_i1 = relabel_rec(i1,position.get_left_child(),&left_ancestors,loop_ancestors,origins)?;
+            let new_i2 = relabel_rec(i2,position.get_right_child(),&right_ancestors,loop_ancestors,origins)?;
+            return Ok(Interaction::Alt(Box::new(new_i1),Box::new(new_i2)));
+        },
+        Interaction::Loop(ref lk, ref i1) => {
+            origins.loops.push(position.clone());
+            let mut new_loop_ancestors = vec![position.clone()];
+            new_loop_ancestors.extend(loop_ancestors.iter().cloned());
+            let new_i1 = relabel_rec(i1,position.get_left_child(),alt_ancestors,&new_loop_ancestors,origins)?;
+            return Ok(Interaction::Loop(lk.clone(),Box::new(new_i1)));
+        },
+        Interaction::Sync(_,_,_) => {
+            return Err(HibouCoreError::UnsupportedOperator("sync".to_string()));
+        },
+        Interaction::And(_,_) => {
+            return Err(HibouCoreError::UnsupportedOperator("and".to_string()));
+        }
+    }
+}
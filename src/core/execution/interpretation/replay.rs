@@ -0,0 +1,86 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::error::HibouCoreError;
+use crate::core::execution::interpretation::interpretation::InteractionInterpretation;
+use crate::core::execution::interpretation::origin::relabel_interaction_actions;
+use crate::core::execution::interpretation::witness::{ExecutionWitness, WitnessStep};
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::{FrontierElement, global_frontier};
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+pub struct WitnessReplay {
+    pub final_interaction : Interaction,
+    pub interpretation : InteractionInterpretation
+}
+
+impl WitnessReplay {
+    pub fn is_accepting(&self) -> bool {
+        self.final_interaction.express_empty()
+    }
+}
+
+fn find_frontier_element(interaction : &Interaction,
+                         position : &Position) -> Option<FrontierElement> {
+    // witnesses are produced with delayed alternatives but we also accept immediate choices
+    for delayed_alt in [true,false] {
+        for frt_elt in global_frontier(interaction,delayed_alt) {
+            if &frt_elt.position == position {
+                return Some(frt_elt);
+            }
+        }
+    }
+    return None;
+}
+
+pub fn replay_witness(interaction : &Interaction,
+                      witness : &ExecutionWitness) -> Result<WitnessReplay,HibouCoreError> {
+    let origins = relabel_interaction_actions(interaction)?;
+    let mut interpretation = InteractionInterpretation::new_from_origins(&origins);
+    let mut current = interaction.clone();
+    let mut relabelled = origins.relabelled.clone();
+    for (step_id,step) in witness.steps.iter().enumerate() {
+        match step {
+            WitnessStep::Execute(ref position, ref actions) => {
+                match find_frontier_element(&current,position) {
+                    None => {
+                        return Err(HibouCoreError::InvalidWitnessStep(step_id,
+                                                                      format!("no action can be executed at position {:}", position)));
+                    },
+                    Some(frt_elt) => {
+                        if &frt_elt.target_actions != actions {
+                            return Err(HibouCoreError::InvalidWitnessStep(step_id,
+                                                                          format!("actions at position {:} differ from those of the witness", position)));
+                        }
+                        interpretation.record_execution(&origins,&relabelled,position);
+                        current = execute_interaction(&current,position,&frt_elt.target_lf_ids,false).interaction;
+                        relabelled = execute_interaction(&relabelled,position,&frt_elt.target_lf_ids,false).interaction;
+                    }
+                }
+            },
+            WitnessStep::Hide(ref lfs_to_hide) => {
+                current = current.eliminate_lifelines(lfs_to_hide);
+                relabelled = relabelled.eliminate_lifelines(lfs_to_hide);
+            }
+        }
+    }
+    return Ok(WitnessReplay{final_interaction:current,interpretation});
+}
@@ -0,0 +1,54 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::position::position::Position;
+
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum WitnessStep {
+    // execution of the actions at the given position (relative to the current interaction term)
+    Execute(Position,BTreeSet<TraceAction>),
+    // elimination of the given lifelines (ids) from the current interaction term
+    Hide(BTreeSet<usize>)
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExecutionWitness {
+    pub steps : Vec<WitnessStep>
+}
+
+impl ExecutionWitness {
+
+    pub fn new(steps : Vec<WitnessStep>) -> ExecutionWitness {
+        return ExecutionWitness{steps};
+    }
+
+    pub fn new_empty() -> ExecutionWitness {
+        return ExecutionWitness::new(vec![]);
+    }
+
+    pub fn add_step(&self, step : WitnessStep) -> ExecutionWitness {
+        let mut steps = self.steps.clone();
+        steps.push(step);
+        return ExecutionWitness::new(steps);
+    }
+
+}
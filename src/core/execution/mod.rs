@@ -15,4 +15,5 @@ limitations under the License.
 */
 
 pub mod trace;
-pub mod semantics;
\ No newline at end of file
+pub mod semantics;
+pub mod interpretation;
\ No newline at end of file
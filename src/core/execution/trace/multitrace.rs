@@ -16,8 +16,9 @@ limitations under the License.
 
 
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
+use crate::core::colocalizations::CoLocalizations;
 use crate::core::execution::trace::trace::TraceAction;
 
 
@@ -32,3 +33,20 @@ pub fn multi_trace_length(multi_trace : &MultiTrace) -> usize {
     }
     return length;
 }
+
+// distributes the actions of a global trace on the components of the co-localizations
+pub fn trace_as_multi_trace(trace : &Trace,
+                            co_localizations : &CoLocalizations) -> MultiTrace {
+    let mut multi_trace : MultiTrace = co_localizations.locs_lf_ids.iter().map(|_| vec![]).collect();
+    for multi_action in trace {
+        let mut to_add : BTreeMap<usize,BTreeSet<TraceAction>> = BTreeMap::new();
+        for action in multi_action {
+            let coloc_id = co_localizations.get_lf_coloc_id(action.lf_id).unwrap();
+            to_add.entry(coloc_id).or_insert_with(BTreeSet::new).insert(action.clone());
+        }
+        for (coloc_id,coloc_multi_action) in to_add {
+            multi_trace.get_mut(coloc_id).unwrap().push(coloc_multi_action);
+        }
+    }
+    return multi_trace;
+}
@@ -108,6 +108,10 @@ impl GeneralContext {
         return self.ms_names.len();
     }
 
+    pub fn get_gt_num(&self) -> usize {
+        return self.gt_names.len();
+    }
+
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
@@ -0,0 +1,237 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::mem::discriminant;
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum InteractionDiffItem {
+    // sub-interaction at the given position in the first interaction which has no counterpart in the second one
+    Removed(Position,Interaction),
+    // sub-interaction at the given position in the second interaction which has no counterpart in the first one
+    Inserted(Position,Interaction),
+    // sub-interaction found at different places in both interactions (first and second position)
+    Moved(Position,Position,Interaction)
+}
+
+/**
+Aligns both interaction terms from the root down.
+Identical sub-interactions are matched as a whole.
+For the same operator on both sides, the operands of its nested applications (e.g. "strict(a,b,c)")
+are aligned as sequences (so that inserting or removing an operand does not shift the others)
+and operands which are not matched are compared pairwise when they have the same root symbol.
+Finally, a removed sub-interaction which is also inserted elsewhere is reported as moved.
+**/
+pub fn diff_interactions(i1 : &Interaction,
+                         i2 : &Interaction) -> Vec<InteractionDiffItem> {
+    let mut removed = vec![];
+    let mut inserted = vec![];
+    align_interactions(i1,Position::Epsilon(None),i2,Position::Epsilon(None),&mut removed,&mut inserted);
+    // ***
+    let mut items = vec![];
+    for (pos1,sub1) in removed {
+        match inserted.iter().position(|(_,sub2)| sub2 == &sub1) {
+            None => {
+                items.push(InteractionDiffItem::Removed(pos1,sub1));
+            },
+            Some(idx) => {
+                let (pos2,_) = inserted.remove(idx);
+                items.push(InteractionDiffItem::Moved(pos1,pos2,sub1));
+            }
+        }
+    }
+    for (pos2,sub2) in inserted {
+        items.push(InteractionDiffItem::Inserted(pos2,sub2));
+    }
+    return items;
+}
+
+fn align_interactions(i1 : &Interaction,
+                      pos1 : Position,
+                      i2 : &Interaction,
+                      pos2 : Position,
+                      removed : &mut Vec<(Position,Interaction)>,
+                      inserted : &mut Vec<(Position,Interaction)>) {
+    if i1 == i2 {
+        return;
+    }
+    match (i1,i2) {
+        (Interaction::Loop(lk1,sub1),Interaction::Loop(lk2,sub2)) if lk1 == lk2 => {
+            align_interactions(sub1,pos1.get_left_child(),sub2,pos2.get_left_child(),removed,inserted);
+        },
+        _ => {
+            if is_same_operator(i1,i2) {
+                let frags1 = get_operands_with_positions(i1,i1,pos1);
+                let frags2 = get_operands_with_positions(i2,i2,pos2);
+                align_operands(frags1,frags2,removed,inserted);
+            } else {
+                removed.push((pos1,i1.clone()));
+                inserted.push((pos2,i2.clone()));
+            }
+        }
+    }
+}
+
+fn is_same_operator(i1 : &Interaction,
+                    i2 : &Interaction) -> bool {
+    match (i1,i2) {
+        (Interaction::Strict(_,_),Interaction::Strict(_,_)) => {
+            return true;
+        },
+        (Interaction::Seq(_,_),Interaction::Seq(_,_)) => {
+            return true;
+        },
+        (Interaction::Alt(_,_),Interaction::Alt(_,_)) => {
+            return true;
+        },
+        (Interaction::Par(_,_),Interaction::Par(_,_)) => {
+            return true;
+        },
+        (Interaction::And(_,_),Interaction::And(_,_)) => {
+            return true;
+        },
+        (Interaction::CoReg(cr1,_,_),Interaction::CoReg(cr2,_,_)) => {
+            return cr1 == cr2;
+        },
+        (Interaction::Sync(acts1,_,_),Interaction::Sync(acts2,_,_)) => {
+            return acts1 == acts2;
+        },
+        _ => {
+            return false;
+        }
+    }
+}
+
+// the operands of the nested applications of the operator at the root of "parent", with their positions
+fn get_operands_with_positions<'lifetime>(parent : &Interaction,
+                                          interaction : &'lifetime Interaction,
+                                          position : Position) -> Vec<(&'lifetime Interaction,Position)> {
+    if !is_same_operator(parent,interaction) {
+        return vec![(interaction,position)];
+    }
+    let (i1,i2) = match interaction {
+        Interaction::Strict(i1,i2) => (i1,i2),
+        Interaction::Seq(i1,i2) => (i1,i2),
+        Interaction::Alt(i1,i2) => (i1,i2),
+        Interaction::Par(i1,i2) => (i1,i2),
+        Interaction::And(i1,i2) => (i1,i2),
+        Interaction::CoReg(_,i1,i2) => (i1,i2),
+        Interaction::Sync(_,i1,i2) => (i1,i2),
+        _ => {
+            panic!("non-conform interaction");
+        }
+    };
+    let mut operands = get_operands_with_positions(parent,i1,position.get_left_child());
+    operands.extend(get_operands_with_positions(parent,i2,position.get_right_child()));
+    return operands;
+}
+
+fn align_operands(frags1 : Vec<(&Interaction,Position)>,
+                  frags2 : Vec<(&Interaction,Position)>,
+                  removed : &mut Vec<(Position,Interaction)>,
+                  inserted : &mut Vec<(Position,Interaction)>) {
+    let n1 = frags1.len();
+    let n2 = frags2.len();
+    // longest common subsequence of identical operands
+    let mut lcs = vec![vec![0_usize;n2+1];n1+1];
+    for x in (0..n1).rev() {
+        for y in (0..n2).rev() {
+            if frags1[x].0 == frags2[y].0 {
+                lcs[x][y] = lcs[x+1][y+1] + 1;
+            } else {
+                lcs[x][y] = lcs[x+1][y].max(lcs[x][y+1]);
+            }
+        }
+    }
+    // the operands between two matched ones are compared pairwise
+    let mut gap1 : Vec<(&Interaction,Position)> = vec![];
+    let mut gap2 : Vec<(&Interaction,Position)> = vec![];
+    let (mut x, mut y) = (0,0);
+    while x < n1 || y < n2 {
+        if x < n1 && y < n2 && frags1[x].0 == frags2[y].0 {
+            align_gap(&mut gap1,&mut gap2,removed,inserted);
+            x += 1;
+            y += 1;
+        } else if y == n2 || (x < n1 && lcs[x+1][y] >= lcs[x][y+1]) {
+            gap1.push(frags1[x].clone());
+            x += 1;
+        } else {
+            gap2.push(frags2[y].clone());
+            y += 1;
+        }
+    }
+    align_gap(&mut gap1,&mut gap2,removed,inserted);
+}
+
+fn align_gap(gap1 : &mut Vec<(&Interaction,Position)>,
+             gap2 : &mut Vec<(&Interaction,Position)>,
+             removed : &mut Vec<(Position,Interaction)>,
+             inserted : &mut Vec<(Position,Interaction)>) {
+    let mut remaining2 : Vec<(&Interaction,Position)> = gap2.drain(..).collect();
+    for (sub1,pos1) in gap1.drain(..) {
+        let same_root = remaining2.iter().position(|(sub2,_)|
+            discriminant(sub1) == discriminant(*sub2) && !matches!(sub1,Interaction::Emission(_) | Interaction::Reception(_))
+        );
+        match same_root {
+            None => {
+                removed.push((pos1,sub1.clone()));
+            },
+            Some(idx) => {
+                let (sub2,pos2) = remaining2.remove(idx);
+                align_interactions(sub1,pos1,sub2,pos2,removed,inserted);
+            }
+        }
+    }
+    for (sub2,pos2) in remaining2 {
+        inserted.push((pos2,sub2.clone()));
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use super::*;
+
+    fn emission(lf_id : usize, ms_id : usize) -> Interaction {
+        Interaction::Emission(EmissionAction::new(lf_id,ms_id,CommunicationSynchronicity::Asynchronous,vec![]))
+    }
+
+    #[test]
+    fn diff_interactions_test() {
+        let a = emission(0,0);
+        let b = emission(1,1);
+        let c = emission(0,2);
+        // strict(a,b) and strict(b,a,c)
+        let i1 = Interaction::Strict(Box::new(a.clone()),Box::new(b.clone()));
+        let i2 = Interaction::Strict(Box::new(b.clone()),
+                                     Box::new(Interaction::Strict(Box::new(a.clone()),Box::new(c.clone()))));
+        let got = diff_interactions(&i1,&i2);
+        let a_left = Position::Left(Box::new(Position::Epsilon(None)));
+        let a_right = Position::Right(Box::new(Position::Left(Box::new(Position::Epsilon(None)))));
+        let c_right = Position::Right(Box::new(Position::Right(Box::new(Position::Epsilon(None)))));
+        assert_eq!(got, vec![InteractionDiffItem::Moved(a_left,a_right,a),
+                             InteractionDiffItem::Inserted(c_right,c)]);
+        assert!(diff_interactions(&i1,&i1).is_empty());
+    }
+
+}
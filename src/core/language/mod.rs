@@ -22,6 +22,9 @@ pub mod position;
 pub mod avoid;
 pub mod prune;
 pub mod unfold;
+pub mod unroll;
+pub mod diff;
+pub mod morphism;
 //pub mod include;
 mod ord;
 
@@ -0,0 +1,121 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::morphism::morphism::{SignatureMorphable, SignatureMorphism};
+use crate::core::language::syntax::action::{EmissionAction, EmissionTargetRef, ReceptionAction};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+fn get_new_lf_id(morphism : &SignatureMorphism, lf_id : usize) -> usize {
+    return *morphism.lifelines.get(&lf_id).unwrap();
+}
+
+fn get_new_ms_id(morphism : &SignatureMorphism, ms_id : usize) -> Option<usize> {
+    return *morphism.messages.get(&ms_id).unwrap();
+}
+
+impl SignatureMorphable for Interaction {
+    fn apply_signature_morphism(&self, morphism : &SignatureMorphism) -> Interaction {
+        match self {
+            Interaction::Empty => {
+                return Interaction::Empty;
+            },
+            Interaction::Emission(em_act) => {
+                match get_new_ms_id(morphism,em_act.ms_id) {
+                    None => {
+                        return Interaction::Empty;
+                    },
+                    Some(new_ms_id) => {
+                        let targets = em_act.targets.iter().map(|tar_ref|
+                            match tar_ref {
+                                EmissionTargetRef::Lifeline(tar_lf_id) => {
+                                    EmissionTargetRef::Lifeline(get_new_lf_id(morphism,*tar_lf_id))
+                                },
+                                EmissionTargetRef::Gate(tar_gt_id) => {
+                                    EmissionTargetRef::Gate(*tar_gt_id)
+                                }
+                            }
+                        ).collect();
+                        return Interaction::Emission(EmissionAction::new(get_new_lf_id(morphism,em_act.origin_lf_id),
+                                                                         new_ms_id,
+                                                                         em_act.synchronicity.clone(),
+                                                                         targets));
+                    }
+                }
+            },
+            Interaction::Reception(rc_act) => {
+                match get_new_ms_id(morphism,rc_act.ms_id) {
+                    None => {
+                        return Interaction::Empty;
+                    },
+                    Some(new_ms_id) => {
+                        let recipients = rc_act.recipients.iter().map(|lf_id| get_new_lf_id(morphism,*lf_id)).collect();
+                        return Interaction::Reception(ReceptionAction::new(rc_act.origin_gt_id.clone(),
+                                                                           new_ms_id,
+                                                                           rc_act.synchronicity.clone(),
+                                                                           recipients));
+                    }
+                }
+            },
+            Interaction::Strict(i1, i2) => {
+                return Interaction::Strict(Box::new(i1.apply_signature_morphism(morphism)),
+                                           Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::Seq(i1, i2) => {
+                return Interaction::Seq(Box::new(i1.apply_signature_morphism(morphism)),
+                                        Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::CoReg(cr, i1, i2) => {
+                let new_cr = cr.iter().map(|lf_id| get_new_lf_id(morphism,*lf_id)).collect();
+                return Interaction::CoReg(new_cr,
+                                          Box::new(i1.apply_signature_morphism(morphism)),
+                                          Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::Alt(i1, i2) => {
+                return Interaction::Alt(Box::new(i1.apply_signature_morphism(morphism)),
+                                        Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::Par(i1, i2) => {
+                return Interaction::Par(Box::new(i1.apply_signature_morphism(morphism)),
+                                        Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::Loop(lkind, i1) => {
+                return Interaction::Loop(lkind.clone(),Box::new(i1.apply_signature_morphism(morphism)));
+            },
+            Interaction::And(i1, i2) => {
+                return Interaction::And(Box::new(i1.apply_signature_morphism(morphism)),
+                                        Box::new(i2.apply_signature_morphism(morphism)));
+            },
+            Interaction::Sync(sync_acts, i1, i2) => {
+                // the hidden actions no longer need to be synchronized
+                let mut new_sync_acts = vec![];
+                for sync_act in sync_acts {
+                    if let Some(new_ms_id) = get_new_ms_id(morphism,sync_act.ms_id) {
+                        new_sync_acts.push(TraceAction::new(get_new_lf_id(morphism,sync_act.lf_id),
+                                                            sync_act.act_kind.clone(),
+                                                            new_ms_id));
+                    }
+                }
+                return Interaction::Sync(new_sync_acts,
+                                         Box::new(i1.apply_signature_morphism(morphism)),
+                                         Box::new(i2.apply_signature_morphism(morphism)));
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashMap;
+
+
+/**
+Maps the lifelines and messages of a signature to those of another one (the gates are left unchanged).
+A message mapped to None is hidden, i.e. the actions carrying it are removed.
+**/
+pub struct SignatureMorphism {
+    pub lifelines : HashMap<usize,usize>,
+    pub messages : HashMap<usize,Option<usize>>
+}
+
+impl SignatureMorphism {
+    pub fn new(lifelines : HashMap<usize,usize>,
+               messages : HashMap<usize,Option<usize>>) -> SignatureMorphism {
+        return SignatureMorphism{lifelines,messages};
+    }
+}
+
+pub trait SignatureMorphable : Sized {
+    // all the lifelines and messages involved must be mapped
+    fn apply_signature_morphism(&self, morphism : &SignatureMorphism) -> Self;
+}
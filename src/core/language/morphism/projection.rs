@@ -0,0 +1,106 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::language::morphism::morphism::{SignatureMorphable, SignatureMorphism};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+Returns the view of the interaction restricted to the lifelines which are not removed,
+along with the signature of this view.
+Each message given in "messages" is either hidden (None) or renamed, several messages renamed alike being merged.
+The other messages, the lifelines and the gates keep their names.
+The result may contain empty interactions left by the removed actions and should be simplified.
+**/
+pub fn project_interaction(gen_ctx : &GeneralContext,
+                           interaction : &Interaction,
+                           lfs_to_remove : &BTreeSet<usize>,
+                           messages : &HashMap<usize,Option<String>>) -> (GeneralContext,Interaction) {
+    let mut new_ctx = GeneralContext::new();
+    let mut lf_map = HashMap::new();
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        if !lfs_to_remove.contains(&lf_id) {
+            let new_lf_id = new_ctx.add_lf(gen_ctx.get_lf_name(lf_id).unwrap());
+            lf_map.insert(lf_id,new_lf_id);
+        }
+    }
+    let mut ms_map = HashMap::new();
+    for ms_id in 0..gen_ctx.get_ms_num() {
+        match messages.get(&ms_id) {
+            None => {
+                let new_ms_id = new_ctx.add_msg(gen_ctx.get_ms_name(ms_id).unwrap());
+                ms_map.insert(ms_id,Some(new_ms_id));
+            },
+            Some(None) => {
+                ms_map.insert(ms_id,None);
+            },
+            Some(Some(new_name)) => {
+                let new_ms_id = new_ctx.add_msg(new_name.clone());
+                ms_map.insert(ms_id,Some(new_ms_id));
+            }
+        }
+    }
+    for gt_id in 0..gen_ctx.get_gt_num() {
+        new_ctx.add_gt(gen_ctx.get_gt_name(gt_id).unwrap());
+    }
+    // ***
+    let morphism = SignatureMorphism::new(lf_map,ms_map);
+    let projected = interaction.eliminate_lifelines(lfs_to_remove).apply_signature_morphism(&morphism);
+    return (new_ctx,projected);
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef};
+    use super::*;
+
+    #[test]
+    fn project_interaction_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        // strict(l1 -- m1 -> l3, l2 -- m2 -> l3)
+        let int = Interaction::Strict(
+            Box::new(Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![EmissionTargetRef::Lifeline(2)]))),
+            Box::new(Interaction::Emission(EmissionAction::new(1,1,CommunicationSynchronicity::Asynchronous,vec![EmissionTargetRef::Lifeline(2)])))
+        );
+        // removing l3, hiding m1 and renaming m2 into n
+        let (new_ctx,projected) = project_interaction(&gen_ctx,
+                                                      &int,
+                                                      &btreeset!{2},
+                                                      &hashmap!{0 => None, 1 => Some("n".to_string())});
+        assert_eq!(new_ctx.get_lf_num(), 2);
+        assert_eq!(new_ctx.get_ms_num(), 1);
+        assert_eq!(new_ctx.get_ms_name(0).unwrap(), "n".to_string());
+        let expected = Interaction::Strict(
+            Box::new(Interaction::Empty),
+            Box::new(Interaction::Emission(EmissionAction::new(1,0,CommunicationSynchronicity::Asynchronous,vec![])))
+        );
+        assert_eq!(projected, expected);
+    }
+
+}
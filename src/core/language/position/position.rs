@@ -80,6 +80,40 @@ impl fmt::Display for Position {
 }
 
 
+impl Position {
 
+    pub fn get_left_child(&self) -> Position {
+        match self {
+            Position::Epsilon(None) => {
+                Position::Left(Box::new(Position::Epsilon(None)))
+            },
+            Position::Left(ref in_self) => {
+                Position::Left(Box::new(in_self.get_left_child()))
+            },
+            Position::Right(ref in_self) => {
+                Position::Right(Box::new(in_self.get_left_child()))
+            },
+            _ => {
+                panic!("cannot extend position {:?}", self);
+            }
+        }
+    }
 
+    pub fn get_right_child(&self) -> Position {
+        match self {
+            Position::Epsilon(None) => {
+                Position::Right(Box::new(Position::Epsilon(None)))
+            },
+            Position::Left(ref in_self) => {
+                Position::Left(Box::new(in_self.get_right_child()))
+            },
+            Position::Right(ref in_self) => {
+                Position::Right(Box::new(in_self.get_right_child()))
+            },
+            _ => {
+                panic!("cannot extend position {:?}", self);
+            }
+        }
+    }
 
+}
@@ -123,6 +123,51 @@ impl Interaction {
         }
     }
 
+    // lower bound on the number of execution steps required to reach a term that expresses the empty trace
+    pub fn min_steps_to_express_empty(&self) -> u32 {
+        match *self {
+            Interaction::Empty => {
+                0
+            },
+            Interaction::Emission(_) => {
+                1
+            },
+            Interaction::Reception(ref rc_act) => {
+                if rc_act.recipients.len() == 0 {
+                    0
+                } else {
+                    1
+                }
+            },
+            Interaction::Strict(ref i1, ref i2) => {
+                i1.min_steps_to_express_empty() + i2.min_steps_to_express_empty()
+            },
+            Interaction::Seq(ref i1, ref i2) => {
+                i1.min_steps_to_express_empty() + i2.min_steps_to_express_empty()
+            },
+            Interaction::CoReg(_, ref i1, ref i2) => {
+                i1.min_steps_to_express_empty() + i2.min_steps_to_express_empty()
+            },
+            Interaction::Par(ref i1, ref i2) => {
+                i1.min_steps_to_express_empty() + i2.min_steps_to_express_empty()
+            },
+            Interaction::Alt(ref i1, ref i2) => {
+                i1.min_steps_to_express_empty().min(i2.min_steps_to_express_empty())
+            },
+            Interaction::Loop(_, _) => {
+                0
+            },
+            Interaction::Sync(_,ref i1, ref i2) => {
+                // synchronized actions are executed in a single step
+                i1.min_steps_to_express_empty().max(i2.min_steps_to_express_empty())
+            },
+            Interaction::And(ref i1, ref i2) => {
+                // actions of both sides may be merged through gates and executed in a single step
+                i1.min_steps_to_express_empty().max(i2.min_steps_to_express_empty())
+            }
+        }
+    }
+
     pub fn contained_model_actions(&self) -> (HashSet<&EmissionAction>,HashSet<&ReceptionAction>) {
         match &self {
             &Interaction::Empty => {
@@ -0,0 +1,190 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::core::language::unroll::unrollable::LoopUnrollable;
+
+
+/**
+"loop_S" iterations are composed with "strict", "loop_W" and "loop_H" ones with "seq" and "loop_P" ones with "par".
+Hence the unrolled interaction accepts exactly the traces using at most "num_iterations" iterations per loop instance,
+except for "loop_H" for which the constraint that each iteration begins before the next one is lost.
+**/
+fn compose_iterations(lkind : &LoopKind,
+                      i1 : Interaction,
+                      i2 : Interaction) -> Interaction {
+    match lkind {
+        LoopKind::SStrictSeq => {
+            return Interaction::Strict(Box::new(i1),Box::new(i2));
+        },
+        LoopKind::HHeadFirstWS => {
+            return Interaction::Seq(Box::new(i1),Box::new(i2));
+        },
+        LoopKind::WWeakSeq => {
+            return Interaction::Seq(Box::new(i1),Box::new(i2));
+        },
+        LoopKind::PInterleaving => {
+            return Interaction::Par(Box::new(i1),Box::new(i2));
+        }
+    }
+}
+
+impl LoopUnrollable for Interaction {
+    fn unroll_loops(&self, num_iterations : u32) -> Interaction {
+        match self {
+            Interaction::Empty => {
+                return Interaction::Empty;
+            },
+            Interaction::Emission(_) => {
+                return self.clone();
+            },
+            Interaction::Reception(_) => {
+                return self.clone();
+            },
+            Interaction::Strict(i1, i2) => {
+                return Interaction::Strict(Box::new(i1.unroll_loops(num_iterations)),
+                                           Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::Seq(i1, i2) => {
+                return Interaction::Seq(Box::new(i1.unroll_loops(num_iterations)),
+                                        Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::CoReg(cr, i1, i2) => {
+                return Interaction::CoReg(cr.clone(),
+                                          Box::new(i1.unroll_loops(num_iterations)),
+                                          Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::Alt(i1, i2) => {
+                return Interaction::Alt(Box::new(i1.unroll_loops(num_iterations)),
+                                        Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::Par(i1, i2) => {
+                return Interaction::Par(Box::new(i1.unroll_loops(num_iterations)),
+                                        Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::And(i1, i2) => {
+                return Interaction::And(Box::new(i1.unroll_loops(num_iterations)),
+                                        Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::Sync(sync_acts, i1, i2) => {
+                return Interaction::Sync(sync_acts.clone(),
+                                         Box::new(i1.unroll_loops(num_iterations)),
+                                         Box::new(i2.unroll_loops(num_iterations)));
+            },
+            Interaction::Loop(lkind, i1) => {
+                let unrolled_i1 = i1.unroll_loops(num_iterations);
+                if num_iterations == 0 || unrolled_i1 == Interaction::Empty {
+                    return Interaction::Empty;
+                }
+                // at most one iteration : alt(o,i1)
+                let mut unrolled = Interaction::Alt(Box::new(Interaction::Empty),
+                                                    Box::new(unrolled_i1.clone()));
+                // at most n+1 iterations : alt(o,i1 op (at most n iterations))
+                for _ in 1..num_iterations {
+                    unrolled = Interaction::Alt(Box::new(Interaction::Empty),
+                                                Box::new(compose_iterations(lkind,unrolled_i1.clone(),unrolled)));
+                }
+                return unrolled;
+            }
+        }
+    }
+
+    fn unrolling_over_approximates(&self, num_iterations : u32) -> bool {
+        if num_iterations < 2 {
+            return false;
+        }
+        match self {
+            Interaction::Empty => {
+                return false;
+            },
+            Interaction::Emission(_) => {
+                return false;
+            },
+            Interaction::Reception(_) => {
+                return false;
+            },
+            Interaction::Strict(i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::Seq(i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::CoReg(_, i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::Alt(i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::Par(i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::And(i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::Sync(_, i1, i2) => {
+                return i1.unrolling_over_approximates(num_iterations) || i2.unrolling_over_approximates(num_iterations);
+            },
+            Interaction::Loop(lkind, i1) => {
+                match lkind {
+                    LoopKind::HHeadFirstWS => {
+                        return true;
+                    },
+                    _ => {
+                        return i1.unrolling_over_approximates(num_iterations);
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use super::*;
+
+    #[test]
+    fn unroll_strict_loop_test() {
+        let act = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![]));
+        let int = Interaction::Loop(LoopKind::SStrictSeq,Box::new(act.clone()));
+        assert_eq!(int.unroll_loops(0), Interaction::Empty);
+        // alt(o,strict(a,alt(o,a)))
+        let expected = Interaction::Alt(
+            Box::new(Interaction::Empty),
+            Box::new(Interaction::Strict(
+                Box::new(act.clone()),
+                Box::new(Interaction::Alt(Box::new(Interaction::Empty),Box::new(act.clone())))
+            ))
+        );
+        assert_eq!(int.unroll_loops(2), expected);
+        assert!(!int.unrolling_over_approximates(2));
+    }
+
+    #[test]
+    fn unroll_head_first_loop_test() {
+        let act = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![]));
+        let int = Interaction::Seq(Box::new(act.clone()),
+                                   Box::new(Interaction::Loop(LoopKind::HHeadFirstWS,Box::new(act.clone()))));
+        // a single iteration is exact
+        assert!(!int.unrolling_over_approximates(1));
+        assert!(int.unrolling_over_approximates(2));
+    }
+
+}
@@ -0,0 +1,31 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+pub trait LoopUnrollable : Sized {
+    /**
+    Replaces each loop with the alternative between at most "num_iterations" iterations of its (unrolled) content,
+    these iterations being composed according to the kind of the loop.
+    **/
+    fn unroll_loops(&self, num_iterations : u32) -> Self;
+
+    /**
+    Whether the unrolled term may accept more traces than those using at most "num_iterations" iterations per loop instance
+    i.e. whether a "loop_H" is unrolled more than once, the constraint that each of its iterations begins before the next one being lost.
+    **/
+    fn unrolling_over_approximates(&self, num_iterations : u32) -> bool;
+}
@@ -141,7 +141,7 @@ fn get_all_transformations_inner(transfos : &Vec<InteractionTransformationKind>,
 
     let mut results : Vec<InteractionTransformationResult> = Vec::new();
     for transfo_kind in transfos {
-        let new_transfos : Vec<InteractionTransformationResult> = transfo_kind.get_transformation()(interaction)
+        let new_transfos : Vec<InteractionTransformationResult> = transfo_kind.apply(interaction)
             .into_iter().map(|x| InteractionTransformationResult::new((*transfo_kind).clone(),Position::Epsilon(None),x)).collect();
         results.extend(new_transfos);
     }
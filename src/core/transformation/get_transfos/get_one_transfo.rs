@@ -176,7 +176,7 @@ pub fn get_one_transformation_rec(transfos : &Vec<InteractionTransformationKind>
 fn get_one_transformation_inner(transfos : &Vec<InteractionTransformationKind>,
                                 interaction : &Interaction) -> Option<InteractionTransformationResult> {
     for transfo_kind in transfos {
-        let mut new_transfos : Vec<InteractionTransformationResult> = transfo_kind.get_transformation()(interaction)
+        let mut new_transfos : Vec<InteractionTransformationResult> = transfo_kind.apply(interaction)
             .into_iter().map(|x| InteractionTransformationResult::new((*transfo_kind).clone(),Position::Epsilon(None),x)).collect();
         if new_transfos.len() > 0 {
             return Some(new_transfos.remove(0));
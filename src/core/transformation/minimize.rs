@@ -0,0 +1,178 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::language::syntax::metrics::InteractionMetrics;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::core::transformation::transfophase::InteractionTransformationPhase;
+
+
+pub static DEFAULT_MINIMIZE_MAX_NODES : u32 = 10000;
+
+pub struct InteractionMinimizationResult {
+    pub minimal : Interaction,
+    pub initial_size : u32,
+    pub minimal_size : u32,
+    pub node_count : u32,
+    // false if the search was stopped because of the maximum number of nodes
+    pub is_complete : bool
+}
+
+pub fn get_interaction_size(interaction : &Interaction) -> u32 {
+    return InteractionMetrics::extract_from_interaction(interaction).get_num_symbols();
+}
+
+/**
+The transformations which preserve the traces and may reduce the size of an interaction
+(including the simplification and unnesting of loops),
+or enable further reductions (defactorizations, flushes and inversions).
+**/
+fn get_minimization_phase() -> InteractionTransformationPhase {
+    return InteractionTransformationPhase::new(
+        vec![InteractionTransformationKind::Simpl,
+             InteractionTransformationKind::FlushRight,
+             InteractionTransformationKind::InvertAlt,
+             InteractionTransformationKind::InvertPar,
+             InteractionTransformationKind::Deduplicate,
+             InteractionTransformationKind::FactorizePrefixStrict,
+             InteractionTransformationKind::FactorizePrefixSeq,
+             InteractionTransformationKind::FactorizeCommutativePar,
+             InteractionTransformationKind::FactorizeSuffixStrict,
+             InteractionTransformationKind::FactorizeSuffixSeq,
+             InteractionTransformationKind::DeFactorizeLeft,
+             InteractionTransformationKind::DeFactorizeRight,
+             InteractionTransformationKind::LoopSimpl,
+             InteractionTransformationKind::LoopUnNest],
+        true,
+        false);
+}
+
+/**
+Best-first search for the smallest interaction (in number of symbols) which can be reached from the initial one.
+The interactions are explored in increasing order of size, ties being broken in the order in which they were found,
+until "max_nodes" distinct interactions have been reached.
+**/
+pub fn minimize_interaction(interaction : &Interaction,
+                            max_nodes : u32) -> InteractionMinimizationResult {
+    let phase = get_minimization_phase();
+    let initial_size = get_interaction_size(interaction);
+    // ***
+    let mut reached : Vec<Interaction> = vec![interaction.clone()];
+    let mut known : HashSet<Interaction> = hashset!{interaction.clone()};
+    // size and index in "reached" of the interactions to explore
+    let mut queue : BinaryHeap<Reverse<(u32,usize)>> = BinaryHeap::new();
+    queue.push(Reverse((initial_size,0)));
+    let mut minimal_id = 0;
+    let mut minimal_size = initial_size;
+    let mut is_complete = true;
+    // ***
+    'search : while let Some(Reverse((size,parent_id))) = queue.pop() {
+        if size < minimal_size {
+            minimal_size = size;
+            minimal_id = parent_id;
+        }
+        for transformed in phase.apply_phase(reached.get(parent_id).unwrap()) {
+            if known.contains(&transformed.result) {
+                continue;
+            }
+            if known.len() as u32 >= max_nodes {
+                is_complete = false;
+                break 'search;
+            }
+            known.insert(transformed.result.clone());
+            queue.push(Reverse((get_interaction_size(&transformed.result),reached.len())));
+            reached.push(transformed.result);
+        }
+    }
+    // the interactions remaining in the queue may be smaller than the last one explored
+    for Reverse((size,id)) in queue {
+        if size < minimal_size || (size == minimal_size && id < minimal_id) {
+            minimal_size = size;
+            minimal_id = id;
+        }
+    }
+    return InteractionMinimizationResult{minimal:reached.swap_remove(minimal_id),
+        initial_size,
+        minimal_size,
+        node_count:known.len() as u32,
+        is_complete};
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+    use crate::nfa_translation::compare::interactions_accept_same_traces;
+    use super::*;
+
+    fn emission(lf_id : usize, ms_id : usize) -> Interaction {
+        Interaction::Emission(EmissionAction::new(lf_id,ms_id,CommunicationSynchronicity::Asynchronous,vec![]))
+    }
+
+    #[test]
+    fn minimize_factorizes_prefix_test() {
+        let a = emission(0,0);
+        let b = emission(1,1);
+        let c = emission(1,2);
+        // alt(strict(a,b),strict(a,c)) -> strict(a,alt(b,c))
+        let int = Interaction::Alt(Box::new(Interaction::Strict(Box::new(a.clone()),Box::new(b.clone()))),
+                                   Box::new(Interaction::Strict(Box::new(a.clone()),Box::new(c.clone()))));
+        let result = minimize_interaction(&int,DEFAULT_MINIMIZE_MAX_NODES);
+        assert!(result.is_complete);
+        assert_eq!(result.initial_size, 7);
+        assert_eq!(result.minimal_size, 5);
+        assert_eq!(result.minimal_size, get_interaction_size(&result.minimal));
+    }
+
+    #[test]
+    fn minimize_redundant_loop_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let int = interaction_from_text(&gen_ctx,"alt(loopS(loopS(l1 -- m1 ->|)),o)").unwrap();
+        let expected = interaction_from_text(&gen_ctx,"loopS(l1 -- m1 ->|)").unwrap();
+        let result = minimize_interaction(&int,DEFAULT_MINIMIZE_MAX_NODES);
+        assert!(result.is_complete);
+        assert_eq!(result.initial_size, 5);
+        assert_eq!(result.minimal_size, 2);
+        assert_eq!(result.minimal, expected);
+    }
+
+    #[test]
+    fn minimized_interaction_accepts_same_traces_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"seq(alt(seq(l1 -- m1 -> l2,l2 -- m2 ->|),seq(l1 -- m1 -> l2,l1 -- m2 ->|)),alt(loopS(loopS(l2 -- m1 ->|)),o))").unwrap();
+        let result = minimize_interaction(&int,DEFAULT_MINIMIZE_MAX_NODES);
+        assert!(result.minimal_size < result.initial_size);
+        // the interaction as written into the ".hif" file
+        let written = interaction_from_text(&gen_ctx,&interaction_as_hif_encoding(&gen_ctx,&result.minimal)).unwrap();
+        assert!(interactions_accept_same_traces(&gen_ctx,&int,&written));
+    }
+
+}
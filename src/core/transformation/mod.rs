@@ -19,6 +19,10 @@ pub mod transfokind;
 pub mod transfofunc;
 pub mod transfores;
 mod get_transfos;
+mod normalize;
 
 pub mod transfophase;
+pub mod rewrite;
+pub mod proof;
+pub mod minimize;
 
@@ -0,0 +1,77 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+
+
+/**
+Applies the transformations bottom-up until none can be applied anywhere.
+The sub-interactions are normalized first and then the first transformation (in the order given)
+which applies at the root is applied, the result being normalized in turn.
+Unlike the canonization process, no graph of intermediate interactions is built and explored.
+**/
+pub fn normalize_bottom_up(transfos : &Vec<InteractionTransformationKind>,
+                           interaction : &Interaction) -> Interaction {
+    let with_normalized_subs = match interaction {
+        &Interaction::Empty | &Interaction::Emission(_) | &Interaction::Reception(_) => {
+            interaction.clone()
+        },
+        &Interaction::Strict(ref i1, ref i2) => {
+            Interaction::Strict(Box::new(normalize_bottom_up(transfos,i1)),
+                                Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::Seq(ref i1, ref i2) => {
+            Interaction::Seq(Box::new(normalize_bottom_up(transfos,i1)),
+                             Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::CoReg(ref cr, ref i1, ref i2) => {
+            Interaction::CoReg(cr.clone(),
+                               Box::new(normalize_bottom_up(transfos,i1)),
+                               Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::Alt(ref i1, ref i2) => {
+            Interaction::Alt(Box::new(normalize_bottom_up(transfos,i1)),
+                             Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::Par(ref i1, ref i2) => {
+            Interaction::Par(Box::new(normalize_bottom_up(transfos,i1)),
+                             Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::Loop(ref lk, ref i1) => {
+            Interaction::Loop(lk.clone(), Box::new(normalize_bottom_up(transfos,i1)))
+        },
+        &Interaction::And(ref i1, ref i2) => {
+            Interaction::And(Box::new(normalize_bottom_up(transfos,i1)),
+                             Box::new(normalize_bottom_up(transfos,i2)))
+        },
+        &Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+            Interaction::Sync(sync_acts.clone(),
+                              Box::new(normalize_bottom_up(transfos,i1)),
+                              Box::new(normalize_bottom_up(transfos,i2)))
+        }
+    };
+    for transfo_kind in transfos {
+        let mut got = transfo_kind.apply(&with_normalized_subs);
+        if !got.is_empty() {
+            // the transformation may yield sub-interactions which are not in normal form
+            return normalize_bottom_up(transfos,&got.remove(0));
+        }
+    }
+    return with_normalized_subs;
+}
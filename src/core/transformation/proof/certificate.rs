@@ -0,0 +1,108 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::proof::derivation::InteractionDerivation;
+use crate::core::transformation::proof::error::InteractionProofError;
+
+
+/**
+Derivations from several interactions to the same normal form,
+which certify that these interactions are equivalent.
+With a single derivation, it is the proof log of a canonization.
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InteractionEquivalenceCertificate {
+    pub derivations : Vec<InteractionDerivation>
+}
+
+impl InteractionEquivalenceCertificate {
+
+    pub fn new(derivations : Vec<InteractionDerivation>) -> InteractionEquivalenceCertificate {
+        return InteractionEquivalenceCertificate{derivations};
+    }
+
+    /**
+    Returns the common normal form if all the derivations are valid.
+    **/
+    pub fn check(&self) -> Result<&Interaction,InteractionProofError> {
+        let normal_form = match self.derivations.first() {
+            None => {
+                return Err( InteractionProofError::NoDerivation );
+            },
+            Some(first) => {
+                first.get_final()
+            }
+        };
+        for (x,derivation) in self.derivations.iter().enumerate() {
+            derivation.check(x+1)?;
+            if derivation.get_final() != normal_form {
+                return Err( InteractionProofError::DifferentNormalForms(x+1) );
+            }
+        }
+        return Ok(normal_form);
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::position::position::Position;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use crate::core::transformation::proof::derivation::InteractionDerivationStep;
+    use crate::core::transformation::transfokind::InteractionTransformationKind;
+    use super::*;
+
+    fn emission(lf_id : usize, ms_id : usize) -> Interaction {
+        Interaction::Emission(EmissionAction::new(lf_id,ms_id,CommunicationSynchronicity::Asynchronous,vec![]))
+    }
+
+    #[test]
+    fn equivalence_certificate_check_test() {
+        let act = emission(0,0);
+        // strict(a,seq(o,a)) -> strict(a,a)
+        let int1 = Interaction::Strict(Box::new(act.clone()),
+                                       Box::new(Interaction::Seq(Box::new(Interaction::Empty),Box::new(act.clone()))));
+        let int1_nf = Interaction::Strict(Box::new(act.clone()),Box::new(act.clone()));
+        let deriv1 = InteractionDerivation::new(int1.clone(),
+                                                vec![InteractionDerivationStep::new(InteractionTransformationKind::Simpl,
+                                                                                    Position::Right(Box::new(Position::Epsilon(None))),
+                                                                                    int1.clone(),
+                                                                                    int1_nf.clone())]);
+        // strict(o,strict(a,a)) -> strict(a,a)
+        let int2 = Interaction::Strict(Box::new(Interaction::Empty),Box::new(int1_nf.clone()));
+        let deriv2 = InteractionDerivation::new(int2.clone(),
+                                                vec![InteractionDerivationStep::new(InteractionTransformationKind::Simpl,
+                                                                                    Position::Epsilon(None),
+                                                                                    int2.clone(),
+                                                                                    int1_nf.clone())]);
+        let certificate = InteractionEquivalenceCertificate::new(vec![deriv1.clone(),deriv2]);
+        assert_eq!(certificate.check(), Ok(&int1_nf));
+        // the same step at a position where it does not apply
+        let wrong_deriv = InteractionDerivation::new(int1.clone(),
+                                                     vec![InteractionDerivationStep::new(InteractionTransformationKind::Simpl,
+                                                                                         Position::Epsilon(None),
+                                                                                         int1.clone(),
+                                                                                         int1_nf.clone())]);
+        let wrong_certificate = InteractionEquivalenceCertificate::new(vec![deriv1,wrong_deriv]);
+        assert_eq!(wrong_certificate.check(),
+                   Err(InteractionProofError::InvalidStep(2,1,InteractionTransformationKind::Simpl,Position::Epsilon(None))));
+    }
+
+}
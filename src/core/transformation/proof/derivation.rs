@@ -0,0 +1,100 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::proof::error::InteractionProofError;
+use crate::core::transformation::proof::position::{get_sub_interaction_at, replace_sub_interaction_at};
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct InteractionDerivationStep {
+    pub kind : InteractionTransformationKind,
+    pub position : Position,
+    pub before : Interaction,
+    pub after : Interaction
+}
+
+impl InteractionDerivationStep {
+    pub fn new(kind : InteractionTransformationKind,
+               position : Position,
+               before : Interaction,
+               after : Interaction) -> InteractionDerivationStep {
+        return InteractionDerivationStep{kind,position,before,after};
+    }
+}
+
+/**
+The chain of transformations rewriting an interaction into another one (e.g. its normal form).
+**/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InteractionDerivation {
+    pub initial : Interaction,
+    pub steps : Vec<InteractionDerivationStep>
+}
+
+impl InteractionDerivation {
+
+    pub fn new(initial : Interaction,
+               steps : Vec<InteractionDerivationStep>) -> InteractionDerivation {
+        return InteractionDerivation{initial,steps};
+    }
+
+    pub fn get_final(&self) -> &Interaction {
+        match self.steps.last() {
+            None => {
+                &self.initial
+            },
+            Some(step) => {
+                &step.after
+            }
+        }
+    }
+
+    /**
+    Re-verifies each step independently of the process which produced the derivation.
+    "deriv_id" only serves to designate the derivation in errors.
+    **/
+    pub fn check(&self, deriv_id : usize) -> Result<(),InteractionProofError> {
+        let mut current = &self.initial;
+        for (x,step) in self.steps.iter().enumerate() {
+            if &step.before != current {
+                return Err( InteractionProofError::BrokenChain(deriv_id,x+1) );
+            }
+            let sub_int = match get_sub_interaction_at(&step.before,&step.position) {
+                None => {
+                    return Err( InteractionProofError::InvalidPosition(deriv_id,x+1,step.position.clone()) );
+                },
+                Some(got) => {
+                    got
+                }
+            };
+            let is_valid = step.kind.apply(sub_int).into_iter()
+                .any(|new_sub_int|
+                    replace_sub_interaction_at(&step.before,&step.position,new_sub_int).as_ref() == Some(&step.after)
+                );
+            if !is_valid {
+                return Err( InteractionProofError::InvalidStep(deriv_id,x+1,step.kind.clone(),step.position.clone()) );
+            }
+            current = &step.after;
+        }
+        return Ok(());
+    }
+
+}
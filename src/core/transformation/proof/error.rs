@@ -0,0 +1,57 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+use crate::core::language::position::position::Position;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum InteractionProofError {
+    // the step (numbered from 1) of a derivation does not start from the interaction its previous step ends with
+    BrokenChain(usize,usize),
+    // the step does not designate a sub-interaction
+    InvalidPosition(usize,usize,Position),
+    // the step cannot be obtained by applying the transformation at the position
+    InvalidStep(usize,usize,InteractionTransformationKind,Position),
+    // the derivation ends with a normal form different from that of the first derivation
+    DifferentNormalForms(usize),
+    NoDerivation
+}
+
+impl fmt::Display for InteractionProofError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InteractionProofError::BrokenChain(deriv, step) => {
+                write!(f, "derivation {:} step {:} does not start from the interaction obtained at the previous step", deriv, step)
+            },
+            InteractionProofError::InvalidPosition(deriv, step, pos) => {
+                write!(f, "derivation {:} step {:} refers to position {:?} which is not that of a sub-interaction", deriv, step, pos)
+            },
+            InteractionProofError::InvalidStep(deriv, step, kind, pos) => {
+                write!(f, "derivation {:} step {:} cannot be obtained by applying {:} at position {:?}", deriv, step, kind, pos)
+            },
+            InteractionProofError::DifferentNormalForms(deriv) => {
+                write!(f, "derivation {:} does not end with the same interaction as derivation 1", deriv)
+            },
+            InteractionProofError::NoDerivation => {
+                write!(f, "no derivation")
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+The sub-interaction at a position at which a transformation may have been applied.
+Positions of executed actions (Both and sub-positions of actions) are not pertinent here.
+**/
+pub fn get_sub_interaction_at<'a>(interaction : &'a Interaction,
+                                 position : &Position) -> Option<&'a Interaction> {
+    match (position,interaction) {
+        (Position::Epsilon(None),_) => {
+            Some(interaction)
+        },
+        (Position::Left(sub_pos),Interaction::Strict(i1,_))
+        | (Position::Left(sub_pos),Interaction::Seq(i1,_))
+        | (Position::Left(sub_pos),Interaction::CoReg(_,i1,_))
+        | (Position::Left(sub_pos),Interaction::Alt(i1,_))
+        | (Position::Left(sub_pos),Interaction::Par(i1,_))
+        | (Position::Left(sub_pos),Interaction::And(i1,_))
+        | (Position::Left(sub_pos),Interaction::Sync(_,i1,_))
+        | (Position::Left(sub_pos),Interaction::Loop(_,i1)) => {
+            get_sub_interaction_at(i1,sub_pos)
+        },
+        (Position::Right(sub_pos),Interaction::Strict(_,i2))
+        | (Position::Right(sub_pos),Interaction::Seq(_,i2))
+        | (Position::Right(sub_pos),Interaction::CoReg(_,_,i2))
+        | (Position::Right(sub_pos),Interaction::Alt(_,i2))
+        | (Position::Right(sub_pos),Interaction::Par(_,i2))
+        | (Position::Right(sub_pos),Interaction::And(_,i2))
+        | (Position::Right(sub_pos),Interaction::Sync(_,_,i2)) => {
+            get_sub_interaction_at(i2,sub_pos)
+        },
+        _ => {
+            None
+        }
+    }
+}
+
+/**
+The interaction obtained by replacing the sub-interaction at the given position.
+**/
+pub fn replace_sub_interaction_at(interaction : &Interaction,
+                                  position : &Position,
+                                  new_sub_int : Interaction) -> Option<Interaction> {
+    match (position,interaction) {
+        (Position::Epsilon(None),_) => {
+            Some(new_sub_int)
+        },
+        (Position::Left(sub_pos),_) => {
+            match interaction {
+                Interaction::Strict(i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Strict(Box::new(new_i1),i2.clone()))
+                },
+                Interaction::Seq(i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Seq(Box::new(new_i1),i2.clone()))
+                },
+                Interaction::CoReg(cr,i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::CoReg(cr.clone(),Box::new(new_i1),i2.clone()))
+                },
+                Interaction::Alt(i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Alt(Box::new(new_i1),i2.clone()))
+                },
+                Interaction::Par(i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Par(Box::new(new_i1),i2.clone()))
+                },
+                Interaction::And(i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::And(Box::new(new_i1),i2.clone()))
+                },
+                Interaction::Sync(sync_acts,i1,i2) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Sync(sync_acts.clone(),Box::new(new_i1),i2.clone()))
+                },
+                Interaction::Loop(lk,i1) => {
+                    let new_i1 = replace_sub_interaction_at(i1,sub_pos,new_sub_int)?;
+                    Some(Interaction::Loop(lk.clone(),Box::new(new_i1)))
+                },
+                _ => {
+                    None
+                }
+            }
+        },
+        (Position::Right(sub_pos),_) => {
+            match interaction {
+                Interaction::Strict(i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::Strict(i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::Seq(i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::Seq(i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::CoReg(cr,i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::CoReg(cr.clone(),i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::Alt(i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::Alt(i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::Par(i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::Par(i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::And(i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::And(i1.clone(),Box::new(new_i2)))
+                },
+                Interaction::Sync(sync_acts,i1,i2) => {
+                    let new_i2 = replace_sub_interaction_at(i2,sub_pos,new_sub_int)?;
+                    Some(Interaction::Sync(sync_acts.clone(),i1.clone(),Box::new(new_i2)))
+                },
+                _ => {
+                    None
+                }
+            }
+        },
+        _ => {
+            None
+        }
+    }
+}
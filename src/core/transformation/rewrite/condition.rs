@@ -0,0 +1,105 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Formatter;
+
+use crate::core::language::avoid::avoids::AvoidsLifelines;
+use crate::core::language::involve::involves::InvolvesLifelines;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+A side condition on the sub-interactions bound to the pattern variables of a rewrite rule.
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum RewriteCondition {
+    // the first sub-interaction avoids all the lifelines involved in the second one
+    Avoids(String,String),
+    ExpressEmpty(String),
+    Equal(String,String),
+    // w.r.t. the order used to sort the operands of alt and par in canonization
+    Lower(String,String),
+    Not(Box<RewriteCondition>)
+}
+
+impl std::fmt::Display for RewriteCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RewriteCondition::Avoids(var1,var2) => {
+                write!(f,"{} avoids {}",var1,var2)
+            },
+            RewriteCondition::ExpressEmpty(var) => {
+                write!(f,"{} express_empty",var)
+            },
+            RewriteCondition::Equal(var1,var2) => {
+                write!(f,"{} == {}",var1,var2)
+            },
+            RewriteCondition::Lower(var1,var2) => {
+                write!(f,"{} < {}",var1,var2)
+            },
+            RewriteCondition::Not(cond) => {
+                write!(f,"not {}",cond)
+            }
+        }
+    }
+}
+
+impl RewriteCondition {
+
+    pub fn get_variables(&self) -> BTreeSet<String> {
+        match self {
+            RewriteCondition::Avoids(var1,var2)
+            | RewriteCondition::Equal(var1,var2)
+            | RewriteCondition::Lower(var1,var2) => {
+                btreeset!{var1.clone(),var2.clone()}
+            },
+            RewriteCondition::ExpressEmpty(var) => {
+                btreeset!{var.clone()}
+            },
+            RewriteCondition::Not(cond) => {
+                cond.get_variables()
+            }
+        }
+    }
+
+    pub fn holds(&self, bindings : &HashMap<String,Interaction>) -> bool {
+        match self {
+            RewriteCondition::Avoids(var1,var2) => {
+                let i1 = bindings.get(var1).unwrap();
+                let i2 = bindings.get(var2).unwrap();
+                i1.avoids_all_of(&i2.involved_lifelines())
+            },
+            RewriteCondition::ExpressEmpty(var) => {
+                bindings.get(var).unwrap().express_empty()
+            },
+            RewriteCondition::Equal(var1,var2) => {
+                bindings.get(var1).unwrap() == bindings.get(var2).unwrap()
+            },
+            RewriteCondition::Lower(var1,var2) => {
+                let i1 = bindings.get(var1).unwrap();
+                let i2 = bindings.get(var2).unwrap();
+                Ord::cmp(i1,i2) == Ordering::Less
+            },
+            RewriteCondition::Not(cond) => {
+                !cond.holds(bindings)
+            }
+        }
+    }
+
+}
@@ -0,0 +1,165 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Formatter;
+
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+
+
+/**
+A term of the rewrite rules language.
+Pattern variables match any sub-interaction.
+A variable occurring several times in a pattern only matches equal sub-interactions.
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum InteractionPattern {
+    Variable(String),
+    Empty,
+    Strict(Box<InteractionPattern>,Box<InteractionPattern>),
+    Seq(Box<InteractionPattern>,Box<InteractionPattern>),
+    Alt(Box<InteractionPattern>,Box<InteractionPattern>),
+    Par(Box<InteractionPattern>,Box<InteractionPattern>),
+    Loop(LoopKind,Box<InteractionPattern>)
+}
+
+impl std::fmt::Display for InteractionPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InteractionPattern::Variable(var) => {
+                write!(f,"{}",var)
+            },
+            InteractionPattern::Empty => {
+                write!(f,"o")
+            },
+            InteractionPattern::Strict(p1,p2) => {
+                write!(f,"strict({},{})",p1,p2)
+            },
+            InteractionPattern::Seq(p1,p2) => {
+                write!(f,"seq({},{})",p1,p2)
+            },
+            InteractionPattern::Alt(p1,p2) => {
+                write!(f,"alt({},{})",p1,p2)
+            },
+            InteractionPattern::Par(p1,p2) => {
+                write!(f,"par({},{})",p1,p2)
+            },
+            InteractionPattern::Loop(lk,p1) => {
+                let lk_str = match lk {
+                    LoopKind::SStrictSeq => "S",
+                    LoopKind::HHeadFirstWS => "H",
+                    LoopKind::WWeakSeq => "W",
+                    LoopKind::PInterleaving => "P"
+                };
+                write!(f,"loop{}({})",lk_str,p1)
+            }
+        }
+    }
+}
+
+impl InteractionPattern {
+
+    pub fn get_variables(&self) -> BTreeSet<String> {
+        match self {
+            InteractionPattern::Variable(var) => {
+                btreeset!{var.clone()}
+            },
+            InteractionPattern::Empty => {
+                btreeset!{}
+            },
+            InteractionPattern::Strict(p1,p2)
+            | InteractionPattern::Seq(p1,p2)
+            | InteractionPattern::Alt(p1,p2)
+            | InteractionPattern::Par(p1,p2) => {
+                let mut vars = p1.get_variables();
+                vars.extend(p2.get_variables());
+                vars
+            },
+            InteractionPattern::Loop(_,p1) => {
+                p1.get_variables()
+            }
+        }
+    }
+
+    /**
+    Matches the pattern against the root of the interaction,
+    extending the bindings of the pattern variables.
+    **/
+    pub fn match_interaction(&self,
+                             interaction : &Interaction,
+                             bindings : &mut HashMap<String,Interaction>) -> bool {
+        match (self,interaction) {
+            (InteractionPattern::Variable(var),_) => {
+                match bindings.get(var) {
+                    None => {
+                        bindings.insert(var.clone(),interaction.clone());
+                        true
+                    },
+                    Some(bound) => {
+                        bound == interaction
+                    }
+                }
+            },
+            (InteractionPattern::Empty,Interaction::Empty) => {
+                true
+            },
+            (InteractionPattern::Strict(p1,p2),Interaction::Strict(i1,i2))
+            | (InteractionPattern::Seq(p1,p2),Interaction::Seq(i1,i2))
+            | (InteractionPattern::Alt(p1,p2),Interaction::Alt(i1,i2))
+            | (InteractionPattern::Par(p1,p2),Interaction::Par(i1,i2)) => {
+                p1.match_interaction(i1,bindings) && p2.match_interaction(i2,bindings)
+            },
+            (InteractionPattern::Loop(plk,p1),Interaction::Loop(ilk,i1)) => {
+                plk == ilk && p1.match_interaction(i1,bindings)
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+    /**
+    Builds the interaction obtained by replacing the pattern variables with their bindings.
+    All the variables of the pattern are expected to be bound.
+    **/
+    pub fn instantiate(&self, bindings : &HashMap<String,Interaction>) -> Interaction {
+        match self {
+            InteractionPattern::Variable(var) => {
+                bindings.get(var).unwrap().clone()
+            },
+            InteractionPattern::Empty => {
+                Interaction::Empty
+            },
+            InteractionPattern::Strict(p1,p2) => {
+                Interaction::Strict(Box::new(p1.instantiate(bindings)),Box::new(p2.instantiate(bindings)))
+            },
+            InteractionPattern::Seq(p1,p2) => {
+                Interaction::Seq(Box::new(p1.instantiate(bindings)),Box::new(p2.instantiate(bindings)))
+            },
+            InteractionPattern::Alt(p1,p2) => {
+                Interaction::Alt(Box::new(p1.instantiate(bindings)),Box::new(p2.instantiate(bindings)))
+            },
+            InteractionPattern::Par(p1,p2) => {
+                Interaction::Par(Box::new(p1.instantiate(bindings)),Box::new(p2.instantiate(bindings)))
+            },
+            InteractionPattern::Loop(lk,p1) => {
+                Interaction::Loop(lk.clone(),Box::new(p1.instantiate(bindings)))
+            }
+        }
+    }
+
+}
@@ -0,0 +1,75 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+use std::fmt::Formatter;
+
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::rewrite::condition::RewriteCondition;
+use crate::core::transformation::rewrite::pattern::InteractionPattern;
+
+
+/**
+A user-defined rewrite rule "name : lhs -> rhs if conditions".
+It is applied at the root of an interaction, its application at any position
+being handled as for the other transformations.
+**/
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct InteractionRewriteRule {
+    pub name : String,
+    pub lhs : InteractionPattern,
+    pub rhs : InteractionPattern,
+    pub conditions : Vec<RewriteCondition>
+}
+
+impl std::fmt::Display for InteractionRewriteRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{} : {} -> {}",self.name,self.lhs,self.rhs)?;
+        for (x,cond) in self.conditions.iter().enumerate() {
+            if x == 0 {
+                write!(f," if {}",cond)?;
+            } else {
+                write!(f," and {}",cond)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InteractionRewriteRule {
+
+    pub fn new(name : String,
+               lhs : InteractionPattern,
+               rhs : InteractionPattern,
+               conditions : Vec<RewriteCondition>) -> InteractionRewriteRule {
+        return InteractionRewriteRule{name,lhs,rhs,conditions};
+    }
+
+    pub fn apply(&self, interaction : &Interaction) -> Vec<Interaction> {
+        let mut bindings : HashMap<String,Interaction> = HashMap::new();
+        if self.lhs.match_interaction(interaction,&mut bindings)
+            && self.conditions.iter().all(|cond| cond.holds(&bindings)) {
+            let result = self.rhs.instantiate(&bindings);
+            // a rule leaving the interaction unchanged would prevent canonization phases from ending
+            if &result != interaction {
+                return vec![result];
+            }
+        }
+        return vec![];
+    }
+
+}
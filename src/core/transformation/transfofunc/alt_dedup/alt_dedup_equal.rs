@@ -28,7 +28,12 @@ pub fn transfo_deduplicate(interaction : &Interaction) -> Vec<Interaction> {
     if as_set.len() < orig_alt_frags.len() {
         let mut new_alt_frags : Vec<&Interaction> = as_set.into_iter().sorted().collect(); //
         return vec![fold_recursive_alt_frags(&mut new_alt_frags)];
-    } else {
-        return vec![];
     }
+    // the empty interaction is included in any alternative which expresses the empty trace
+    if orig_alt_frags.contains(&&Interaction::Empty)
+        && orig_alt_frags.iter().any(|frag| *frag != &Interaction::Empty && frag.express_empty()) {
+        let mut new_alt_frags : Vec<&Interaction> = orig_alt_frags.into_iter().filter(|frag| *frag != &Interaction::Empty).collect();
+        return vec![fold_recursive_alt_frags(&mut new_alt_frags)];
+    }
+    return vec![];
 }
@@ -20,22 +20,26 @@ use crate::core::language::syntax::action::{EmissionAction, EmissionTargetRef, R
 use crate::core::language::syntax::interaction::Interaction;
 
 
-pub fn transfo_merge_action(interaction : &Interaction) -> Option<Interaction> {
+pub fn transfo_merge_action(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         Interaction::And(ref i1, ref i2) => {
             match (&**i1,&**i2) {
                 (Interaction::Emission(ref em_act),Interaction::Reception(ref rc_act)) => {
-                    return merge_action_inner(em_act,rc_act);
+                    if let Some(merged) = merge_action_inner(em_act,rc_act) {
+                        return vec![merged];
+                    }
                 },
                 (Interaction::Reception(ref rc_act),Interaction::Emission(ref em_act)) => {
-                    return merge_action_inner(em_act,rc_act);
+                    if let Some(merged) = merge_action_inner(em_act,rc_act) {
+                        return vec![merged];
+                    }
                 },
                 _ => {}
             }
         },
         _ => {}
     }
-    return None;
+    return vec![];
 }
 
 
@@ -63,7 +67,7 @@ fn merge_action_inner(emission : &EmissionAction,
                     },
                     EmissionTargetRef::Gate(gt_id) => {
                         if gt_id == gt_id_to_match {
-                            is_match == true;
+                            is_match = true;
                             for recipient_lf in &reception.recipients {
                                 targets.push(EmissionTargetRef::Lifeline(*recipient_lf));
                             }
@@ -0,0 +1,59 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::language::syntax::action::EmissionTargetRef;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+The gates through which one of the interactions emits towards the other.
+**/
+pub fn get_connecting_gates(i1 : &Interaction, i2 : &Interaction) -> HashSet<usize> {
+    let (i1_em_acts,i1_rc_acts) = i1.contained_model_actions();
+    let (i2_em_acts,i2_rc_acts) = i2.contained_model_actions();
+    let mut connecting = HashSet::new();
+    for (em_acts,rc_acts) in [(&i1_em_acts,&i2_rc_acts),(&i2_em_acts,&i1_rc_acts)] {
+        let received_from : HashSet<usize> = rc_acts.iter().filter_map(|rc| rc.origin_gt_id).collect();
+        for em_act in em_acts {
+            for target_ref in &em_act.targets {
+                if let EmissionTargetRef::Gate(gt_id) = target_ref {
+                    if received_from.contains(gt_id) {
+                        connecting.insert(*gt_id);
+                    }
+                }
+            }
+        }
+    }
+    return connecting;
+}
+
+/**
+Once they do not communicate through any gate, both sides of a merge are simply interleaved.
+**/
+pub fn transfo_merge_par(interaction : &Interaction) -> Vec<Interaction> {
+    match interaction {
+        &Interaction::And(ref i1, ref i2) => {
+            if get_connecting_gates(i1,i2).is_empty() {
+                return vec![Interaction::Par(i1.clone(), i2.clone())];
+            }
+        },
+        _ => {}
+    }
+    return vec![];
+}
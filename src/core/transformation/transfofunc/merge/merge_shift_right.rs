@@ -17,74 +17,74 @@ limitations under the License.
 
 use crate::core::language::syntax::interaction::Interaction;
 
-pub fn transfo_merge_shift_right_1(interaction : &Interaction) -> Option<Interaction> {
+pub fn transfo_merge_shift_right_1(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         &Interaction::And(ref i1, ref i2) => {
             match **i2 {
                 Interaction::Strict(ref i21, ref i22) => {
                     let new_left = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::Strict(Box::new(new_left), i22.clone() ));
+                    return vec![Interaction::Strict(Box::new(new_left), i22.clone() )];
                 },
                 Interaction::Seq(ref i21, ref i22) => {
                     let new_left = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::Seq(Box::new(new_left), i22.clone() ));
+                    return vec![Interaction::Seq(Box::new(new_left), i22.clone() )];
                 },
                 Interaction::CoReg(ref cr, ref i21, ref i22) => {
                     let new_left = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::CoReg(cr.clone(),Box::new(new_left), i22.clone() ));
+                    return vec![Interaction::CoReg(cr.clone(),Box::new(new_left), i22.clone() )];
                 },
                 Interaction::Par(ref i21, ref i22) => {
                     let new_left = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::Par(Box::new(new_left), i22.clone() ));
+                    return vec![Interaction::Par(Box::new(new_left), i22.clone() )];
                 },
                 Interaction::Alt(ref i21, ref i22) => {
                     let new_left = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::Alt(Box::new(new_left), i22.clone() ));
+                    return vec![Interaction::Alt(Box::new(new_left), i22.clone() )];
                 },
                 Interaction::Loop(ref lk, ref i21) => {
                     let new_sub = Interaction::And( i1.clone(), i21.clone() );
-                    return Some(Interaction::Loop(lk.clone(), Box::new(new_sub) ));
+                    return vec![Interaction::Loop(lk.clone(), Box::new(new_sub) )];
                 },
                 _ => {}
             }
         },
         _ => {}
     }
-    return None;
+    return vec![];
 }
 
 
 
-pub fn transfo_merge_shift_right_2(interaction : &Interaction) -> Option<Interaction> {
+pub fn transfo_merge_shift_right_2(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         &Interaction::And(ref i1, ref i2) => {
             match **i2 {
                 Interaction::Strict(ref i21, ref i22) => {
                     let new_right = Interaction::And( i1.clone(), i22.clone() );
-                    return Some(Interaction::Strict( i21.clone(), Box::new(new_right) ));
+                    return vec![Interaction::Strict( i21.clone(), Box::new(new_right) )];
                 },
                 Interaction::Seq(ref i21, ref i22) => {
                     let new_right = Interaction::And( i1.clone(), i22.clone() );
-                    return Some(Interaction::Seq( i21.clone(), Box::new(new_right) ));
+                    return vec![Interaction::Seq( i21.clone(), Box::new(new_right) )];
                 },
                 Interaction::CoReg(ref cr, ref i21, ref i22) => {
                     let new_right = Interaction::And( i1.clone(), i22.clone() );
-                    return Some(Interaction::CoReg( cr.clone(),i21.clone(), Box::new(new_right) ));
+                    return vec![Interaction::CoReg( cr.clone(),i21.clone(), Box::new(new_right) )];
                 },
                 Interaction::Par(ref i21, ref i22) => {
                     let new_right = Interaction::And( i1.clone(), i22.clone() );
-                    return Some(Interaction::Par( i21.clone(), Box::new(new_right) ));
+                    return vec![Interaction::Par( i21.clone(), Box::new(new_right) )];
                 },
                 Interaction::Alt(ref i21, ref i22) => {
                     let new_right = Interaction::And( i1.clone(), i22.clone() );
-                    return Some(Interaction::Alt( i21.clone(), Box::new(new_right) ));
+                    return vec![Interaction::Alt( i21.clone(), Box::new(new_right) )];
                 },
                 _ => {}
             }
         },
         _ => {}
     }
-    return None;
+    return vec![];
 }
 
 
@@ -17,41 +17,41 @@ limitations under the License.
 
 use crate::core::language::syntax::interaction::Interaction;
 
-pub fn transfo_merge_skip(interaction : &Interaction) -> Option<Interaction> {
+pub fn transfo_merge_skip(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         &Interaction::And(ref i1, ref i2) => {
             match (&**i1,&**i2) {
                 (Interaction::Strict(ref i11, ref i12),Interaction::Strict(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i21.clone() );
                     let new_right = Interaction::And( i12.clone(), i22.clone() );
-                    return Some(Interaction::Strict(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Strict(Box::new(new_left), Box::new(new_right) )];
                 },
                 (Interaction::Seq(ref i11, ref i12),Interaction::Seq(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i21.clone() );
                     let new_right = Interaction::And( i12.clone(), i22.clone() );
-                    return Some(Interaction::Seq(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Seq(Box::new(new_left), Box::new(new_right) )];
                 },
                 (Interaction::CoReg(ref cr1, ref i11, ref i12),Interaction::CoReg(ref cr2,ref i21, ref i22)) => {
                     if cr1 == cr2 {
                         let new_left = Interaction::And( i11.clone(), i21.clone() );
                         let new_right = Interaction::And( i12.clone(), i22.clone() );
-                        return Some(Interaction::CoReg(cr1.clone(), Box::new(new_left), Box::new(new_right) ));
+                        return vec![Interaction::CoReg(cr1.clone(), Box::new(new_left), Box::new(new_right) )];
                     }
                 },
                 (Interaction::Par(ref i11, ref i12),Interaction::Par(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i21.clone() );
                     let new_right = Interaction::And( i12.clone(), i22.clone() );
-                    return Some(Interaction::Par(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Par(Box::new(new_left), Box::new(new_right) )];
                 },
                 (Interaction::Alt(ref i11, ref i12),Interaction::Alt(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i21.clone() );
                     let new_right = Interaction::And( i12.clone(), i22.clone() );
-                    return Some(Interaction::Alt(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Alt(Box::new(new_left), Box::new(new_right) )];
                 },
                 _ => {}
             }
         },
         _ => {}
     }
-    return None;
+    return vec![];
 }
\ No newline at end of file
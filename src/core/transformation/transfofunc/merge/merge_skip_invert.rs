@@ -17,24 +17,24 @@ limitations under the License.
 
 use crate::core::language::syntax::interaction::Interaction;
 
-pub fn transfo_merge_skip_invert(interaction : &Interaction) -> Option<Interaction> {
+pub fn transfo_merge_skip_invert(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         &Interaction::And(ref i1, ref i2) => {
             match (&**i1,&**i2) {
                 (Interaction::Par(ref i11, ref i12),Interaction::Par(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i22.clone() );
                     let new_right = Interaction::And( i12.clone(), i21.clone() );
-                    return Some(Interaction::Par(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Par(Box::new(new_left), Box::new(new_right) )];
                 },
                 (Interaction::Alt(ref i11, ref i12),Interaction::Alt(ref i21, ref i22)) => {
                     let new_left = Interaction::And( i11.clone(), i22.clone() );
                     let new_right = Interaction::And( i12.clone(), i21.clone() );
-                    return Some(Interaction::Alt(Box::new(new_left), Box::new(new_right) ));
+                    return vec![Interaction::Alt(Box::new(new_left), Box::new(new_right) )];
                 },
                 _ => {}
             }
         },
         _ => {}
     }
-    return None;
+    return vec![];
 }
\ No newline at end of file
@@ -21,5 +21,6 @@ pub mod merge_skip;
 pub mod merge_skip_invert;
 pub mod merge_shift_left;
 pub mod merge_shift_right;
+pub mod merge_par;
 
 
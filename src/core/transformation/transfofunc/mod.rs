@@ -24,3 +24,4 @@ pub mod invert;
 pub mod loop_simpl;
 pub mod strict_to_seq;
 pub mod par_to_seq;
+pub mod merge;
@@ -26,10 +26,17 @@ use crate::core::transformation::transfofunc::factorize::factorize_prefix::{tran
 use crate::core::transformation::transfofunc::factorize::factorize_suffix::{transfo_factorize_suffix_seq, transfo_factorize_suffix_strict};
 use crate::core::transformation::transfofunc::flush::transfo_flush_right;
 use crate::core::transformation::transfofunc::invert::{transfo_invert_alt_sorted, transfo_invert_par_sorted};
+use crate::core::transformation::transfofunc::merge::merge_action::transfo_merge_action;
+use crate::core::transformation::transfofunc::merge::merge_par::transfo_merge_par;
+use crate::core::transformation::transfofunc::merge::merge_shift_left::{transfo_merge_shift_left_1, transfo_merge_shift_left_2};
+use crate::core::transformation::transfofunc::merge::merge_shift_right::{transfo_merge_shift_right_1, transfo_merge_shift_right_2};
+use crate::core::transformation::transfofunc::merge::merge_skip::transfo_merge_skip;
+use crate::core::transformation::transfofunc::merge::merge_skip_invert::transfo_merge_skip_invert;
 use crate::core::transformation::transfofunc::loop_simpl::{transfo_loop_empty_simpl, transfo_loop_unnest};
 use crate::core::transformation::transfofunc::par_to_seq::transfo_par_to_seq;
 use crate::core::transformation::transfofunc::simpl::transfo_simpl;
 use crate::core::transformation::transfofunc::strict_to_seq::transfo_strict_to_seq;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
 
 #[derive(IntoStaticStr,Clone, PartialEq, Debug, Eq, Hash)]
 pub enum InteractionTransformationKind {
@@ -51,18 +58,24 @@ pub enum InteractionTransformationKind {
     StrictToSeq,
     ParToSeq,
     UnfoldActions,
+    // user-defined rewrite rule
+    Rule(InteractionRewriteRule),
     // ***
-    /*MergeShiftLeft1,
+    MergeShiftLeft1,
     MergeShiftLeft2,
     MergeShiftRight1,
     MergeShiftRight2,
     MergeAction,
     MergeSkip,
-    MergeSkipInvert,*/
+    MergeSkipInvert,
+    MergePar
 }
 
 impl std::fmt::Display for InteractionTransformationKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let InteractionTransformationKind::Rule(rule) = self {
+            return write!(f,"Rule({})", rule.name);
+        }
         let as_static_str : &'static str = self.into();
         write!(f,"{}", as_static_str)
     }
@@ -71,10 +84,94 @@ impl std::fmt::Display for InteractionTransformationKind {
 
 impl InteractionTransformationKind {
     pub fn to_string(&self) -> String {
-        let as_static_str : &'static str = self.into();
-        return as_static_str.to_string();
+        return format!("{}", self);
+    }
+    /**
+     * All the built-in transformation kinds i.e. excluding user-defined rewrite rules.
+     **/
+    pub fn get_builtin_kinds() -> Vec<InteractionTransformationKind> {
+        return vec![
+            InteractionTransformationKind::Simpl,
+            InteractionTransformationKind::FlushRight,
+            InteractionTransformationKind::InvertAlt,
+            InteractionTransformationKind::InvertPar,
+            InteractionTransformationKind::Deduplicate,
+            InteractionTransformationKind::FactorizePrefixStrict,
+            InteractionTransformationKind::FactorizePrefixSeq,
+            InteractionTransformationKind::FactorizeCommutativePar,
+            InteractionTransformationKind::FactorizeSuffixStrict,
+            InteractionTransformationKind::FactorizeSuffixSeq,
+            InteractionTransformationKind::DeFactorizeLeft,
+            InteractionTransformationKind::DeFactorizeRight,
+            InteractionTransformationKind::LoopSimpl,
+            InteractionTransformationKind::LoopUnNest,
+            InteractionTransformationKind::SortActionContent,
+            InteractionTransformationKind::StrictToSeq,
+            InteractionTransformationKind::ParToSeq,
+            InteractionTransformationKind::UnfoldActions,
+            InteractionTransformationKind::MergeShiftLeft1,
+            InteractionTransformationKind::MergeShiftLeft2,
+            InteractionTransformationKind::MergeShiftRight1,
+            InteractionTransformationKind::MergeShiftRight2,
+            InteractionTransformationKind::MergeAction,
+            InteractionTransformationKind::MergeSkip,
+            InteractionTransformationKind::MergeSkipInvert,
+            InteractionTransformationKind::MergePar
+        ];
+    }
+    /**
+     * Keywords with which the kind is referred to in the canonization phases of a .hcf file.
+     **/
+    pub fn get_hcf_keywords(&self) -> &'static [&'static str] {
+        match self {
+            InteractionTransformationKind::Simpl => &["simpl"],
+            InteractionTransformationKind::FlushRight => &["flush_right","flush"],
+            InteractionTransformationKind::InvertAlt => &["invert_alt"],
+            InteractionTransformationKind::InvertPar => &["invert_par"],
+            InteractionTransformationKind::Deduplicate => &["deduplicate"],
+            InteractionTransformationKind::FactorizePrefixStrict => &["factorize_prefix_strict"],
+            InteractionTransformationKind::FactorizePrefixSeq => &["factorize_prefix_seq"],
+            InteractionTransformationKind::FactorizeCommutativePar => &["factorize_par"],
+            InteractionTransformationKind::FactorizeSuffixStrict => &["factorize_suffix_strict"],
+            InteractionTransformationKind::FactorizeSuffixSeq => &["factorize_suffix_seq"],
+            InteractionTransformationKind::DeFactorizeLeft => &["defactorize_left"],
+            InteractionTransformationKind::DeFactorizeRight => &["defactorize_right"],
+            InteractionTransformationKind::LoopSimpl => &["loop_simpl"],
+            InteractionTransformationKind::LoopUnNest => &["loop_unnest"],
+            InteractionTransformationKind::SortActionContent => &["sort_action_content"],
+            InteractionTransformationKind::StrictToSeq => &["strict_to_seq"],
+            InteractionTransformationKind::ParToSeq => &["par_to_seq"],
+            InteractionTransformationKind::UnfoldActions => &["unfold_actions"],
+            InteractionTransformationKind::MergeShiftLeft1 => &["merge_shift_left_1"],
+            InteractionTransformationKind::MergeShiftLeft2 => &["merge_shift_left_2"],
+            InteractionTransformationKind::MergeShiftRight1 => &["merge_shift_right_1"],
+            InteractionTransformationKind::MergeShiftRight2 => &["merge_shift_right_2"],
+            InteractionTransformationKind::MergeAction => &["merge_action"],
+            InteractionTransformationKind::MergeSkip => &["merge_skip"],
+            InteractionTransformationKind::MergeSkipInvert => &["merge_skip_invert"],
+            InteractionTransformationKind::MergePar => &["merge_par"],
+            InteractionTransformationKind::Rule(_) => &[]
+        }
+    }
+    /**
+     * Resolves a built-in transformation kind from its name (as displayed e.g. "FlushRight" in .hpf proofs)
+     * or from one of its .hcf keywords (e.g. "flush_right").
+     **/
+    pub fn from_name(name : &str) -> Option<InteractionTransformationKind> {
+        return InteractionTransformationKind::get_builtin_kinds().into_iter()
+            .find(|k| k.to_string() == name || k.get_hcf_keywords().contains(&name));
     }
-    pub fn get_transformation(&self) -> fn(&Interaction) -> Vec<Interaction> {
+    pub fn apply(&self, interaction : &Interaction) -> Vec<Interaction> {
+        match self {
+            InteractionTransformationKind::Rule(rule) => {
+                rule.apply(interaction)
+            },
+            _ => {
+                self.get_transformation()(interaction)
+            }
+        }
+    }
+    fn get_transformation(&self) -> fn(&Interaction) -> Vec<Interaction> {
         match self {
             InteractionTransformationKind::Simpl => {
                 return transfo_simpl;
@@ -129,9 +226,11 @@ impl InteractionTransformationKind {
             },
             InteractionTransformationKind::UnfoldActions => {
                 return transfo_unfold_action;
-            }
+            },
+            InteractionTransformationKind::Rule(_) => {
+                panic!("rewrite rules are not associated to a transformation function");
+            },
             // ***
-            /*
             InteractionTransformationKind::MergeShiftLeft1 => {
                 return transfo_merge_shift_left_1;
             },
@@ -143,8 +242,39 @@ impl InteractionTransformationKind {
             },
             InteractionTransformationKind::MergeShiftRight2 => {
                 return transfo_merge_shift_right_2;
-            },*/
+            },
+            InteractionTransformationKind::MergeAction => {
+                return transfo_merge_action;
+            },
+            InteractionTransformationKind::MergeSkip => {
+                return transfo_merge_skip;
+            },
+            InteractionTransformationKind::MergeSkipInvert => {
+                return transfo_merge_skip_invert;
+            },
+            InteractionTransformationKind::MergePar => {
+                return transfo_merge_par;
+            }
         }
     }
 }
 
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transformation_kind_names_test() {
+        for kind in InteractionTransformationKind::get_builtin_kinds() {
+            assert_eq!(InteractionTransformationKind::from_name(&kind.to_string()), Some(kind.clone()));
+            for keyword in kind.get_hcf_keywords() {
+                assert_eq!(InteractionTransformationKind::from_name(keyword), Some(kind.clone()));
+            }
+        }
+        assert_eq!(InteractionTransformationKind::from_name("MergeShiftLeft1"), Some(InteractionTransformationKind::MergeShiftLeft1));
+        assert_eq!(InteractionTransformationKind::from_name("flush"), Some(InteractionTransformationKind::FlushRight));
+        assert_eq!(InteractionTransformationKind::from_name("NotAKind"), None);
+    }
+}
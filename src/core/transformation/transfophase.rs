@@ -19,15 +19,17 @@ use std::fmt::Formatter;
 use crate::core::language::syntax::interaction::Interaction;
 use crate::core::transformation::get_transfos::get_all_transfos::get_all_transformations_rec;
 use crate::core::transformation::get_transfos::get_one_transfo::get_one_transformation_rec;
+use crate::core::transformation::normalize::normalize_bottom_up;
 use crate::core::transformation::transfokind::InteractionTransformationKind;
 use crate::core::transformation::transfores::InteractionTransformationResult;
 
+#[derive(Clone, PartialEq, Debug)]
 pub struct InteractionTransformationPhase {
     pub transfos : Vec<InteractionTransformationKind>,
-    /*
+    // whether or not all the transformations that can be applied are considered
     pub get_all : bool,
     // a priority order in the application of the InteractionTransformationKind
-    pub ordered : bool*/
+    pub ordered : bool
 }
 
 impl std::fmt::Display for InteractionTransformationPhase {
@@ -36,38 +38,38 @@ impl std::fmt::Display for InteractionTransformationPhase {
             format!("{:},{:}",prev,phase)
         );
         got.push_str("]");
-        write!(f, "{}", got)
+        write!(f, "{} (get_all={:},ordered={:})", got, self.get_all, self.ordered)
     }
 }
 
 impl InteractionTransformationPhase {
-    pub fn new(transfos : Vec<InteractionTransformationKind>,/*get_all : bool,ordered : bool*/) -> InteractionTransformationPhase {
-        return InteractionTransformationPhase{transfos/*,get_all,ordered*/};
+    pub fn new(transfos : Vec<InteractionTransformationKind>,get_all : bool,ordered : bool) -> InteractionTransformationPhase {
+        return InteractionTransformationPhase{transfos,get_all,ordered};
     }
 
-    pub fn get_transfos(&self, interaction : &Interaction, get_all : bool) -> Vec<InteractionTransformationResult> {
-        if get_all {
-            return get_all_transformations_rec(&self.transfos,interaction);
-        } else {
-            match get_one_transformation_rec(&self.transfos,interaction) {
-                None => {
-                    return Vec::new();
-                },
-                Some( got ) => {
-                    return vec![got];
+    pub fn apply_phase(&self,interaction : &Interaction) -> Vec<InteractionTransformationResult> {
+        if self.ordered {
+            // only the first kind (in the order of the phase) which can be applied somewhere is considered
+            for transfo in &self.transfos {
+                let got = self.get_transfos(interaction,&vec![transfo.clone()]);
+                if !got.is_empty() {
+                    return got;
                 }
             }
-        }
-    }
-
-    /*pub fn apply_phase(&self,interaction : &Interaction) -> Vec<InteractionTransformationResult> {
-        if self.ordered {
-            panic!("not implemented");
+            return Vec::new();
         } else {
             return self.get_transfos(interaction,&self.transfos);
         }
     }
 
+    /**
+    Returns the interaction on which none of the transformations of the phase can be applied,
+    deterministically and without exploring alternative rewritings.
+    **/
+    pub fn normalize(&self,interaction : &Interaction) -> Interaction {
+        return normalize_bottom_up(&self.transfos,interaction);
+    }
+
     fn get_transfos(&self,
                     interaction : &Interaction,
                     transfos : &Vec<InteractionTransformationKind>) -> Vec<InteractionTransformationResult> {
@@ -83,7 +85,7 @@ impl InteractionTransformationPhase {
                 }
             }
         }
-    }*/
+    }
 }
 
 
@@ -0,0 +1,88 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::time::Instant;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+pub struct AnalysisStrategyMetrics {
+    pub verdict : AnalysisGlobalVerdict,
+    pub node_count : u32,
+    pub median_time : u128
+}
+
+impl AnalysisStrategyMetrics {
+    pub fn add_csv_title_line(strategy_name : &str, results : &mut String) {
+        results.push_str(&format!("{:}verdict,",strategy_name));
+        results.push_str(&format!("{:}nodes,",strategy_name));
+        results.push_str(&format!("{:}medtime,",strategy_name));
+    }
+    pub fn add_csv_line(&self, results : &mut String) {
+        results.push_str(&self.verdict.to_string());
+        results.push_str(",");
+        results.push_str(&self.node_count.to_string());
+        results.push_str(",");
+        results.push_str(&self.median_time.to_string());
+        results.push_str(",");
+    }
+}
+
+fn median(numbers: Vec<u128>) -> u128 {
+    let mut numbers = numbers;
+    numbers.sort();
+    let mid = numbers.len() / 2;
+    numbers[mid]
+}
+
+// the loggers of the options are not used so that they do not interfere with the time measurements
+fn analyze_once(gen_ctx : &GeneralContext,
+                interaction : &Interaction,
+                co_localizations : &CoLocalizations,
+                multi_trace : &MultiTrace,
+                ana_opts : HibouAnalyzeOptions) -> (u32,AnalysisGlobalVerdict,u128) {
+    let now = Instant::now();
+    let (node_count,verdict) = analyze_multi_trace_without_loggers(gen_ctx,interaction,co_localizations,multi_trace,ana_opts);
+    (node_count,verdict,now.elapsed().as_micros())
+}
+
+/**
+ * Analyzes a multi-trace "num_tries" times with the options returned by "get_options"
+ * and returns the verdict, the number of nodes and the median time (in microseconds).
+ **/
+pub fn benchmark_analysis_strategy(gen_ctx : &GeneralContext,
+                                   interaction : &Interaction,
+                                   co_localizations : &CoLocalizations,
+                                   multi_trace : &MultiTrace,
+                                   get_options : &dyn Fn() -> HibouAnalyzeOptions,
+                                   num_tries : u32) -> AnalysisStrategyMetrics {
+    let mut durations = vec![];
+    let mut last_result = None;
+    for _ in 0..num_tries.max(1) {
+        let (node_count,verdict,duration) = analyze_once(gen_ctx,interaction,co_localizations,multi_trace,get_options());
+        durations.push(duration);
+        last_result = Some((node_count,verdict));
+    }
+    let (node_count,verdict) = last_result.unwrap();
+    AnalysisStrategyMetrics{verdict,node_count,median_time:median(durations)}
+}
@@ -51,7 +51,7 @@ pub fn generate_canonical_random_interaction(gen_ctx : &GeneralContext,
              isymbs
     );
 
-    let ican = canonize_interaction(&gen_ctx,&i, DefaultCanonizationProcess::BasicWithToSeq);
+    let ican = canonize_interaction(&gen_ctx,&i, DefaultCanonizationProcess::BasicWithToSeq,false);
     let icanmetrics = InteractionMetrics::extract_from_interaction(&ican);
     let icansymbs = icanmetrics.symbols.iter().fold(0_u32,|x,(_,c)| x + c);
     println!("canonized to interaction of depth {:} with {:} symbols",
@@ -20,4 +20,4 @@ pub mod interaction_random_gen;
 pub mod parstrict_interaction_generation;
 pub mod doors_interactions_generation;
 pub mod loopalt_interaction_generation;
-pub mod next_action;
\ No newline at end of file
+pub mod next_action;pub mod ana_strategies;
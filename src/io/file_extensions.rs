@@ -25,4 +25,8 @@ pub static HIBOU_CONFIGURATION_FILE_EXTENSION : &'static str = "hcf";
 
 pub static HIBOU_INTERACTION_FILE_EXTENSION : &'static str = "hif";
 
+pub static HIBOU_WITNESS_FILE_EXTENSION : &'static str = "hwf";
 
+pub static HIBOU_REWRITE_RULES_FILE_EXTENSION : &'static str = "hrf";
+
+pub static HIBOU_PROOF_FILE_EXTENSION : &'static str = "hpf";
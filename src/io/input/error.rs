@@ -35,6 +35,7 @@ pub enum HibouParsingError {
     // ***
     EmissionDefinitionError(String),
     OtherDefinitionError(String),
+    RewriteRuleDefinitionError(String),
     // ***
     NonDisjointTraceComponents,
     IllDefinedTraceComponents(String)
@@ -85,6 +86,9 @@ impl fmt::Display for HibouParsingError {
             HibouParsingError::OtherDefinitionError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing ; other definition error : {:}", sub_e));
             },
+            HibouParsingError::RewriteRuleDefinitionError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing ; rewrite rule definition error : {:}", sub_e));
+            },
             // ***
             HibouParsingError::NonDisjointTraceComponents => {
                 return write!(f, "{}", format!("error while parsing ; non disjoint trace canals"));
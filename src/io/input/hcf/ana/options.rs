@@ -25,6 +25,7 @@ use crate::core::general_context::GeneralContext;
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
+use crate::io::input::hcf::loggers::witness::parse_witness_logger;
 
 
 #[allow(unused_imports)]
@@ -89,6 +90,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                              file_name : &str) -> Result<HibouAnalyzeOptions,HibouParsingError> {
     let mut loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>> = Vec::new();
     let mut strategy : QueueSearchStrategy = QueueSearchStrategy::BFS;
+    let mut heuristic_guidance = false;
     let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
     let mut priorities : GenericProcessPriorities<AnalysisPriorities> = GenericProcessPriorities::new(AnalysisPriorities::default(),false);
     let mut ana_kind = AnalysisKind::Prefix;
@@ -127,6 +129,10 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                         Rule::OPTION_NFAIT_LOGGER => {
                             return Err(HibouParsingError::HcfSetupError("cannot use NFAIT logger for trace analysis".to_string()));
                         },
+                        Rule::OPTION_WITNESS_LOGGER => {
+                            let wlogger = parse_witness_logger(logger_id,logger_kind_pair);
+                            loggers.push( Box::new(wlogger));
+                        },
                         _ => {
                             panic!("what rule then ? : {:?}", logger_kind_pair.as_rule() );
                         }
@@ -138,12 +144,20 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                 match strategy_pair.as_rule() {
                     Rule::OPTION_STRATEGY_BFS => {
                         strategy = QueueSearchStrategy::BFS;
+                        heuristic_guidance = false;
                     },
                     Rule::OPTION_STRATEGY_DFS => {
                         strategy = QueueSearchStrategy::DFS;
+                        heuristic_guidance = false;
                     },
                     Rule::OPTION_STRATEGY_HCS => {
                         strategy = QueueSearchStrategy::HCS;
+                        heuristic_guidance = false;
+                    },
+                    Rule::OPTION_STRATEGY_GREEDY_DFS => {
+                        // a depth-first search in which the children of each node are ordered by their heuristic
+                        strategy = QueueSearchStrategy::DFS;
+                        heuristic_guidance = true;
                     },
                     _ => {
                         panic!("what rule then ? : {:?}", strategy_pair.as_rule() );
@@ -287,7 +301,8 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
     } else {
         None
     };
-    let param = AnalysisParameterization::new(ana_kind,locana_param, use_partial_order_reduction);
+    let mut param = AnalysisParameterization::new(ana_kind,locana_param, use_partial_order_reduction);
+    param.heuristic_guidance = heuristic_guidance;
     let hoptions = HibouAnalyzeOptions::new(loggers,strategy,filters,priorities,param,use_memoization,goal);
     return Ok(hoptions);
 }
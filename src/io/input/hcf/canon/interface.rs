@@ -27,13 +27,15 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 
 
 use crate::core::general_context::GeneralContext;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
 use crate::io::file_extensions::HIBOU_CONFIGURATION_FILE_EXTENSION;
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::hcf::canon::options::{HibouCanonizeOptions, parse_canonize_options};
 
 
 pub fn parse_hcf_file_for_canonize(gen_ctx : &GeneralContext,
-                                  file_path : &str) -> Result<HibouCanonizeOptions,HibouParsingError> {
+                                  file_path : &str,
+                                  rules : &Vec<InteractionRewriteRule>) -> Result<HibouCanonizeOptions,HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
     if file_extension != HIBOU_CONFIGURATION_FILE_EXTENSION {
@@ -42,7 +44,7 @@ pub fn parse_hcf_file_for_canonize(gen_ctx : &GeneralContext,
     let file_name : &str = path_object.file_stem().unwrap().to_str().unwrap();
     match fs::read_to_string(file_path) {
         Ok( unparsed_hcf_str ) => {
-            return parse_hcf_string_for_canonize(gen_ctx,unparsed_hcf_str, file_name);
+            return parse_hcf_string_for_canonize(gen_ctx,unparsed_hcf_str, file_name, rules);
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
@@ -52,14 +54,15 @@ pub fn parse_hcf_file_for_canonize(gen_ctx : &GeneralContext,
 
 
 fn parse_hcf_string_for_canonize(gen_ctx : &GeneralContext,
-                                    hcf_string : String, file_name : &str)
+                                    hcf_string : String, file_name : &str,
+                                    rules : &Vec<InteractionRewriteRule>)
                                     -> Result<HibouCanonizeOptions,HibouParsingError> {
     match HcfParser::parse(Rule::HCF_PEST_FILE, &hcf_string) {
         Ok( ref mut got_pair ) => {
             let conf_pair = got_pair.next().unwrap();
             match conf_pair.as_rule() {
                 Rule::HIBOU_CONFIGURATION => {
-                    return parse_conf_pair_for_canonize(gen_ctx,conf_pair, file_name);
+                    return parse_conf_pair_for_canonize(gen_ctx,conf_pair, file_name, rules);
                 },
                 _ => {
                     panic!("what rule then ? : {:?}", conf_pair.as_rule() );
@@ -75,7 +78,8 @@ fn parse_hcf_string_for_canonize(gen_ctx : &GeneralContext,
 
 fn parse_conf_pair_for_canonize(gen_ctx : &GeneralContext,
                                conf_pair : Pair<Rule>,
-                               file_name : &str)
+                               file_name : &str,
+                               rules : &Vec<InteractionRewriteRule>)
                                -> Result<HibouCanonizeOptions,HibouParsingError> {
     let mut got_section_canonize_options   : bool = false;
     let mut canonize_options = HibouCanonizeOptions::default();
@@ -90,7 +94,7 @@ fn parse_conf_pair_for_canonize(gen_ctx : &GeneralContext,
                 }
                 got_section_canonize_options = true;
                 // ***
-                match parse_canonize_options(gen_ctx,current_pair,file_name) {
+                match parse_canonize_options(gen_ctx,current_pair,file_name,rules) {
                     Err(e) => {
                         return Err(e);
                     },
@@ -113,3 +117,44 @@ fn parse_conf_pair_for_canonize(gen_ctx : &GeneralContext,
 
     return Ok(canonize_options);
 }
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::transformation::transfokind::InteractionTransformationKind;
+    use super::*;
+
+    #[test]
+    fn specific_phases_test() {
+        let gen_ctx = GeneralContext::new();
+        // "search_all" is declared after the phases and still applies to those which do not set "get_all"
+        let hcf_str = "@canonize_option{
+            phases = [
+                phase[ordered=true] {defactorize_left, defactorize_right, deduplicate},
+                phase[get_all=false,ordered=false] {simpl, flush},
+                phase {simpl, invert_alt, simpl}
+            ];
+            search_all = true
+        }".to_string();
+        let options = parse_hcf_string_for_canonize(&gen_ctx,hcf_str,"test",&vec![]).unwrap();
+        assert!(options.search_all);
+        let phases = options.phases.unwrap();
+        assert_eq!(phases.len(), 3);
+        assert_eq!(phases[0].transfos, vec![InteractionTransformationKind::DeFactorizeLeft,
+                                             InteractionTransformationKind::DeFactorizeRight,
+                                             InteractionTransformationKind::Deduplicate]);
+        assert!(phases[0].get_all);
+        assert!(phases[0].ordered);
+        assert_eq!(phases[1].transfos, vec![InteractionTransformationKind::Simpl,
+                                             InteractionTransformationKind::FlushRight]);
+        assert!(!phases[1].get_all);
+        assert!(!phases[1].ordered);
+        // duplicated transformations are only kept once
+        assert_eq!(phases[2].transfos, vec![InteractionTransformationKind::Simpl,
+                                             InteractionTransformationKind::InvertAlt]);
+        assert!(phases[2].get_all);
+        assert!(!phases[2].ordered);
+    }
+
+}
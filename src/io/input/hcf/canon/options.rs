@@ -22,6 +22,9 @@ use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrate
 use pest::iterators::Pair;
 
 use crate::core::general_context::GeneralContext;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::core::transformation::transfophase::InteractionTransformationPhase;
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
@@ -35,6 +38,7 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 use crate::process::canon::conf::CanonizationConfig;
 use crate::process::canon::filter::elim::CanonizationFilterEliminationKind;
 use crate::process::canon::filter::filter::CanonizationFilterCriterion;
+use crate::process::canon::param::default::DefaultCanonizationProcess;
 use crate::process::canon::priorities::CanonizationPriorities;
 
 
@@ -44,6 +48,8 @@ pub struct HibouCanonizeOptions {
     pub filters : Vec<Box<dyn AbstractFilter<CanonizationFilterCriterion,CanonizationFilterEliminationKind>>>,
     pub priorities : GenericProcessPriorities<CanonizationPriorities>,
     pub search_all : bool,
+    // if None, the default canonization process of the command is used
+    pub phases : Option<Vec<InteractionTransformationPhase>>
 }
 
 
@@ -53,8 +59,9 @@ impl HibouCanonizeOptions {
                strategy : QueueSearchStrategy,
                filters : Vec<Box<dyn AbstractFilter<CanonizationFilterCriterion,CanonizationFilterEliminationKind>>>,
                priorities : GenericProcessPriorities<CanonizationPriorities>,
-           search_all : bool) -> HibouCanonizeOptions {
-        return HibouCanonizeOptions{loggers,strategy,filters,priorities,search_all};
+           search_all : bool,
+           phases : Option<Vec<InteractionTransformationPhase>>) -> HibouCanonizeOptions {
+        return HibouCanonizeOptions{loggers,strategy,filters,priorities,search_all,phases};
     }
 
     pub fn default() -> HibouCanonizeOptions {
@@ -63,7 +70,8 @@ impl HibouCanonizeOptions {
             QueueSearchStrategy::BFS,
             vec![],
             GenericProcessPriorities::new(CanonizationPriorities::default(),false),
-            false
+            false,
+            None
         )
     }
 
@@ -74,9 +82,11 @@ impl HibouCanonizeOptions {
 
 pub fn parse_canonize_options(gen_ctx: &GeneralContext,
                              option_pair : Pair<Rule>,
-                             file_name : &str) -> Result<HibouCanonizeOptions,HibouParsingError> {
+                             file_name : &str,
+                             rules : &Vec<InteractionRewriteRule>) -> Result<HibouCanonizeOptions,HibouParsingError> {
     let mut loggers : Vec<Box<dyn AbstractProcessLogger<CanonizationConfig>>> = Vec::new();
     let mut search_all = false;
+    let mut phases_decl : Option<CanonizationPhasesDeclaration> = None;
     // ***
     for option_decl_pair in option_pair.into_inner() {
         match option_decl_pair.as_rule() {
@@ -86,6 +96,9 @@ pub fn parse_canonize_options(gen_ctx: &GeneralContext,
             Rule::OPTION_CANON_searchall_no => {
                 search_all = false;
             },
+            Rule::OPTION_CANON_PHASES_DECL => {
+                phases_decl = Some(parse_phases_decl(option_decl_pair,rules)?);
+            },
             Rule::OPTION_LOGGER_DECL => {
                 loggers = Vec::new();
                 // ***
@@ -112,11 +125,156 @@ pub fn parse_canonize_options(gen_ctx: &GeneralContext,
     let strategy : QueueSearchStrategy = QueueSearchStrategy::BFS;
     let filters : Vec<Box<dyn AbstractFilter<CanonizationFilterCriterion,CanonizationFilterEliminationKind>>> = Vec::new();
     let priorities : GenericProcessPriorities<CanonizationPriorities> = GenericProcessPriorities::new(CanonizationPriorities::default(),false);
-    let hoptions = HibouCanonizeOptions::new(loggers,strategy,filters,priorities,search_all);
+    // the phases are built at the end given that "search_all" may be declared after them
+    let phases = phases_decl.map(|decl| decl.into_phases(search_all));
+    let hoptions = HibouCanonizeOptions::new(loggers,strategy,filters,priorities,search_all,phases);
     return Ok(hoptions);
 }
 
 
+enum CanonizationPhasesDeclaration {
+    Default(DefaultCanonizationProcess),
+    // for each phase : its transformations, its "get_all" flag if it is specified, and its "ordered" flag
+    Specific(Vec<(Vec<InteractionTransformationKind>,Option<bool>,bool)>)
+}
+
+impl CanonizationPhasesDeclaration {
+    fn into_phases(self, search_all : bool) -> Vec<InteractionTransformationPhase> {
+        match self {
+            CanonizationPhasesDeclaration::Default(def) => {
+                def.get_phases(search_all)
+            },
+            CanonizationPhasesDeclaration::Specific(phases) => {
+                phases.into_iter()
+                    .map(|(transfos,get_all,ordered)|
+                        InteractionTransformationPhase::new(transfos,get_all.unwrap_or(search_all),ordered)
+                    )
+                    .collect()
+            }
+        }
+    }
+}
+
+fn parse_phases_decl(phases_decl_pair : Pair<Rule>,
+                     rules : &Vec<InteractionRewriteRule>) -> Result<CanonizationPhasesDeclaration,HibouParsingError> {
+    let inner = phases_decl_pair.into_inner().next().unwrap();
+    let decl = match inner.as_rule() {
+        Rule::OPTION_CANON_PHASES_basic => {
+            CanonizationPhasesDeclaration::Default(DefaultCanonizationProcess::Basic)
+        },
+        Rule::OPTION_CANON_PHASES_basic_with_unfold_actions => {
+            CanonizationPhasesDeclaration::Default(DefaultCanonizationProcess::BasicWithUnfoldActions)
+        },
+        Rule::OPTION_CANON_PHASES_basic_with_to_seq => {
+            CanonizationPhasesDeclaration::Default(DefaultCanonizationProcess::BasicWithToSeq)
+        },
+        Rule::OPTION_CANON_PHASES_five_phases => {
+            CanonizationPhasesDeclaration::Default(DefaultCanonizationProcess::FivePhases)
+        },
+        Rule::OPTION_CANON_PHASES_specific => {
+            let mut phases = vec![];
+            for phase_pair in inner.into_inner() {
+                phases.push( parse_phase(phase_pair,rules)? );
+            }
+            CanonizationPhasesDeclaration::Specific(phases)
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", inner.as_rule() );
+        }
+    };
+    return Ok(decl);
+}
+
+fn parse_phase(phase_pair : Pair<Rule>,
+               rules : &Vec<InteractionRewriteRule>) -> Result<(Vec<InteractionTransformationKind>,Option<bool>,bool),HibouParsingError> {
+    let mut transfos = vec![];
+    let mut get_all = None;
+    let mut ordered = false;
+    for phase_content_pair in phase_pair.into_inner() {
+        match phase_content_pair.as_rule() {
+            Rule::OPTION_CANON_PHASE_opts => {
+                for opt_pair in phase_content_pair.into_inner() {
+                    let opt_rule = opt_pair.as_rule();
+                    let value = parse_bool(opt_pair.into_inner().next().unwrap());
+                    match opt_rule {
+                        Rule::OPTION_CANON_PHASE_get_all => {
+                            get_all = Some(value);
+                        },
+                        Rule::OPTION_CANON_PHASE_ordered => {
+                            ordered = value;
+                        },
+                        _ => {
+                            panic!("what rule then ? : {:?}", opt_rule );
+                        }
+                    }
+                }
+            },
+            Rule::OPTION_CANON_PHASE_transfos => {
+                for transfo_pair in phase_content_pair.into_inner() {
+                    for transfo in parse_transfo_kinds(transfo_pair,rules)? {
+                        if !transfos.contains(&transfo) {
+                            transfos.push(transfo);
+                        }
+                    }
+                }
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", phase_content_pair.as_rule() );
+            }
+        }
+    }
+    Ok((transfos,get_all,ordered))
+}
+
+fn parse_bool(bool_pair : Pair<Rule>) -> bool {
+    match bool_pair.as_rule() {
+        Rule::HIBOU_true => {
+            true
+        },
+        Rule::HIBOU_false => {
+            false
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", bool_pair.as_rule() );
+        }
+    }
+}
+
+fn parse_transfo_kinds(transfo_pair : Pair<Rule>,
+                       rules : &Vec<InteractionRewriteRule>) -> Result<Vec<InteractionTransformationKind>,HibouParsingError> {
+    match transfo_pair.as_rule() {
+        Rule::OPTION_CANON_TRANSFO_all_rules => {
+            return Ok( rules.iter().map(|r| InteractionTransformationKind::Rule(r.clone())).collect() );
+        },
+        Rule::OPTION_CANON_TRANSFO_rule => {
+            let rule_name = transfo_pair.into_inner().next().unwrap().as_str();
+            match rules.iter().find(|r| r.name == rule_name) {
+                None => {
+                    return Err( HibouParsingError::HcfSetupError(format!("unknown rewrite rule '{}'", rule_name)) );
+                },
+                Some(rule) => {
+                    return Ok( vec![InteractionTransformationKind::Rule(rule.clone())] );
+                }
+            }
+        },
+        _ => {
+            return Ok( vec![parse_transfo_kind(transfo_pair)] );
+        }
+    }
+}
+
+fn parse_transfo_kind(transfo_pair : Pair<Rule>) -> InteractionTransformationKind {
+    match InteractionTransformationKind::from_name(transfo_pair.as_str()) {
+        Some(kind) => {
+            return kind;
+        },
+        None => {
+            panic!("what rule then ? : {:?}", transfo_pair.as_rule() );
+        }
+    }
+}
+
+
 
 
 
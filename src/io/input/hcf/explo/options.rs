@@ -113,6 +113,9 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                                 }
                             }
                         },
+                        Rule::OPTION_WITNESS_LOGGER => {
+                            return Err(HibouParsingError::HcfSetupError("cannot use witness logger for exploration".to_string()));
+                        },
                         _ => {
                             panic!("what rule then ? : {:?}", logger_kind_pair.as_rule() );
                         }
@@ -131,6 +134,9 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                     Rule::OPTION_STRATEGY_HCS => {
                         strategy = QueueSearchStrategy::HCS;
                     },
+                    Rule::OPTION_STRATEGY_GREEDY_DFS => {
+                        return Err(HibouParsingError::HcfSetupError("the GreedyDFS strategy is only available for trace analysis".to_string()));
+                    },
                     _ => {
                         panic!("what rule then ? : {:?}", strategy_pair.as_rule() );
                     }
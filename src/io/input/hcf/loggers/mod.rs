@@ -16,4 +16,5 @@ limitations under the License.
 
 pub mod graphviz;
 pub mod tracegen;
-pub mod nfait;
\ No newline at end of file
+pub mod nfait;
+pub mod witness;
\ No newline at end of file
@@ -0,0 +1,90 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use graph_process_manager_loggers::stepstrace::logger::GenericStepsTraceLogger;
+use graph_process_manager_loggers::stepstrace::printer::StepsTraceProcessPrinter;
+
+use pest::iterators::Pair;
+
+use crate::io::file_extensions::HIBOU_WITNESS_FILE_EXTENSION;
+
+
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+use crate::loggers::witness::object::WitnessLoggerObject;
+use crate::loggers::witness::printer::WitnessProcessPrinter;
+
+
+pub fn parse_witness_logger<Conf : AbstractProcessConfiguration>(logger_id : u32,
+                                                                 logger_kind_pair : Pair<Rule>)
+        -> GenericStepsTraceLogger<Conf,WitnessLoggerObject>
+        where
+            WitnessProcessPrinter : StepsTraceProcessPrinter<Conf,WitnessLoggerObject>  {
+    // default configuration
+    let mut no_duplicates_via_memoization = false;
+    let mut parent_folder = format!("witness_l{:}", logger_id);
+    let mut files_prefix = "witness".to_string();
+    // ***
+    match logger_kind_pair.into_inner().next() {
+        None => {
+            // nothing
+        },
+        Some(witness_logger_opts) => {
+            for opt_pair in witness_logger_opts.into_inner() {
+                match opt_pair.as_rule() {
+                    Rule::WITNESS_LOGGER_parent_folder => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        parent_folder = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    Rule::WITNESS_LOGGER_witness_prefix => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        files_prefix = inner_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    },
+                    Rule::WITNESS_LOGGER_no_duplicate => {
+                        let inner_pair = opt_pair.into_inner().next().unwrap();
+                        match inner_pair.as_rule() {
+                            Rule::HIBOU_true => {
+                                no_duplicates_via_memoization = true;
+                            },
+                            Rule::HIBOU_false => {
+                                no_duplicates_via_memoization = false;
+                            },
+                            _ => {
+                                panic!("what rule then ? : {:?}", inner_pair.as_rule());
+                            }
+                        }
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", opt_pair.as_rule());
+                    }
+                }
+            }
+        }
+    }
+    let printer = WitnessProcessPrinter::new();
+    return GenericStepsTraceLogger::new(Box::new(printer),
+                                        no_duplicates_via_memoization,
+                                        files_prefix,
+                                        HIBOU_WITNESS_FILE_EXTENSION.to_string(),
+                                        parent_folder);
+}
@@ -44,4 +44,6 @@ pub fn parse_hif_file(gen_ctx : &GeneralContext, file_path : &str) -> Result<Int
     }
 }
 
-
+pub fn interaction_from_text(gen_ctx : &GeneralContext, hif_str : &str) -> Result<Interaction,HibouParsingError> {
+    return parse_hif_string(gen_ctx,hif_str.to_string());
+}
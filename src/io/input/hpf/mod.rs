@@ -15,12 +15,6 @@ limitations under the License.
 */
 
 
-pub mod transformations;
+mod parser;
+mod proof;
 pub mod interface;
-pub mod manager;
-
-
-
-
-
-
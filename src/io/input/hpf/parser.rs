@@ -16,16 +16,11 @@ limitations under the License.
 
 
 
+#[allow(unused_imports)]
+use pest::Parser;
 
 
-use crate::core::language::syntax::interaction::Interaction;
-use crate::process::abstract_proc::generic::AbstractNodeKind;
-use crate::process::canon_proc::transformations::phases::CanonizationPhase;
-
-pub struct CanonizationNodeKind {
-    pub interaction : Interaction,
-    pub phase : CanonizationPhase
-}
-
-impl AbstractNodeKind for CanonizationNodeKind {}
 
+#[derive(Parser)]
+#[grammar = "io/input/hpf/hpf_syntax.pest"]
+pub struct HpfParser;
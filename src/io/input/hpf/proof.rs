@@ -0,0 +1,142 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use pest::iterators::Pair;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
+use crate::core::transformation::proof::certificate::InteractionEquivalenceCertificate;
+use crate::core::transformation::proof::derivation::{InteractionDerivation, InteractionDerivationStep};
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hif::interface::interaction_from_text;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hpf::parser::{HpfParser,Rule};
+
+
+pub fn parse_hpf_string(gen_ctx : &GeneralContext,
+                        hpf_string : String,
+                        rules : &Vec<InteractionRewriteRule>) -> Result<InteractionEquivalenceCertificate,HibouParsingError> {
+    match HpfParser::parse(Rule::HPF_PEST_FILE, &hpf_string) {
+        Ok( got_pairs ) => {
+            let mut derivations = vec![];
+            for deriv_pair in got_pairs {
+                match deriv_pair.as_rule() {
+                    Rule::PROOF_DERIVATION => {
+                        derivations.push( parse_derivation(gen_ctx,deriv_pair,rules)? );
+                    },
+                    Rule::EOI => {},
+                    _ => {
+                        panic!("what rule then ? : {:?}", deriv_pair.as_rule() );
+                    }
+                }
+            }
+            return Ok( InteractionEquivalenceCertificate::new(derivations) );
+        },
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        }
+    }
+}
+
+fn parse_derivation(gen_ctx : &GeneralContext,
+                    deriv_pair : Pair<Rule>,
+                    rules : &Vec<InteractionRewriteRule>) -> Result<InteractionDerivation,HibouParsingError> {
+    let mut content = deriv_pair.into_inner();
+    let initial_pair = content.next().unwrap().into_inner().next().unwrap();
+    let initial = interaction_from_text(gen_ctx,initial_pair.as_str().trim())?;
+    let mut steps : Vec<InteractionDerivationStep> = vec![];
+    for step_pair in content {
+        let mut step_content = step_pair.into_inner();
+        let kind = parse_transformation_kind(step_content.next().unwrap(),rules)?;
+        let position = parse_position(step_content.next().unwrap());
+        let after = interaction_from_text(gen_ctx,step_content.next().unwrap().as_str().trim())?;
+        // the step is applied to the interaction obtained at the previous step
+        let before = match steps.last() {
+            None => initial.clone(),
+            Some(prev) => prev.after.clone()
+        };
+        steps.push( InteractionDerivationStep::new(kind,position,before,after) );
+    }
+    return Ok( InteractionDerivation::new(initial,steps) );
+}
+
+fn parse_transformation_kind(kind_pair : Pair<Rule>,
+                             rules : &Vec<InteractionRewriteRule>) -> Result<InteractionTransformationKind,HibouParsingError> {
+    let inner = kind_pair.into_inner().next().unwrap();
+    match inner.as_rule() {
+        Rule::PROOF_KIND_RULE => {
+            let rule_name = inner.into_inner().next().unwrap().as_str();
+            match rules.iter().find(|r| r.name == rule_name) {
+                None => {
+                    return Err( HibouParsingError::RewriteRuleDefinitionError(format!("unknown rewrite rule '{}'", rule_name)) );
+                },
+                Some(rule) => {
+                    return Ok( InteractionTransformationKind::Rule(rule.clone()) );
+                }
+            }
+        },
+        Rule::HIBOU_LABEL => {
+            let kind_name = inner.as_str();
+            match InteractionTransformationKind::from_name(kind_name) {
+                None => {
+                    return Err( HibouParsingError::OtherDefinitionError(format!("unknown transformation '{}'", kind_name)) );
+                },
+                Some(kind) => {
+                    return Ok(kind);
+                }
+            }
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", inner.as_rule() );
+        }
+    }
+}
+
+fn parse_position(position_pair : Pair<Rule>) -> Position {
+    match position_pair.as_rule() {
+        Rule::PROOF_POSITION_EPSILON => {
+            return Position::Epsilon(None);
+        },
+        Rule::PROOF_POSITION_SUB => {
+            let sub_idx : usize = position_pair.into_inner().next().unwrap().as_str().parse::<usize>().unwrap();
+            return Position::Epsilon(Some(sub_idx));
+        },
+        Rule::PROOF_POSITION_LEFT => {
+            let sub_pos = parse_position(position_pair.into_inner().next().unwrap());
+            return Position::Left(Box::new(sub_pos));
+        },
+        Rule::PROOF_POSITION_RIGHT => {
+            let sub_pos = parse_position(position_pair.into_inner().next().unwrap());
+            return Position::Right(Box::new(sub_pos));
+        },
+        Rule::PROOF_POSITION_BOTH => {
+            let mut content = position_pair.into_inner();
+            let sub_pos1 = parse_position(content.next().unwrap());
+            let sub_pos2 = parse_position(content.next().unwrap());
+            return Position::Both(Box::new(sub_pos1),Box::new(sub_pos2));
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", position_pair.as_rule() );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::Path;
+
+
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hrf::rules::parse_hrf_string;
+
+use crate::io::file_extensions::HIBOU_REWRITE_RULES_FILE_EXTENSION;
+
+
+pub fn parse_hrf_file(file_path : &str) -> Result<Vec<InteractionRewriteRule>,HibouParsingError> {
+    let path_object = Path::new(file_path);
+    let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension != HIBOU_REWRITE_RULES_FILE_EXTENSION {
+        return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_REWRITE_RULES_FILE_EXTENSION.to_string()));
+    }
+    match fs::read_to_string(file_path) {
+        Ok( unparsed_hrf_str ) => {
+            return parse_hrf_string(unparsed_hrf_str);
+        },
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        }
+    }
+}
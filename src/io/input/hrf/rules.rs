@@ -0,0 +1,204 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use pest::iterators::Pair;
+
+use crate::core::language::syntax::interaction::LoopKind;
+use crate::core::transformation::rewrite::condition::RewriteCondition;
+use crate::core::transformation::rewrite::pattern::InteractionPattern;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::io::input::error::HibouParsingError;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hrf::parser::{HrfParser,Rule};
+
+
+pub fn parse_hrf_string(hrf_string : String) -> Result<Vec<InteractionRewriteRule>,HibouParsingError> {
+    match HrfParser::parse(Rule::HRF_PEST_FILE, &hrf_string) {
+        Ok( got_pairs ) => {
+            let mut rules : Vec<InteractionRewriteRule> = vec![];
+            for rule_pair in got_pairs {
+                match rule_pair.as_rule() {
+                    Rule::REWRITE_RULE => {
+                        let rule = parse_rewrite_rule(rule_pair)?;
+                        if rules.iter().any(|r| r.name == rule.name) {
+                            return Err( HibouParsingError::RewriteRuleDefinitionError(format!("several rules named '{}'", rule.name)) );
+                        }
+                        rules.push(rule);
+                    },
+                    Rule::EOI => {},
+                    _ => {
+                        panic!("what rule then ? : {:?}", rule_pair.as_rule() );
+                    }
+                }
+            }
+            return Ok( rules );
+        },
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        }
+    }
+}
+
+fn parse_rewrite_rule(rule_pair : Pair<Rule>) -> Result<InteractionRewriteRule,HibouParsingError> {
+    let mut content = rule_pair.into_inner();
+    let name = content.next().unwrap().as_str().to_string();
+    let lhs = parse_pattern(content.next().unwrap().into_inner().next().unwrap());
+    let rhs = parse_pattern(content.next().unwrap().into_inner().next().unwrap());
+    let conditions : Vec<RewriteCondition> = content.map(parse_condition).collect();
+    // ***
+    let lhs_vars = lhs.get_variables();
+    let mut other_vars : BTreeSet<String> = rhs.get_variables();
+    for cond in &conditions {
+        other_vars.extend(cond.get_variables());
+    }
+    if let Some(unbound) = other_vars.difference(&lhs_vars).next() {
+        return Err( HibouParsingError::RewriteRuleDefinitionError(
+            format!("variable '{}' of rule '{}' does not occur in its left-hand side", unbound, name)) );
+    }
+    return Ok( InteractionRewriteRule::new(name,lhs,rhs,conditions) );
+}
+
+fn parse_pattern(pattern_pair : Pair<Rule>) -> InteractionPattern {
+    match pattern_pair.as_rule() {
+        Rule::PATTERN_VARIABLE => {
+            InteractionPattern::Variable(pattern_pair.as_str().to_string())
+        },
+        Rule::PATTERN_EMPTY => {
+            InteractionPattern::Empty
+        },
+        Rule::PATTERN_LOOP => {
+            let mut content = pattern_pair.into_inner();
+            let lk = match content.next().unwrap().as_rule() {
+                Rule::PATTERN_LOOP_KIND_S => LoopKind::SStrictSeq,
+                Rule::PATTERN_LOOP_KIND_H => LoopKind::HHeadFirstWS,
+                Rule::PATTERN_LOOP_KIND_W => LoopKind::WWeakSeq,
+                Rule::PATTERN_LOOP_KIND_P => LoopKind::PInterleaving,
+                other => {
+                    panic!("what rule then ? : {:?}", other );
+                }
+            };
+            let sub = parse_pattern(content.next().unwrap());
+            InteractionPattern::Loop(lk,Box::new(sub))
+        },
+        Rule::PATTERN_STRICT | Rule::PATTERN_SEQ | Rule::PATTERN_ALT | Rule::PATTERN_PAR => {
+            let op_rule = pattern_pair.as_rule();
+            let mut sub_patterns : Vec<InteractionPattern> = pattern_pair.into_inner().map(parse_pattern).collect();
+            // n-ary operators are folded to the right, as in .hif files
+            let mut folded = sub_patterns.pop().unwrap();
+            while let Some(sub) = sub_patterns.pop() {
+                folded = match op_rule {
+                    Rule::PATTERN_STRICT => InteractionPattern::Strict(Box::new(sub),Box::new(folded)),
+                    Rule::PATTERN_SEQ => InteractionPattern::Seq(Box::new(sub),Box::new(folded)),
+                    Rule::PATTERN_ALT => InteractionPattern::Alt(Box::new(sub),Box::new(folded)),
+                    _ => InteractionPattern::Par(Box::new(sub),Box::new(folded))
+                };
+            }
+            folded
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", pattern_pair.as_rule() );
+        }
+    }
+}
+
+fn parse_condition(condition_pair : Pair<Rule>) -> RewriteCondition {
+    let cond_rule = condition_pair.as_rule();
+    if let Rule::CONDITION_NOT = cond_rule {
+        let sub = parse_condition(condition_pair.into_inner().next().unwrap());
+        return RewriteCondition::Not(Box::new(sub));
+    }
+    let vars : Vec<String> = condition_pair.into_inner().map(|p| p.as_str().to_string()).collect();
+    match cond_rule {
+        Rule::CONDITION_AVOIDS => {
+            RewriteCondition::Avoids(vars[0].clone(),vars[1].clone())
+        },
+        Rule::CONDITION_EXPRESS_EMPTY => {
+            RewriteCondition::ExpressEmpty(vars[0].clone())
+        },
+        Rule::CONDITION_EQUAL => {
+            RewriteCondition::Equal(vars[0].clone(),vars[1].clone())
+        },
+        Rule::CONDITION_DIFFERENT => {
+            RewriteCondition::Not(Box::new(RewriteCondition::Equal(vars[0].clone(),vars[1].clone())))
+        },
+        Rule::CONDITION_LOWER => {
+            RewriteCondition::Lower(vars[0].clone(),vars[1].clone())
+        },
+        Rule::CONDITION_GREATER => {
+            RewriteCondition::Lower(vars[1].clone(),vars[0].clone())
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", cond_rule );
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use crate::core::language::syntax::interaction::Interaction;
+    use super::*;
+
+    fn emission(lf_id : usize, ms_id : usize) -> Interaction {
+        Interaction::Emission(EmissionAction::new(lf_id,ms_id,CommunicationSynchronicity::Asynchronous,vec![]))
+    }
+
+    fn alt_of(i1 : Interaction, i2 : Interaction) -> Interaction {
+        Interaction::Alt(Box::new(i1),Box::new(i2))
+    }
+
+    #[test]
+    fn rewrite_rules_application_test() {
+        let rules = parse_hrf_string("
+            alt_idem : alt(X,X) -> X;
+            seq_to_par : seq(X,Y) -> par(X,Y) if X avoids Y;
+            /* non-linear patterns and negated conditions */
+            loop_alt : loopS(alt(X,Y)) -> loopS(Y) if not X != Y;
+        ".to_string()).unwrap();
+        assert_eq!(rules.len(), 3);
+        // ***
+        let alt = alt_of(emission(0,0),emission(0,0));
+        assert_eq!(rules[0].apply(&alt), vec![emission(0,0)]);
+        let alt = alt_of(emission(0,0),emission(0,1));
+        assert!(rules[0].apply(&alt).is_empty());
+        // ***
+        let seq = Interaction::Seq(Box::new(emission(0,0)),Box::new(emission(1,1)));
+        assert_eq!(rules[1].apply(&seq), vec![Interaction::Par(Box::new(emission(0,0)),Box::new(emission(1,1)))]);
+        let seq = Interaction::Seq(Box::new(emission(0,0)),Box::new(emission(0,1)));
+        assert!(rules[1].apply(&seq).is_empty());
+        // ***
+        let lp = Interaction::Loop(LoopKind::SStrictSeq,Box::new(alt_of(emission(0,0),emission(0,0))));
+        assert_eq!(rules[2].apply(&lp), vec![Interaction::Loop(LoopKind::SStrictSeq,Box::new(emission(0,0)))]);
+        let lp = Interaction::Loop(LoopKind::WWeakSeq,Box::new(alt_of(emission(0,0),emission(0,0))));
+        assert!(rules[2].apply(&lp).is_empty());
+    }
+
+    #[test]
+    fn rewrite_rules_unbound_variable_test() {
+        let got = parse_hrf_string("bad : seq(X,o) -> seq(X,Y);".to_string());
+        assert!(got.is_err());
+    }
+
+}
@@ -0,0 +1,112 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use pest::iterators::Pair;
+
+use crate::core::execution::interpretation::witness::{ExecutionWitness, WitnessStep};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::htf::interface::multi_action_from_text;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hwf::parser::{HwfParser,Rule};
+
+
+pub fn parse_hwf_string(gen_ctx : &GeneralContext, hwf_string : String) -> Result<ExecutionWitness,HibouParsingError> {
+    match HwfParser::parse(Rule::HWF_PEST_FILE, &hwf_string) {
+        Ok( got_pairs ) => {
+            let mut steps = vec![];
+            for step_pair in got_pairs {
+                match step_pair.as_rule() {
+                    Rule::WITNESS_EXECUTE => {
+                        steps.push( parse_execution_step(gen_ctx,step_pair)? );
+                    },
+                    Rule::WITNESS_HIDE => {
+                        steps.push( parse_hiding_step(gen_ctx,step_pair)? );
+                    },
+                    Rule::EOI => {},
+                    _ => {
+                        panic!("what rule then ? : {:?}", step_pair.as_rule() );
+                    }
+                }
+            }
+            return Ok( ExecutionWitness::new(steps) );
+        },
+        Err(e) => {
+            return Err( HibouParsingError::MatchError(e.to_string()) );
+        }
+    }
+}
+
+fn parse_execution_step(gen_ctx : &GeneralContext, step_pair : Pair<Rule>) -> Result<WitnessStep,HibouParsingError> {
+    let mut content = step_pair.into_inner();
+    let position = parse_position(content.next().unwrap());
+    let actions_str = content.next().unwrap().as_str().trim().to_string();
+    let actions = multi_action_from_text(gen_ctx,&actions_str)?;
+    return Ok( WitnessStep::Execute(position,actions) );
+}
+
+fn parse_hiding_step(gen_ctx : &GeneralContext, step_pair : Pair<Rule>) -> Result<WitnessStep,HibouParsingError> {
+    let mut lfs_to_hide : BTreeSet<usize> = btreeset!{};
+    for lf_pair in step_pair.into_inner() {
+        let lf_name = lf_pair.as_str();
+        match gen_ctx.get_lf_id(lf_name) {
+            None => {
+                return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+            },
+            Some( lf_id ) => {
+                lfs_to_hide.insert(lf_id);
+            }
+        }
+    }
+    return Ok( WitnessStep::Hide(lfs_to_hide) );
+}
+
+fn parse_position(position_pair : Pair<Rule>) -> Position {
+    match position_pair.as_rule() {
+        Rule::WITNESS_POSITION_EPSILON => {
+            return Position::Epsilon(None);
+        },
+        Rule::WITNESS_POSITION_SUB => {
+            let sub_idx : usize = position_pair.into_inner().next().unwrap().as_str().parse::<usize>().unwrap();
+            return Position::Epsilon(Some(sub_idx));
+        },
+        Rule::WITNESS_POSITION_LEFT => {
+            let sub_pos = parse_position(position_pair.into_inner().next().unwrap());
+            return Position::Left(Box::new(sub_pos));
+        },
+        Rule::WITNESS_POSITION_RIGHT => {
+            let sub_pos = parse_position(position_pair.into_inner().next().unwrap());
+            return Position::Right(Box::new(sub_pos));
+        },
+        Rule::WITNESS_POSITION_BOTH => {
+            let mut content = position_pair.into_inner();
+            let sub_pos1 = parse_position(content.next().unwrap());
+            let sub_pos2 = parse_position(content.next().unwrap());
+            return Position::Both(Box::new(sub_pos1),Box::new(sub_pos2));
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", position_pair.as_rule() );
+        }
+    }
+}
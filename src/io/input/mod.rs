@@ -19,6 +19,9 @@ pub mod hsf;
 pub mod hcf;
 pub mod htf;
 pub mod hif;
+pub mod hwf;
+pub mod hrf;
+pub mod hpf;
 
 
 
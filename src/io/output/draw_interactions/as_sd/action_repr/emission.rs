@@ -28,7 +28,7 @@ use imageproc::rect::Rect;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::{EmissionAction, EmissionTargetRef};
 use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Message, HCP_Black};
+use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
 use crate::io::output::draw_interactions::as_sd::action_repr::common::draw_line_for_message_exchange;
 use crate::io::output::draw_interactions::as_sd::util::arrow_heads::{draw_arrowhead_leftward, draw_arrowhead_rightward};
@@ -41,7 +41,8 @@ pub fn draw_emission( image : &mut RgbImage,
                     gen_ctx: &GeneralContext,
                     em_act : &EmissionAction,
                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
-                    yshift : u32) -> [usize;2] {
+                    yshift : u32,
+                    msg_color : [u8;3]) -> [usize;2] {
     // ***
     let mut min_lf_id : usize = em_act.origin_lf_id;
     let mut max_lf_id : usize = em_act.origin_lf_id;
@@ -49,7 +50,7 @@ pub fn draw_emission( image : &mut RgbImage,
     let msg_to_print : Vec<TextToPrint>;
     {
         let msg_label = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
-        msg_to_print = vec![TextToPrint::new(msg_label,Rgb(HC_Message))];
+        msg_to_print = vec![TextToPrint::new(msg_label,Rgb(msg_color))];
     }
     // ***
     let text_y_pos = get_y_pos_from_yshift(yshift) + VERTICAL_SIZE/2.0;
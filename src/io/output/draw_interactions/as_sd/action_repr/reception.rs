@@ -27,7 +27,7 @@ use imageproc::rect::Rect;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::ReceptionAction;
 use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
-use crate::io::output::draw_commons::hibou_color_palette::{HC_Message, HCP_Black};
+use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
 use crate::io::output::draw_interactions::as_sd::action_repr::common::draw_line_for_message_exchange;
 use crate::io::output::draw_interactions::as_sd::util::arrow_heads::draw_arrowhead_rightward;
@@ -40,7 +40,8 @@ pub fn draw_reception( image : &mut RgbImage,
                     gen_ctx: &GeneralContext,
                     rc_act : &ReceptionAction,
                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
-                    yshift : u32) -> [usize;2] {
+                    yshift : u32,
+                    msg_color : [u8;3]) -> [usize;2] {
     // ***
     let mut min_lf_id : usize = gen_ctx.get_lf_num();
     let mut max_lf_id : usize = 0;
@@ -48,7 +49,7 @@ pub fn draw_reception( image : &mut RgbImage,
     let msg_to_print : Vec<TextToPrint>;
     {
         let msg_label = gen_ctx.get_ms_name(rc_act.ms_id).unwrap();
-        msg_to_print = vec![TextToPrint::new(msg_label,Rgb(HC_Message))];
+        msg_to_print = vec![TextToPrint::new(msg_label,Rgb(msg_color))];
     }
     // ***
     let text_y_pos = get_y_pos_from_yshift(yshift) + VERTICAL_SIZE/2.0;
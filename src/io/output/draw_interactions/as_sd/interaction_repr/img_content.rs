@@ -24,13 +24,14 @@ use imageproc::drawing::draw_line_segment_mut;
 
 
 use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
 use crate::core::language::syntax::interaction::{Interaction, LoopKind};
-use crate::core::language::syntax::util::get_recursive_frag::{get_recursive_strict_frags, get_recursive_par_frags, get_recursive_alt_frags, get_recursive_coreg_frags, get_recursive_sync_frags};
 use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
 use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
 use crate::io::output::draw_interactions::as_sd::action_repr::emission::draw_emission;
 use crate::io::output::draw_interactions::as_sd::action_repr::reception::draw_reception;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_y_pos_from_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_actions;
@@ -42,6 +43,8 @@ use crate::io::textual_convention::*;
 pub fn draw_interaction_rec(    image : &mut RgbImage,
                                 gen_ctx : &GeneralContext,
                                 interaction : &Interaction,
+                                position : Position,
+                                highlighting : &SequenceDiagramHighlighting,
                                 lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                 lf_num : usize,
                                 nest_shift : &mut u32,
@@ -52,71 +55,69 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
             return [lf_num,0]; // because when going up we keep the minimum on the left and maximum on the right
         },
         &Interaction::Emission(ref em_act) => {
-            let lr_bounds = draw_emission(image,gen_ctx,em_act,lf_x_widths,*yshift);
+            let msg_color = highlighting.get_action_color(&position);
+            let lr_bounds = draw_emission(image,gen_ctx,em_act,lf_x_widths,*yshift,msg_color);
             *yshift = *yshift + 3;
             return lr_bounds;
         },
         &Interaction::Reception(ref rc_act) => {
-            let lr_bounds = draw_reception(image,gen_ctx,rc_act,lf_x_widths,*yshift);
+            let msg_color = highlighting.get_action_color(&position);
+            let lr_bounds = draw_reception(image,gen_ctx,rc_act,lf_x_widths,*yshift,msg_color);
             *yshift = *yshift + 3;
             return lr_bounds;
         },
         &Interaction::Seq(ref i1,ref i2) => {
-            let wr1 : [usize;2] = draw_interaction_rec(image, gen_ctx,i1, lf_x_widths,  lf_num,nest_shift, yshift);
+            let wr1 : [usize;2] = draw_interaction_rec(image, gen_ctx,i1, position.get_left_child(), highlighting, lf_x_widths,  lf_num,nest_shift, yshift);
             *yshift = *yshift +1;
-            let wr2 : [usize;2] = draw_interaction_rec(image,  gen_ctx,i2, lf_x_widths,  lf_num,nest_shift, yshift);
+            let wr2 : [usize;2] = draw_interaction_rec(image,  gen_ctx,i2, position.get_right_child(), highlighting, lf_x_widths,  lf_num,nest_shift, yshift);
             return [ std::cmp::min(wr1[0],wr2[0]) , std::cmp::max(wr1[1],wr2[1]) ];
         },
-        &Interaction::Strict(ref i1,ref i2) => {
-            let mut frags = get_recursive_strict_frags(i1);
-            frags.extend( get_recursive_strict_frags(i2) );
-            let label = vec![TextToPrint::new(SYNTAX_STRICT.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+        &Interaction::Strict(_,_) => {
+            let label = get_fragment_label(SYNTAX_STRICT.to_string(),&position,highlighting);
+            let frags = get_recursive_frags_with_positions(interaction,interaction,position);
+            return draw_n_ary_combined_fragment(image, gen_ctx,frags,highlighting,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
-        &Interaction::CoReg(ref cr, ref i1,ref i2) => {
-            let mut frags = get_recursive_coreg_frags(cr, i1);
-            frags.extend( get_recursive_coreg_frags(cr,i2) );
-            return draw_n_ary_coregion(image, gen_ctx,frags,cr,lf_x_widths, lf_num, nest_shift, yshift);
+        &Interaction::CoReg(ref cr, _,_) => {
+            let frags = get_recursive_frags_with_positions(interaction,interaction,position);
+            return draw_n_ary_coregion(image, gen_ctx,frags,highlighting,cr,lf_x_widths, lf_num, nest_shift, yshift);
         },
-        &Interaction::Alt(ref i1,ref i2) => {
-            let mut frags = get_recursive_alt_frags(i1);
-            frags.extend( get_recursive_alt_frags(i2) );
-            let label = vec![TextToPrint::new(SYNTAX_ALT.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+        &Interaction::Alt(_,_) => {
+            let label = get_fragment_label(SYNTAX_ALT.to_string(),&position,highlighting);
+            let frags = get_recursive_frags_with_positions(interaction,interaction,position);
+            return draw_n_ary_combined_fragment(image, gen_ctx,frags,highlighting,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
-        &Interaction::Par(ref i1,ref i2) => {
-            let mut frags = get_recursive_par_frags(i1);
-            frags.extend( get_recursive_par_frags(i2) );
-            let label = vec![TextToPrint::new(SYNTAX_PAR.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+        &Interaction::Par(_,_) => {
+            let label = get_fragment_label(SYNTAX_PAR.to_string(),&position,highlighting);
+            let frags = get_recursive_frags_with_positions(interaction,interaction,position);
+            return draw_n_ary_combined_fragment(image, gen_ctx,frags,highlighting,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
-        &Interaction::Sync(ref sync_acts, ref i1,ref i2) => {
-            let mut frags = get_recursive_sync_frags(sync_acts,i1);
-            frags.extend( get_recursive_sync_frags(sync_acts,i2) );
+        &Interaction::Sync(ref sync_acts, _,_) => {
             let mut label = vec![TextToPrint::new(SYNTAX_SYNC.to_string(),Rgb(HCP_Black))];
             let sync_acts_as_set = BTreeSet::from_iter(sync_acts.iter().cloned());
             label.append(&mut diagram_repr_trace_actions(&sync_acts_as_set,gen_ctx,true));
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            if let Some(annotation) = highlighting.fragments_annotations.get(&position) {
+                label.push(TextToPrint::new(format!(" {}",annotation),Rgb(highlighting.annotations_color)));
+            }
+            let frags = get_recursive_frags_with_positions(interaction,interaction,position);
+            return draw_n_ary_combined_fragment(image, gen_ctx,frags,highlighting,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         &Interaction::Loop(ref lkind, ref i1) => {
-            match lkind {
+            let loop_syntax = match lkind {
                 LoopKind::SStrictSeq => {
-                    let label = vec![TextToPrint::new(SYNTAX_LOOP_S.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    SYNTAX_LOOP_S
                 },
                 LoopKind::HHeadFirstWS => {
-                    let label = vec![TextToPrint::new(SYNTAX_LOOP_H.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    SYNTAX_LOOP_H
                 },
                 LoopKind::WWeakSeq => {
-                    let label = vec![TextToPrint::new(SYNTAX_LOOP_W.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    SYNTAX_LOOP_W
                 },
                 LoopKind::PInterleaving => {
-                    let label = vec![TextToPrint::new(SYNTAX_LOOP_P.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    SYNTAX_LOOP_P
                 }
-            }
+            };
+            let label = get_fragment_label(loop_syntax.to_string(),&position,highlighting);
+            return draw_unary_combined_fragment(image,  gen_ctx,i1,position.get_left_child(),highlighting,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         _ => {
             panic!("non-conform interaction");
@@ -124,9 +125,65 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
     }
 }
 
+fn get_fragment_label(operator_syntax : String,
+                      position : &Position,
+                      highlighting : &SequenceDiagramHighlighting) -> Vec<TextToPrint> {
+    let mut label = vec![TextToPrint::new(operator_syntax,Rgb(HCP_Black))];
+    if let Some(annotation) = highlighting.fragments_annotations.get(position) {
+        label.push(TextToPrint::new(format!(" {}",annotation),Rgb(highlighting.annotations_color)));
+    }
+    return label;
+}
+
+// same as the "get_recursive_*_frags" functions except that the positions of the fragments are also returned
+fn get_recursive_frags_with_positions<'lifetime>(parent : &Interaction,
+                                                 interaction : &'lifetime Interaction,
+                                                 position : Position) -> Vec<(&'lifetime Interaction,Position)> {
+    let sub_ints : Option<(&Interaction,&Interaction)> = match (parent,interaction) {
+        (&Interaction::Strict(_,_), &Interaction::Strict(ref i1, ref i2)) => {
+            Some((i1,i2))
+        },
+        (&Interaction::Alt(_,_), &Interaction::Alt(ref i1, ref i2)) => {
+            Some((i1,i2))
+        },
+        (&Interaction::Par(_,_), &Interaction::Par(ref i1, ref i2)) => {
+            Some((i1,i2))
+        },
+        (&Interaction::CoReg(ref ref_cr,_,_), &Interaction::CoReg(ref cr, ref i1, ref i2)) => {
+            if cr == ref_cr {
+                Some((i1,i2))
+            } else {
+                None
+            }
+        },
+        (&Interaction::Sync(ref ref_sync_acts,_,_), &Interaction::Sync(ref sync_acts, ref i1, ref i2)) => {
+            if sync_acts == ref_sync_acts {
+                Some((i1,i2))
+            } else {
+                None
+            }
+        },
+        _ => {
+            None
+        }
+    };
+    match sub_ints {
+        None => {
+            return vec![(interaction,position)];
+        },
+        Some((i1,i2)) => {
+            let mut frags = get_recursive_frags_with_positions(parent,i1,position.get_left_child());
+            frags.extend( get_recursive_frags_with_positions(parent,i2,position.get_right_child()) );
+            return frags;
+        }
+    }
+}
+
 fn draw_unary_combined_fragment(    image : &mut RgbImage,
                                     gen_ctx : &GeneralContext,
                                     i1 : &Interaction,
+                                    position : Position,
+                                    highlighting : &SequenceDiagramHighlighting,
                                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                     lf_num : usize,
                                     label : Vec<TextToPrint>,
@@ -136,7 +193,7 @@ fn draw_unary_combined_fragment(    image : &mut RgbImage,
     *nest_shift += 1;
     let start_y : u32 = *yshift;
     *yshift += 3;
-    let lr_bounds : [usize;2] = draw_interaction_rec(image,  gen_ctx,i1, lf_x_widths,  lf_num,nest_shift, yshift);
+    let lr_bounds : [usize;2] = draw_interaction_rec(image,  gen_ctx,i1, position, highlighting, lf_x_widths,  lf_num,nest_shift, yshift);
     *yshift += 1;
     let end_y : u32 = *yshift;
     *nest_shift -= 1;
@@ -148,7 +205,8 @@ fn draw_unary_combined_fragment(    image : &mut RgbImage,
 
 fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
                                   gen_ctx : &GeneralContext,
-                                  sub_ints : Vec<&Interaction>,
+                                  sub_ints : Vec<(&Interaction,Position)>,
+                                  highlighting : &SequenceDiagramHighlighting,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                   lf_num : usize,
                                   label : Vec<TextToPrint>,
@@ -162,9 +220,9 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
     //
     let mut min_lf_id : usize = gen_ctx.get_lf_num();
     let mut max_lf_id : usize = 0;
-    for my_int in sub_ints {
+    for (my_int,my_pos) in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, my_pos, highlighting, lf_x_widths,  lf_num,nest_shift, yshift);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -180,7 +238,8 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
 
 fn draw_n_ary_coregion(  image : &mut RgbImage,
                                   gen_ctx : &GeneralContext,
-                                  sub_ints : Vec<&Interaction>,
+                                  sub_ints : Vec<(&Interaction,Position)>,
+                                  highlighting : &SequenceDiagramHighlighting,
                                   coreg_ids : &Vec<usize>,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                   lf_num : usize,
@@ -194,9 +253,9 @@ fn draw_n_ary_coregion(  image : &mut RgbImage,
     //
     let mut min_lf_id : usize = gen_ctx.get_lf_num();
     let mut max_lf_id : usize = 0;
-    for my_int in sub_ints {
+    for (my_int,my_pos) in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, my_pos, highlighting, lf_x_widths,  lf_num,nest_shift, yshift);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -21,16 +21,19 @@ use image::RgbImage;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::involve::involves::InvolvesLifelines;
+use crate::core::language::position::position::Position;
 use crate::core::language::syntax::interaction::Interaction;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
 use crate::io::output::draw_interactions::as_sd::interaction_repr::img_content::draw_interaction_rec;
 use crate::io::output::draw_interactions::as_sd::interaction_repr::img_frame::{draw_frame, draw_lifelines};
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_interaction_max_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 
 
-pub fn make_interaction_image(gen_ctx : &GeneralContext,
-                              interaction : &Interaction) -> RgbImage {
+pub fn make_highlighted_interaction_image(gen_ctx : &GeneralContext,
+                                          interaction : &Interaction,
+                                          highlighting : &SequenceDiagramHighlighting) -> RgbImage {
     // ***
     let mut lf_x_widths : HashMap<usize,DrawingLifelineCoords> = HashMap::new();
     let mut current_x : f32 = MARGIN;
@@ -65,7 +68,7 @@ pub fn make_interaction_image(gen_ctx : &GeneralContext,
     // Draw Fragments
     let mut nest_shift : u32 = 1; // shift to display nested fragments
     let mut yshift : u32 = 3;
-    draw_interaction_rec(&mut image,  gen_ctx, interaction, &lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift);
+    draw_interaction_rec(&mut image,  gen_ctx, interaction, Position::Epsilon(None), highlighting, &lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift);
     // ***
     return image;
 }
\ No newline at end of file
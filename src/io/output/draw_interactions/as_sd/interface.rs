@@ -16,21 +16,55 @@ limitations under the License.
 
 
 use std::path::PathBuf;
+
+use image::{GenericImage, Rgb, RgbImage};
+
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::make_interaction_image;
+use crate::io::output::draw_commons::hibou_color_palette::HCP_White;
+use crate::io::output::draw_commons::sd_drawing_conf::MARGIN;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
+use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::make_highlighted_interaction_image;
 
 
 pub fn draw_int_as_sd(gen_ctx : &GeneralContext,
                       interaction : &Interaction,
                       parent_folder : &String,
                       output_file_name : &String) {
+    draw_highlighted_int_as_sd(gen_ctx,interaction,&SequenceDiagramHighlighting::new_empty(),parent_folder,output_file_name);
+}
+
+pub fn draw_highlighted_int_as_sd(gen_ctx : &GeneralContext,
+                                  interaction : &Interaction,
+                                  highlighting : &SequenceDiagramHighlighting,
+                                  parent_folder : &String,
+                                  output_file_name : &String) {
     // ***
     let output_file_name = format!("{:}.png", output_file_name);
     let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
-    let image = make_interaction_image(gen_ctx,interaction);
+    let image = make_highlighted_interaction_image(gen_ctx,interaction,highlighting);
     image.save(output_path.as_path());
 }
 
 
 
+
+// draws both interactions next to each other in a single image
+pub fn draw_highlighted_ints_side_by_side_as_sd(gen_ctx : &GeneralContext,
+                                                left : (&Interaction,&SequenceDiagramHighlighting),
+                                                right : (&Interaction,&SequenceDiagramHighlighting),
+                                                parent_folder : &String,
+                                                output_file_name : &String) {
+    // ***
+    let output_file_name = format!("{:}.png", output_file_name);
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    let left_image = make_highlighted_interaction_image(gen_ctx,left.0,left.1);
+    let right_image = make_highlighted_interaction_image(gen_ctx,right.0,right.1);
+    let separation = (2.0*MARGIN) as u32;
+    let mut image = RgbImage::from_pixel(left_image.width() + separation + right_image.width(),
+                                         left_image.height().max(right_image.height()),
+                                         Rgb(HCP_White));
+    image.copy_from(&left_image,0,0).unwrap();
+    image.copy_from(&right_image,left_image.width() + separation,0).unwrap();
+    image.save(output_path.as_path());
+}
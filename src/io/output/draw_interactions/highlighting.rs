@@ -0,0 +1,145 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashMap;
+
+use crate::core::execution::interpretation::coverage::InteractionCoverage;
+use crate::core::execution::interpretation::interpretation::InteractionInterpretation;
+use crate::core::language::diff::diff::InteractionDiffItem;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::draw_commons::hibou_color_palette::{HC_Message, HCP_BrightGray, HCP_LightGreen, HCP_LightOrange, HCP_LightRed, HCP_StandardPurple};
+
+
+pub struct SequenceDiagramHighlighting {
+    // colors of the labels of the actions at the given positions
+    pub actions_colors : HashMap<Position,[u8;3]>,
+    // color of the labels of all the other actions
+    pub default_action_color : [u8;3],
+    // annotations added after the labels of the fragments at the given positions
+    pub fragments_annotations : HashMap<Position,String>,
+    pub annotations_color : [u8;3]
+}
+
+impl SequenceDiagramHighlighting {
+
+    pub fn new(actions_colors: HashMap<Position, [u8; 3]>,
+               default_action_color: [u8; 3],
+               fragments_annotations: HashMap<Position, String>,
+               annotations_color: [u8; 3]) -> Self {
+        SequenceDiagramHighlighting { actions_colors, default_action_color, fragments_annotations, annotations_color }
+    }
+
+    pub fn new_empty() -> Self {
+        SequenceDiagramHighlighting::new(hashmap!{},HC_Message,hashmap!{},HCP_StandardPurple)
+    }
+
+    // executed actions in green, the others in gray and the number of instances of each loop
+    pub fn new_from_interpretation(interpretation : &InteractionInterpretation) -> Self {
+        let mut highlighting = SequenceDiagramHighlighting::new_empty();
+        highlighting.default_action_color = HCP_BrightGray;
+        for (act_pos,num_executions) in &interpretation.actions {
+            if *num_executions > 0 {
+                highlighting.actions_colors.insert(act_pos.clone(),HCP_LightGreen);
+            }
+        }
+        for (loop_pos,num_instances) in &interpretation.loops {
+            highlighting.fragments_annotations.insert(loop_pos.clone(),format!("x{:}", num_instances));
+        }
+        return highlighting;
+    }
+
+    // covered actions in green, the others in red and the number of interpretations for each branch and loop
+    pub fn new_from_coverage(coverage : &InteractionCoverage) -> Self {
+        let mut highlighting = SequenceDiagramHighlighting::new_empty();
+        highlighting.default_action_color = HCP_LightRed;
+        for (act_pos,num_covering) in &coverage.actions {
+            if *num_covering > 0 {
+                highlighting.actions_colors.insert(act_pos.clone(),HCP_LightGreen);
+            }
+        }
+        for (alt_pos,num_left,num_right) in &coverage.alts {
+            highlighting.fragments_annotations.insert(alt_pos.clone(),format!("[{:}|{:}]", num_left, num_right));
+        }
+        for loop_cov in &coverage.loops {
+            highlighting.fragments_annotations.insert(loop_cov.position.clone(),
+                                                      format!("0:{:} 1:{:} n:{:}", loop_cov.zero, loop_cov.one, loop_cov.many));
+        }
+        return highlighting;
+    }
+
+    // on the first interaction (resp. the second one), removed (resp. inserted) sub-interactions in red (resp. green) and moved ones in orange
+    pub fn new_from_diff(diff : &Vec<InteractionDiffItem>, is_first : bool) -> Self {
+        let mut highlighting = SequenceDiagramHighlighting::new_empty();
+        for item in diff {
+            let highlighted = match (item,is_first) {
+                (InteractionDiffItem::Removed(pos,sub_int),true) => {
+                    Some((pos,sub_int,HCP_LightRed,"[-]"))
+                },
+                (InteractionDiffItem::Inserted(pos,sub_int),false) => {
+                    Some((pos,sub_int,HCP_LightGreen,"[+]"))
+                },
+                (InteractionDiffItem::Moved(pos,_,sub_int),true) => {
+                    Some((pos,sub_int,HCP_LightOrange,"[moved]"))
+                },
+                (InteractionDiffItem::Moved(_,pos,sub_int),false) => {
+                    Some((pos,sub_int,HCP_LightOrange,"[moved]"))
+                },
+                _ => {
+                    None
+                }
+            };
+            if let Some((pos,sub_int,color,annotation)) = highlighted {
+                highlighting.fragments_annotations.insert(pos.clone(),annotation.to_string());
+                highlighting.add_actions_color(sub_int,pos.clone(),color);
+            }
+        }
+        return highlighting;
+    }
+
+    // colors all the actions of the sub-interaction at the given position
+    fn add_actions_color(&mut self, sub_int : &Interaction, position : Position, color : [u8;3]) {
+        match sub_int {
+            Interaction::Emission(_) | Interaction::Reception(_) => {
+                self.actions_colors.insert(position,color);
+            },
+            Interaction::Empty => {},
+            Interaction::Loop(_,i1) => {
+                self.add_actions_color(i1,position.get_left_child(),color);
+            },
+            Interaction::Strict(i1,i2) | Interaction::Seq(i1,i2) | Interaction::Alt(i1,i2)
+            | Interaction::Par(i1,i2) | Interaction::And(i1,i2)
+            | Interaction::CoReg(_,i1,i2) | Interaction::Sync(_,i1,i2) => {
+                self.add_actions_color(i1,position.get_left_child(),color);
+                self.add_actions_color(i2,position.get_right_child(),color);
+            }
+        }
+    }
+
+    pub fn get_action_color(&self, position : &Position) -> [u8;3] {
+        match self.actions_colors.get(position) {
+            None => {
+                self.default_action_color
+            },
+            Some( color ) => {
+                *color
+            }
+        }
+    }
+
+}
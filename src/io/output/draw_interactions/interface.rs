@@ -18,8 +18,9 @@ limitations under the License.
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interface::draw_int_as_sd;
+use crate::io::output::draw_interactions::as_sd::interface::{draw_highlighted_int_as_sd, draw_highlighted_ints_side_by_side_as_sd, draw_int_as_sd};
 use crate::io::output::draw_interactions::as_term::interface::draw_int_as_term;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
 
 
 
@@ -43,4 +44,20 @@ pub fn draw_interaction(gen_ctx : &GeneralContext,
             draw_int_as_term(gen_ctx,int,temp_folder,parent_folder,output_file_name);
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn draw_highlighted_interaction_as_sd(gen_ctx : &GeneralContext,
+                                          int : &Interaction,
+                                          highlighting : &SequenceDiagramHighlighting,
+                                          parent_folder : &String,
+                                          output_file_name : &String) {
+    draw_highlighted_int_as_sd(gen_ctx,int,highlighting,parent_folder,output_file_name);
+}
+
+pub fn draw_highlighted_interactions_side_by_side_as_sd(gen_ctx : &GeneralContext,
+                                                        left : (&Interaction,&SequenceDiagramHighlighting),
+                                                        right : (&Interaction,&SequenceDiagramHighlighting),
+                                                        parent_folder : &String,
+                                                        output_file_name : &String) {
+    draw_highlighted_ints_side_by_side_as_sd(gen_ctx,left,right,parent_folder,output_file_name);
+}
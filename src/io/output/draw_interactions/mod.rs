@@ -18,5 +18,6 @@ mod as_term;
 mod as_sd;
 
 pub mod interface;
+pub mod highlighting;
 
 
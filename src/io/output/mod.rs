@@ -22,5 +22,8 @@ pub mod draw_traces;
 pub mod draw_transitions;
 
 pub mod to_hfiles;
+pub mod to_json;
+pub mod to_cfsm;
+pub mod to_rust_monitor;
 
 
@@ -0,0 +1,51 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_cfsm::get_cfsm_transition_label;
+use crate::nfa_translation::cfsm::CommunicatingMachine;
+
+
+// one cluster per lifeline, accepting states being drawn with a double circle
+pub fn machines_as_dot(gen_ctx : &GeneralContext, machines : &Vec<CommunicatingMachine>) -> String {
+    let mut lines = vec![];
+    lines.push("digraph cfsm {".to_string());
+    lines.push("\trankdir=LR;".to_string());
+    for machine in machines {
+        let lf_name = gen_ctx.get_lf_name(machine.lf_id).unwrap();
+        lines.push(format!("\tsubgraph cluster_{:} {{", machine.lf_id));
+        lines.push(format!("\t\tlabel=\"{:}\";", lf_name));
+        lines.push(format!("\t\tl{:}_init [shape=point];", machine.lf_id));
+        for state in 0..machine.num_states {
+            let shape = if machine.finals.contains(&state) {"doublecircle"} else {"circle"};
+            lines.push(format!("\t\tl{:}_q{:} [label=\"q{:}\" shape={:}];", machine.lf_id, state, state, shape));
+        }
+        lines.push(format!("\t\tl{:}_init -> l{:}_q0;", machine.lf_id, machine.lf_id));
+        for transition in &machine.transitions {
+            lines.push(format!("\t\tl{:}_q{:} -> l{:}_q{:} [label=\"{:}\"];",
+                               machine.lf_id,
+                               transition.source,
+                               machine.lf_id,
+                               transition.target,
+                               get_cfsm_transition_label(gen_ctx,transition)));
+        }
+        lines.push("\t}".to_string());
+    }
+    lines.push("}".to_string());
+    return format!("{:}\n", lines.join("\n"));
+}
@@ -0,0 +1,53 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::trace::trace::TraceActionKind;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::nfa_translation::cfsm::CommunicatingMachine;
+
+
+// peers are given by their names, an empty array standing for the environment
+pub fn machines_as_json(gen_ctx : &GeneralContext, machines : &Vec<CommunicatingMachine>) -> String {
+    let mut machines_jsons = vec![];
+    for machine in machines {
+        let mut transitions_jsons = vec![];
+        for transition in &machine.transitions {
+            let kind = match transition.act_kind {
+                TraceActionKind::Emission => {
+                    "send"
+                },
+                TraceActionKind::Reception => {
+                    "receive"
+                }
+            };
+            let peers : Vec<String> = transition.peers.iter().map(|x| json_string(&gen_ctx.get_lf_name(*x).unwrap())).collect();
+            transitions_jsons.push(json_object(vec![("source",transition.source.to_string()),
+                                                    ("target",transition.target.to_string()),
+                                                    ("kind",json_string(kind)),
+                                                    ("message",json_string(&gen_ctx.get_ms_name(transition.ms_id).unwrap())),
+                                                    ("peers",json_array(peers))]));
+        }
+        machines_jsons.push(json_object(vec![("lifeline",json_string(&gen_ctx.get_lf_name(machine.lf_id).unwrap())),
+                                             ("states",machine.num_states.to_string()),
+                                             ("initial","0".to_string()),
+                                             ("finals",json_array(machine.finals.iter().map(|x| x.to_string()).collect())),
+                                             ("transitions",json_array(transitions_jsons))]));
+    }
+    return format!("{:}\n", json_object(vec![("machines",json_array(machines_jsons))]));
+}
@@ -0,0 +1,101 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::trace::trace::TraceActionKind;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_cfsm::dot::machines_as_dot;
+use crate::io::output::to_cfsm::json::machines_as_json;
+use crate::io::output::to_cfsm::text::machines_as_cfsm_text;
+use crate::nfa_translation::cfsm::{CfsmTransition, CommunicatingMachine};
+
+pub mod text;
+pub mod dot;
+pub mod json;
+
+
+pub enum CfsmOutputFormat {
+    Text,
+    Dot,
+    Json
+}
+
+impl CfsmOutputFormat {
+
+    pub fn get_file_extension(&self) -> &'static str {
+        match self {
+            CfsmOutputFormat::Text => {
+                "cfsm"
+            },
+            CfsmOutputFormat::Dot => {
+                "dot"
+            },
+            CfsmOutputFormat::Json => {
+                "json"
+            }
+        }
+    }
+
+}
+
+pub fn machines_to_file(file_path : &Path,
+                        gen_ctx : &GeneralContext,
+                        machines : &Vec<CommunicatingMachine>,
+                        format : &CfsmOutputFormat) {
+    let encoding = match format {
+        CfsmOutputFormat::Text => {
+            machines_as_cfsm_text(gen_ctx,machines)
+        },
+        CfsmOutputFormat::Dot => {
+            machines_as_dot(gen_ctx,machines)
+        },
+        CfsmOutputFormat::Json => {
+            machines_as_json(gen_ctx,machines)
+        }
+    };
+    let mut file = File::create(file_path).unwrap();
+    file.write(encoding.as_bytes());
+}
+
+// name of the peers of a transition, "env" standing for the environment
+pub fn get_cfsm_peers_repr(gen_ctx : &GeneralContext, transition : &CfsmTransition) -> String {
+    if transition.peers.is_empty() {
+        return "env".to_string();
+    }
+    let peers_names : Vec<String> = transition.peers.iter().map(|x| gen_ctx.get_lf_name(*x).unwrap()).collect();
+    return peers_names.join(",");
+}
+
+// e.g. "b ! m1" for sending m1 to b and "a ? m1" for receiving m1 from a
+pub fn get_cfsm_transition_label(gen_ctx : &GeneralContext, transition : &CfsmTransition) -> String {
+    let direction = match transition.act_kind {
+        TraceActionKind::Emission => {
+            "!"
+        },
+        TraceActionKind::Reception => {
+            "?"
+        }
+    };
+    return format!("{:} {:} {:}",
+                   get_cfsm_peers_repr(gen_ctx,transition),
+                   direction,
+                   gen_ctx.get_ms_name(transition.ms_id).unwrap());
+}
@@ -0,0 +1,49 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_cfsm::get_cfsm_transition_label;
+use crate::nfa_translation::cfsm::CommunicatingMachine;
+
+
+/**
+ * Textual format adapted from the "fsa" format of communicating finite state machines:
+ * each machine is described between ".outputs <lifeline>" and ".end",
+ * with one "<source> <peers> <!|?> <message> <target>" line per transition,
+ * its initial state after ".marking" and its accepting states after ".final".
+ **/
+pub fn machines_as_cfsm_text(gen_ctx : &GeneralContext, machines : &Vec<CommunicatingMachine>) -> String {
+    let mut machines_strs = vec![];
+    for machine in machines {
+        let mut lines = vec![];
+        lines.push(format!(".outputs {:}", gen_ctx.get_lf_name(machine.lf_id).unwrap()));
+        lines.push(".state graph".to_string());
+        for transition in &machine.transitions {
+            lines.push(format!("q{:} {:} q{:}",
+                               transition.source,
+                               get_cfsm_transition_label(gen_ctx,transition),
+                               transition.target));
+        }
+        lines.push(".marking q0".to_string());
+        let finals : Vec<String> = machine.finals.iter().map(|x| format!("q{:}", x)).collect();
+        lines.push(format!(".final {:}", finals.join(" ")).trim_end().to_string());
+        lines.push(".end".to_string());
+        machines_strs.push(lines.join("\n"));
+    }
+    return format!("{:}\n", machines_strs.join("\n\n"));
+}
@@ -29,14 +29,24 @@ use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_EMPTY, SYNT
 
 pub fn interaction_as_hif_encoding(gen_ctx : &GeneralContext,
                            interaction : &Interaction) -> String {
-    return interaction_as_hif_encoding_inner(gen_ctx,0, interaction);
+    return interaction_as_hif_encoding_inner(gen_ctx,0, interaction, true);
+}
+
+/**
+Encodes each binary operator separately instead of flattening nested operators of the same kind,
+so that parsing the result yields exactly the same term.
+**/
+pub fn interaction_as_binary_hif_encoding(gen_ctx : &GeneralContext,
+                                          interaction : &Interaction) -> String {
+    return interaction_as_hif_encoding_inner(gen_ctx,0, interaction, false);
 }
 
 fn op_as_hif_encoding(gen_ctx : &GeneralContext,
                      depth : usize,
                      op_text : &str,
-                     sub_ints : Vec<&Interaction>) -> String {
-    let ints_strs : Vec<String> = sub_ints.iter().map(|i| interaction_as_hif_encoding_inner(gen_ctx,depth+1,i)).collect();
+                     sub_ints : Vec<&Interaction>,
+                     flatten : bool) -> String {
+    let ints_strs : Vec<String> = sub_ints.iter().map(|i| interaction_as_hif_encoding_inner(gen_ctx,depth+1,i,flatten)).collect();
     return format!("{0}{1}(\n{2}\n{0})", "\t".repeat(depth), op_text, ints_strs.join(",\n"));
 }
 
@@ -59,7 +69,8 @@ fn loop_kind_as_text(lk : &LoopKind) -> &'static str {
 
 fn interaction_as_hif_encoding_inner(gen_ctx : &GeneralContext,
                              depth : usize,
-                            interaction : &Interaction) -> String {
+                            interaction : &Interaction,
+                             flatten : bool) -> String {
     match interaction {
         &Interaction::Empty => {
             return format!("{}{}", "\t".repeat(depth), SYNTAX_EMPTY);
@@ -70,35 +81,52 @@ fn interaction_as_hif_encoding_inner(gen_ctx : &GeneralContext,
         &Interaction::Reception(ref rc_act) => {
             return format!("{}{}", "\t".repeat(depth), reception_as_hif_encoding(gen_ctx,rc_act));
         },
+        &Interaction::Strict(ref i1, ref i2) if !flatten => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_STRICT,vec![&**i1,&**i2],flatten);
+        },
         &Interaction::Strict(ref i1, ref i2) => {
             let mut strict_frags = get_recursive_strict_frags(i1);
             strict_frags.extend_from_slice(&mut get_recursive_strict_frags(i2));
-            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_STRICT,strict_frags);
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_STRICT,strict_frags,flatten);
+        },
+        &Interaction::Seq(ref i1, ref i2) if !flatten => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_SEQ,vec![&**i1,&**i2],flatten);
         },
         &Interaction::Seq(ref i1, ref i2) => {
             let mut seq_frags = get_recursive_seq_frags(i1);
             seq_frags.extend_from_slice(&mut get_recursive_seq_frags(i2));
-            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_SEQ,seq_frags);
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_SEQ,seq_frags,flatten);
         },
         &Interaction::CoReg(ref cr, ref i1, ref i2) => {
-            let mut cr_frags = get_recursive_coreg_frags(cr,i1);
-            cr_frags.extend_from_slice(&mut get_recursive_coreg_frags(cr,i2));
+            let cr_frags = if flatten {
+                let mut cr_frags = get_recursive_coreg_frags(cr,i1);
+                cr_frags.extend_from_slice(&mut get_recursive_coreg_frags(cr,i2));
+                cr_frags
+            } else {
+                vec![&**i1,&**i2]
+            };
             let cr_lfs : Vec<String> = cr.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
             let operator_name = format!("{:}({:})", SYNTAX_COREG, cr_lfs.join(","));
-            return op_as_hif_encoding(gen_ctx,depth,&operator_name,cr_frags);
+            return op_as_hif_encoding(gen_ctx,depth,&operator_name,cr_frags,flatten);
+        },
+        &Interaction::Par(ref i1, ref i2) if !flatten => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_PAR,vec![&**i1,&**i2],flatten);
         },
         &Interaction::Par(ref i1, ref i2) => {
             let mut par_frags = get_recursive_par_frags(i1);
             par_frags.extend_from_slice(&mut get_recursive_par_frags(i2));
-            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_PAR,par_frags);
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_PAR,par_frags,flatten);
+        },
+        &Interaction::Alt(ref i1, ref i2) if !flatten => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_ALT,vec![&**i1,&**i2],flatten);
         },
         &Interaction::Alt(ref i1, ref i2) => {
             let mut alt_frags = get_recursive_alt_frags(i1);
             alt_frags.extend_from_slice(&mut get_recursive_alt_frags(i2));
-            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_ALT,alt_frags);
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_ALT,alt_frags,flatten);
         },
         &Interaction::Loop(ref lk, ref i1) => {
-            let i1_string = interaction_as_hif_encoding_inner(gen_ctx,depth+1,i1);
+            let i1_string = interaction_as_hif_encoding_inner(gen_ctx,depth+1,i1,flatten);
             return format!("{0}{1}(\n{2}\n{0})", "\t".repeat(depth), loop_kind_as_text(lk), i1_string);
         },
         _ => {
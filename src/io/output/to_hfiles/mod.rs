@@ -17,3 +17,6 @@ limitations under the License.
 
 pub mod trace;
 pub mod interaction;
+pub mod witness;
+pub mod proof;
+pub mod signature;
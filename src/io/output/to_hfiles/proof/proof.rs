@@ -0,0 +1,45 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::general_context::GeneralContext;
+use crate::core::transformation::proof::certificate::InteractionEquivalenceCertificate;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_binary_hif_encoding;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+
+
+/**
+Each derivation gives its initial interaction and, for each step, the transformation, its position
+and the interaction it yields (the interaction it is applied to being that of the previous step).
+Interactions are encoded without flattening operators so that they are parsed back exactly.
+**/
+pub fn certificate_as_hpf_encoding(gen_ctx : &GeneralContext,
+                                   certificate : &InteractionEquivalenceCertificate) -> String {
+    let mut cert_str = String::new();
+    for derivation in &certificate.derivations {
+        cert_str.push_str("derivation {\n");
+        cert_str.push_str( &format!("from\n{:}\n;\n", interaction_as_binary_hif_encoding(gen_ctx,&derivation.initial)) );
+        for step in &derivation.steps {
+            cert_str.push_str( &format!("{:} at {:} :\n{:}\n;\n",
+                                        step.kind,
+                                        position_as_hwf_encoding(&step.position),
+                                        interaction_as_binary_hif_encoding(gen_ctx,&step.after)) );
+        }
+        cert_str.push_str("}\n");
+    }
+    return cert_str;
+}
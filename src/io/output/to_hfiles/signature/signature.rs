@@ -0,0 +1,38 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::general_context::GeneralContext;
+
+
+fn section_as_hsf_encoding(section_name : &str, names : Vec<String>) -> String {
+    // empty sections are not allowed
+    if names.is_empty() {
+        return "".to_string();
+    }
+    return format!("@{}{{\n\t{}\n}}\n", section_name, names.join(";\n\t"));
+}
+
+pub fn signature_as_hsf_encoding(gen_ctx : &GeneralContext) -> String {
+    let messages : Vec<String> = (0..gen_ctx.get_ms_num()).map(|ms_id| gen_ctx.get_ms_name(ms_id).unwrap()).collect();
+    let lifelines : Vec<String> = (0..gen_ctx.get_lf_num()).map(|lf_id| gen_ctx.get_lf_name(lf_id).unwrap()).collect();
+    let gates : Vec<String> = (0..gen_ctx.get_gt_num()).map(|gt_id| gen_ctx.get_gt_name(gt_id).unwrap()).collect();
+    return format!("{}{}{}",
+                   section_as_hsf_encoding("message",messages),
+                   section_as_hsf_encoding("lifeline",lifelines),
+                   section_as_hsf_encoding("gate",gates));
+}
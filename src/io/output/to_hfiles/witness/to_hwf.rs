@@ -0,0 +1,33 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::interpretation::witness::ExecutionWitness;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::witness::witness::witness_as_hwf_encoding;
+
+
+pub fn write_witness_into_file(file_path : &Path,
+                               gen_ctx : &GeneralContext,
+                               witness : &ExecutionWitness) {
+    let mut file = File::create(file_path).unwrap();
+    file.write(witness_as_hwf_encoding(gen_ctx,witness).as_bytes() );
+}
@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use itertools::Itertools;
+
+use crate::core::execution::interpretation::witness::{ExecutionWitness, WitnessStep};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+
+
+pub fn position_as_hwf_encoding(position : &Position) -> String {
+    match position {
+        Position::Epsilon(None) => {
+            return "o".to_string();
+        },
+        Position::Epsilon(Some(sub_idx)) => {
+            return format!("s{:}", sub_idx);
+        },
+        Position::Left(ref sub_pos) => {
+            return format!("1{:}", position_as_hwf_encoding(sub_pos));
+        },
+        Position::Right(ref sub_pos) => {
+            return format!("2{:}", position_as_hwf_encoding(sub_pos));
+        },
+        Position::Both(ref sub_pos1, ref sub_pos2) => {
+            return format!("({:},{:})", position_as_hwf_encoding(sub_pos1), position_as_hwf_encoding(sub_pos2));
+        }
+    }
+}
+
+pub fn witness_as_hwf_encoding(gen_ctx : &GeneralContext,
+                               witness : &ExecutionWitness) -> String {
+    let mut witness_str = "{\n".to_string();
+    for step in &witness.steps {
+        match step {
+            WitnessStep::Execute(ref position, ref actions) => {
+                witness_str.push_str( &format!("\texecute {:} : {:};\n",
+                                               position_as_hwf_encoding(position),
+                                               trace_actions_as_htf_encoding(gen_ctx,actions)) );
+            },
+            WitnessStep::Hide(ref lfs_to_hide) => {
+                let lf_names = lfs_to_hide.iter().sorted().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).join(",");
+                witness_str.push_str( &format!("\thide {:};\n", lf_names) );
+            }
+        }
+    }
+    witness_str.push_str("}\n");
+    return witness_str;
+}
@@ -0,0 +1,81 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::execution::interpretation::coverage::InteractionCoverage;
+use crate::core::execution::interpretation::origin::InteractionOrigins;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+
+
+// "traces" gives the name of each analyzed trace file and whether or not it was accepted
+pub fn coverage_as_json(gen_ctx : &GeneralContext,
+                        origins : &InteractionOrigins,
+                        coverage : &InteractionCoverage,
+                        traces : &Vec<(String,bool)>) -> String {
+    let traces_json : Vec<String> = traces.iter()
+        .map(|(trace_name,accepted)| json_object(vec![("file",json_string(trace_name)),
+                                                       ("accepted",accepted.to_string())]))
+        .collect();
+    // ***
+    let mut actions_json = vec![];
+    for (origin,(act_pos,num_covering)) in origins.actions.iter().zip(coverage.actions.iter()) {
+        actions_json.push( json_object(vec![("position",json_string(&position_as_hwf_encoding(act_pos))),
+                                            ("message",json_string(&gen_ctx.get_ms_name(origin.ms_id).unwrap())),
+                                            ("covering_traces",num_covering.to_string())]) );
+    }
+    let mut alts_json = vec![];
+    for (alt_pos,num_left,num_right) in &coverage.alts {
+        alts_json.push( json_object(vec![("position",json_string(&position_as_hwf_encoding(alt_pos))),
+                                         ("left_branch_traces",num_left.to_string()),
+                                         ("right_branch_traces",num_right.to_string())]) );
+    }
+    let mut loops_json = vec![];
+    for loop_cov in &coverage.loops {
+        loops_json.push( json_object(vec![("position",json_string(&position_as_hwf_encoding(&loop_cov.position))),
+                                          ("zero_instance_traces",loop_cov.zero.to_string()),
+                                          ("one_instance_traces",loop_cov.one.to_string()),
+                                          ("many_instances_traces",loop_cov.many.to_string())]) );
+    }
+    // ***
+    let summary = json_object(vec![("accepted_traces",coverage.interpretations_num.to_string()),
+                                   ("analyzed_traces",traces.len().to_string()),
+                                   ("covered_actions",coverage.get_covered_actions_num().to_string()),
+                                   ("total_actions",coverage.actions.len().to_string()),
+                                   ("covered_alt_branches",coverage.get_covered_alt_branches_num().to_string()),
+                                   ("total_alt_branches",(2*coverage.alts.len()).to_string())]);
+    return json_object(vec![("summary",summary),
+                            ("traces",json_array(traces_json)),
+                            ("actions",json_array(actions_json)),
+                            ("alts",json_array(alts_json)),
+                            ("loops",json_array(loops_json))]);
+}
+
+pub fn write_coverage_into_json_file(file_path : &Path,
+                                     gen_ctx : &GeneralContext,
+                                     origins : &InteractionOrigins,
+                                     coverage : &InteractionCoverage,
+                                     traces : &Vec<(String,bool)>) -> std::io::Result<()> {
+    let mut file = File::create(file_path)?;
+    file.write_all(coverage_as_json(gen_ctx,origins,coverage,traces).as_bytes())?;
+    return Ok(());
+}
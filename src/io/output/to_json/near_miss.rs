@@ -0,0 +1,64 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::trace_manip::near_miss::generate::NearMissMutant;
+
+
+// "sources" gives the names of the original trace files and "mutants" the name of the file of each mutant
+pub fn near_miss_manifest_as_json(gen_ctx : &GeneralContext,
+                                  interaction_name : &str,
+                                  co_localizations : &CoLocalizations,
+                                  sources : &Vec<String>,
+                                  mutants : &Vec<(String,NearMissMutant)>,
+                                  discarded : u32) -> String {
+    let mut mutants_json = vec![];
+    for (file_name,mutant) in mutants {
+        let (coloc_id,index) = mutant.divergence;
+        let lifelines : Vec<String> = co_localizations.get_coloc_lfs_ids(coloc_id).iter()
+            .map(|lf_id| json_string(&gen_ctx.get_lf_name(*lf_id).unwrap()))
+            .collect();
+        let divergence = json_object(vec![("component",coloc_id.to_string()),
+                                          ("lifelines",json_array(lifelines)),
+                                          ("index",index.to_string())]);
+        mutants_json.push( json_object(vec![("file",json_string(file_name)),
+                                            ("source",json_string(sources.get(mutant.source_id).unwrap())),
+                                            ("mutation",json_string(&mutant.kind.to_string())),
+                                            ("divergence",divergence)]) );
+    }
+    return json_object(vec![("interaction",json_string(interaction_name)),
+                            ("verdict",json_string("Fail")),
+                            ("discarded_mutants",discarded.to_string()),
+                            ("mutants",json_array(mutants_json))]);
+}
+
+pub fn write_near_miss_manifest_into_json_file(file_path : &Path,
+                                               gen_ctx : &GeneralContext,
+                                               interaction_name : &str,
+                                               co_localizations : &CoLocalizations,
+                                               sources : &Vec<String>,
+                                               mutants : &Vec<(String,NearMissMutant)>,
+                                               discarded : u32) {
+    let mut file = File::create(file_path).unwrap();
+    file.write(near_miss_manifest_as_json(gen_ctx,interaction_name,co_localizations,sources,mutants,discarded).as_bytes() );
+}
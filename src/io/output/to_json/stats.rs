@@ -0,0 +1,59 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::loggers::stats::logger::GenericProcessStatsLogger;
+
+
+pub fn optional_string_as_json(value : &Option<String>) -> String {
+    match value {
+        None => {
+            "null".to_string()
+        },
+        Some( got_str ) => {
+            json_string(got_str)
+        }
+    }
+}
+
+// the strategy is given apart from the statistics because the loggers only know of the QueueSearchStrategy
+pub fn process_options_as_json<Conf : AbstractProcessConfiguration>(strategy : &str,
+                                                                   stats : &GenericProcessStatsLogger<Conf>) -> String {
+    let filters_json : Vec<String> = stats.filters.iter().map(|f| json_string(f)).collect();
+    let param_json : Vec<String> = stats.parameterization.iter().map(|p| json_string(p)).collect();
+    return json_object(vec![("strategy",json_string(strategy)),
+                            ("priorities",json_string(&stats.priorities)),
+                            ("filters",json_array(filters_json)),
+                            ("goal",optional_string_as_json(&stats.goal)),
+                            ("memoization",stats.use_memoization.to_string()),
+                            ("parameterization",json_array(param_json))]);
+}
+
+pub fn process_statistics_as_json<Conf : AbstractProcessConfiguration>(stats : &GenericProcessStatsLogger<Conf>,
+                                                                      elapsed_secs : f64) -> String {
+    let filtered_json : Vec<(&str,String)> = stats.filtered.iter()
+        .map(|(elim_kind,num)| (elim_kind.as_str(),num.to_string()))
+        .collect();
+    return json_object(vec![("nodes",stats.nodes_num.to_string()),
+                            ("edges",stats.edges_num.to_string()),
+                            ("max_queue_size",stats.max_queue_size.to_string()),
+                            ("filtered",json_object(filtered_json)),
+                            ("elapsed",format!("{:?}", elapsed_secs))]);
+}
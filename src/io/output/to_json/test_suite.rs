@@ -0,0 +1,96 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::trace_manip::test_suite::criterion::{TestCoverageItem, TestSuiteProblem};
+
+
+fn test_coverage_item_as_json(gen_ctx : &GeneralContext,
+                              item : &TestCoverageItem) -> String {
+    match item {
+        TestCoverageItem::Action(position,ms_id) => {
+            json_object(vec![("kind",json_string("action")),
+                             ("position",json_string(&position_as_hwf_encoding(position))),
+                             ("message",json_string(&gen_ctx.get_ms_name(*ms_id).unwrap()))])
+        },
+        TestCoverageItem::AltBranch(position,is_left) => {
+            let branch = if *is_left {"left"} else {"right"};
+            json_object(vec![("kind",json_string("alt_branch")),
+                             ("position",json_string(&position_as_hwf_encoding(position))),
+                             ("branch",json_string(branch))])
+        },
+        TestCoverageItem::LoopInstances(position,num_instances) => {
+            json_object(vec![("kind",json_string("loop")),
+                             ("position",json_string(&position_as_hwf_encoding(position))),
+                             ("instances",num_instances.to_string())])
+        },
+        TestCoverageItem::NfaTransition(origin,actions,target) => {
+            json_object(vec![("kind",json_string("nfa_transition")),
+                             ("origin",origin.to_string()),
+                             ("actions",json_string(&trace_actions_as_htf_encoding(gen_ctx,actions))),
+                             ("target",target.to_string())])
+        }
+    }
+}
+
+// "tests" gives the name of each written trace file and the index of the corresponding candidate
+pub fn test_suite_manifest_as_json(gen_ctx : &GeneralContext,
+                                   interaction_name : &str,
+                                   problem : &TestSuiteProblem,
+                                   tests : &Vec<(String,usize)>) -> String {
+    let mut covered = vec![false;problem.items.len()];
+    let mut tests_json = vec![];
+    for (file_name,cand_id) in tests {
+        let candidate = problem.candidates.get(*cand_id).unwrap();
+        let mut covers_json = vec![];
+        for item_id in &candidate.covered_items {
+            covered[*item_id] = true;
+            covers_json.push(test_coverage_item_as_json(gen_ctx,problem.items.get(*item_id).unwrap()));
+        }
+        tests_json.push( json_object(vec![("file",json_string(file_name)),
+                                          ("length",candidate.trace.len().to_string()),
+                                          ("covers",json_array(covers_json))]) );
+    }
+    let uncovered_json : Vec<String> = problem.items.iter().zip(covered.iter())
+        .filter(|(_,is_covered)| !**is_covered)
+        .map(|(item,_)| test_coverage_item_as_json(gen_ctx,item))
+        .collect();
+    let covered_num = covered.iter().filter(|x| **x).count();
+    return json_object(vec![("interaction",json_string(interaction_name)),
+                            ("criterion",json_string(&problem.criterion.to_string())),
+                            ("complete_search",problem.is_complete.to_string()),
+                            ("total_items",problem.items.len().to_string()),
+                            ("covered_items",covered_num.to_string()),
+                            ("tests",json_array(tests_json)),
+                            ("uncovered",json_array(uncovered_json))]);
+}
+
+pub fn write_test_suite_manifest_into_json_file(file_path : &Path,
+                                                gen_ctx : &GeneralContext,
+                                                interaction_name : &str,
+                                                problem : &TestSuiteProblem,
+                                                tests : &Vec<(String,usize)>) {
+    let mut file = File::create(file_path).unwrap();
+    file.write(test_suite_manifest_as_json(gen_ctx,interaction_name,problem,tests).as_bytes() );
+}
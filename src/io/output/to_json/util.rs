@@ -0,0 +1,61 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+// serde is not among our dependencies so JSON documents are written by hand
+
+pub fn json_string(value : &str) -> String {
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '"' => {
+                escaped.push_str("\\\"");
+            },
+            '\\' => {
+                escaped.push_str("\\\\");
+            },
+            '\n' => {
+                escaped.push_str("\\n");
+            },
+            '\r' => {
+                escaped.push_str("\\r");
+            },
+            '\t' => {
+                escaped.push_str("\\t");
+            },
+            _ => {
+                if (c as u32) < 0x20 {
+                    escaped.push_str(&format!("\\u{:04x}", c as u32));
+                } else {
+                    escaped.push(c);
+                }
+            }
+        }
+    }
+    return format!("\"{:}\"", escaped);
+}
+
+pub fn json_object(fields : Vec<(&str,String)>) -> String {
+    let fields_as_text : Vec<String> = fields.into_iter()
+        .map(|(key,value)| format!("{:}:{:}", json_string(key), value))
+        .collect();
+    return format!("{{{:}}}", fields_as_text.join(","));
+}
+
+pub fn json_array(elements : Vec<String>) -> String {
+    return format!("[{:}]", elements.join(","));
+}
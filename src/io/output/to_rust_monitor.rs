@@ -0,0 +1,295 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::nfa_translation::monitor::table::{MonitorTable, MonitorVerdict};
+
+
+// e.g. "Emission_a_m1" for "a!m1", an index being appended in the unlikely case of a name clash
+fn get_events_names(gen_ctx : &GeneralContext, alphabet : &Vec<BTreeSet<TraceAction>>) -> Vec<String> {
+    let mut names = vec![];
+    let mut used : HashSet<String> = HashSet::new();
+    for (letter,actions) in alphabet.iter().enumerate() {
+        let action = actions.iter().next().unwrap();
+        let kind = match action.act_kind {
+            TraceActionKind::Emission => {
+                "Emission"
+            },
+            TraceActionKind::Reception => {
+                "Reception"
+            }
+        };
+        let mut name = format!("{:}_{:}_{:}",
+                               kind,
+                               gen_ctx.get_lf_name(action.lf_id).unwrap(),
+                               gen_ctx.get_ms_name(action.ms_id).unwrap());
+        if used.contains(&name) {
+            name = format!("{:}_{:}", name, letter);
+        }
+        used.insert(name.clone());
+        names.push(name);
+    }
+    names
+}
+
+// action as in ".htf" files, e.g. "a!m1"
+fn get_action_repr(gen_ctx : &GeneralContext, action : &TraceAction) -> String {
+    let direction = match action.act_kind {
+        TraceActionKind::Emission => {
+            "!"
+        },
+        TraceActionKind::Reception => {
+            "?"
+        }
+    };
+    format!("{:}{:}{:}",
+            gen_ctx.get_lf_name(action.lf_id).unwrap(),
+            direction,
+            gen_ctx.get_ms_name(action.ms_id).unwrap())
+}
+
+fn get_verdict_repr(verdict : &MonitorVerdict) -> &'static str {
+    match verdict {
+        MonitorVerdict::Accepted => {
+            "Verdict::Accepted"
+        },
+        MonitorVerdict::Pending => {
+            "Verdict::Pending"
+        },
+        MonitorVerdict::Rejected => {
+            "Verdict::Rejected"
+        }
+    }
+}
+
+/**
+ * Generates a self-contained Rust module implementing the monitor described by "table".
+ * It contains an "Event" enum (one variant per action of the signature), a "State" enum,
+ * a "transition" function, a "verdict" function and a "Monitor" struct wrapping them.
+ * The "checked_traces" and their expected verdicts are written as a unit test of the module.
+ **/
+pub fn monitor_as_rust_module(gen_ctx : &GeneralContext,
+                              alphabet : &Vec<BTreeSet<TraceAction>>,
+                              table : &MonitorTable,
+                              checked_traces : &Vec<(Vec<usize>,MonitorVerdict)>,
+                              source_name : &str) -> String {
+    let events_names = get_events_names(gen_ctx,alphabet);
+    let actions_reprs : Vec<String> = alphabet.iter().map(|x| get_action_repr(gen_ctx,x.iter().next().unwrap())).collect();
+    let mut lines : Vec<String> = vec![];
+    lines.push(format!("// Runtime monitor generated by hibou_label from interaction '{:}'.", source_name));
+    lines.push("// It reads the actions of a global trace one at a time and tells whether the trace read so far".to_string());
+    lines.push("// is accepted by the interaction, is the prefix of an accepted trace or can no longer be accepted.".to_string());
+    lines.push("".to_string());
+    // ***
+    lines.push("#[allow(non_camel_case_types)]".to_string());
+    lines.push("#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]".to_string());
+    lines.push("pub enum Event {".to_string());
+    for (name,repr) in events_names.iter().zip(actions_reprs.iter()) {
+        lines.push(format!("    /// {:}", repr));
+        lines.push(format!("    {:},", name));
+    }
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.push("impl Event {".to_string());
+    lines.push("".to_string());
+    lines.push("    /// Event from an action written as in \".htf\" files, e.g. \"a!m\".".to_string());
+    lines.push("    pub fn from_action(action : &str) -> Option<Event> {".to_string());
+    lines.push("        match action.trim() {".to_string());
+    for (name,repr) in events_names.iter().zip(actions_reprs.iter()) {
+        lines.push(format!("            {:?} => Some(Event::{:}),", repr, name));
+    }
+    lines.push("            _ => None".to_string());
+    lines.push("        }".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    // ***
+    lines.push("#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]".to_string());
+    lines.push("pub enum State {".to_string());
+    for state in 0..table.num_states {
+        lines.push(format!("    S{:},", state));
+    }
+    lines.push("    /// the trace can no longer be accepted".to_string());
+    lines.push("    Sink".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.push("#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]".to_string());
+    lines.push("pub enum Verdict {".to_string());
+    lines.push("    /// the trace is accepted".to_string());
+    lines.push("    Accepted,".to_string());
+    lines.push("    /// the trace is not accepted but is the prefix of an accepted trace".to_string());
+    lines.push("    Pending,".to_string());
+    lines.push("    /// the trace is not the prefix of any accepted trace".to_string());
+    lines.push("    Rejected".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    // ***
+    lines.push(format!("pub const INITIAL_STATE : State = State::{:};", if table.num_states > 0 {"S0"} else {"Sink"}));
+    lines.push("".to_string());
+    lines.push("pub fn transition(state : State, event : Event) -> State {".to_string());
+    lines.push("    match (state,event) {".to_string());
+    for (source,outgoing) in table.transitions.iter().enumerate() {
+        for (letter,target) in outgoing {
+            lines.push(format!("        (State::S{:},Event::{:}) => State::S{:},", source, events_names.get(*letter).unwrap(), target));
+        }
+    }
+    lines.push("        _ => State::Sink".to_string());
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.push("pub fn verdict(state : State) -> Verdict {".to_string());
+    lines.push("    match state {".to_string());
+    if !table.finals.is_empty() {
+        let finals : Vec<String> = table.finals.iter().map(|x| format!("State::S{:}", x)).collect();
+        lines.push(format!("        {:} => Verdict::Accepted,", finals.join(" | ")));
+    }
+    lines.push("        State::Sink => Verdict::Rejected,".to_string());
+    lines.push("        #[allow(unreachable_patterns)]".to_string());
+    lines.push("        _ => Verdict::Pending".to_string());
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    // ***
+    lines.push("pub struct Monitor {".to_string());
+    lines.push("    state : State".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.push("impl Default for Monitor {".to_string());
+    lines.push("    fn default() -> Monitor {".to_string());
+    lines.push("        Monitor::new()".to_string());
+    lines.push("    }".to_string());
+    lines.push("}".to_string());
+    lines.push("".to_string());
+    lines.push("impl Monitor {".to_string());
+    lines.push("".to_string());
+    lines.push("    pub fn new() -> Monitor {".to_string());
+    lines.push("        Monitor{state:INITIAL_STATE}".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("    /// Reads an event and returns the verdict for the trace read so far.".to_string());
+    lines.push("    pub fn step(&mut self, event : Event) -> Verdict {".to_string());
+    lines.push("        self.state = transition(self.state,event);".to_string());
+    lines.push("        verdict(self.state)".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("    pub fn verdict(&self) -> Verdict {".to_string());
+    lines.push("        verdict(self.state)".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("    pub fn state(&self) -> State {".to_string());
+    lines.push("        self.state".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("    pub fn reset(&mut self) {".to_string());
+    lines.push("        self.state = INITIAL_STATE;".to_string());
+    lines.push("    }".to_string());
+    lines.push("".to_string());
+    lines.push("}".to_string());
+    // ***
+    if !checked_traces.is_empty() {
+        lines.push("".to_string());
+        lines.push("#[cfg(test)]".to_string());
+        lines.push("mod tests {".to_string());
+        lines.push("    use super::*;".to_string());
+        lines.push("".to_string());
+        lines.push("    fn run(actions : &[&str]) -> Verdict {".to_string());
+        lines.push("        let mut monitor = Monitor::new();".to_string());
+        lines.push("        for action in actions {".to_string());
+        lines.push("            monitor.step(Event::from_action(action).unwrap());".to_string());
+        lines.push("        }".to_string());
+        lines.push("        monitor.verdict()".to_string());
+        lines.push("    }".to_string());
+        lines.push("".to_string());
+        lines.push("    // expected verdicts are those of the analysis of the traces by hibou".to_string());
+        lines.push("    #[test]".to_string());
+        lines.push("    fn agrees_with_hibou_analysis() {".to_string());
+        for (word,verdict) in checked_traces {
+            let actions : Vec<String> = word.iter().map(|x| format!("{:?}", actions_reprs.get(*x).unwrap())).collect();
+            lines.push(format!("        assert_eq!(run(&[{:}]), {:});", actions.join(","), get_verdict_repr(verdict)));
+        }
+        lines.push("    }".to_string());
+        lines.push("".to_string());
+        lines.push("}".to_string());
+    }
+    format!("{:}\n", lines.join("\n"))
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::process::Command;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::experiments::interaction_random_gen::interface::generate_canonical_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+    use crate::nfa_translation::monitor::checks::get_checked_traces;
+    use super::*;
+
+    // the generated module is compiled and its own test (which embeds the verdicts of the analysis) is run
+    #[test]
+    fn generated_monitor_agrees_with_analysis_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        let mut rng = StdRng::seed_from_u64(0);
+        let int = loop {
+            if let Some(got) = generate_canonical_random_interaction(&gen_ctx,&mut rng,3,3,&probas) {
+                break got;
+            }
+        };
+        let (nfa,_) = get_nfa_from_interaction_exploration(&gen_ctx,&int,alphabet.clone());
+        let table = MonitorTable::from_nfa(&nfa);
+        let checked_traces = get_checked_traces(&gen_ctx,&int,&alphabet,&table,30,8,0);
+        for (word,verdict) in &checked_traces {
+            assert_eq!(table.get_verdict(word), *verdict);
+        }
+        // ***
+        let dir = std::env::temp_dir().join("hibou_monitor_test");
+        fs::create_dir_all(&dir).unwrap();
+        let source_path = dir.join("monitor.rs");
+        let binary_path = dir.join("monitor_test");
+        fs::write(&source_path,monitor_as_rust_module(&gen_ctx,&alphabet,&table,&checked_traces,"random")).unwrap();
+        let rustc = std::env::var("RUSTC").unwrap_or("rustc".to_string());
+        let compilation = Command::new(rustc)
+            .args(["--edition","2021","--test","-D","warnings","-o"])
+            .arg(&binary_path)
+            .arg(&source_path)
+            .output()
+            .unwrap();
+        assert!(compilation.status.success(), "{:}", String::from_utf8_lossy(&compilation.stderr));
+        let execution = Command::new(&binary_path).output().unwrap();
+        assert!(execution.status.success(), "{:}", String::from_utf8_lossy(&execution.stdout));
+    }
+
+}
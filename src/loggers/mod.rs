@@ -16,5 +16,7 @@ limitations under the License.
 
 
 pub mod graphviz;
+pub mod stats;
 pub mod tracegen;
+pub mod witness;
 
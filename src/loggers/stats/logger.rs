@@ -0,0 +1,264 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::config::{AbstractProcessConfiguration, AbstractProcessParameterization};
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+
+/**
+ * Collects statistics on a process (of any kind) so that they can be reported once it terminates.
+ * The size of the queue is not exposed by the process manager, so it is computed once the process terminates :
+ * each step which is extracted from the queue is notified (either as filtered or as a new step) with its parent node,
+ * and the children of a node are enqueued right after its creation.
+ * Steps which are still in the queue when the process stops early (once its goal is reached) are never notified
+ * and are therefore not counted.
+ **/
+pub struct GenericProcessStatsLogger<Conf : AbstractProcessConfiguration> {
+    pub strategy : String,
+    pub priorities : String,
+    pub filters : Vec<String>,
+    pub goal : Option<String>,
+    pub use_memoization : bool,
+    pub parameterization : Vec<String>,
+    // ***
+    pub nodes_num : u32,
+    pub edges_num : u32,
+    pub filtered : BTreeMap<String,u32>,
+    pub max_queue_size : u32,
+    pub verdict : Option<String>,
+    // ***
+    // number of steps extracted from the queue so far
+    dequeued_num : u32,
+    // for each node, the number of steps extracted from the queue before its children were enqueued
+    enqueued_after : HashMap<u32,u32>,
+    // for each node, the number of its children which were extracted from the queue
+    children_num : HashMap<u32,u32>,
+    phantom : PhantomData<Conf>
+}
+
+impl<Conf : AbstractProcessConfiguration> GenericProcessStatsLogger<Conf> {
+
+    pub fn new() -> Self {
+        GenericProcessStatsLogger{
+            strategy : "".to_string(),
+            priorities : "".to_string(),
+            filters : vec![],
+            goal : None,
+            use_memoization : false,
+            parameterization : vec![],
+            nodes_num : 0,
+            edges_num : 0,
+            filtered : BTreeMap::new(),
+            max_queue_size : 0,
+            verdict : None,
+            dequeued_num : 0,
+            enqueued_after : HashMap::new(),
+            children_num : HashMap::new(),
+            phantom : PhantomData
+        }
+    }
+
+    pub fn filtered_num(&self) -> u32 {
+        self.filtered.values().sum()
+    }
+
+    fn on_step_dequeued(&mut self, parent_node_id : u32) {
+        self.dequeued_num += 1;
+        *self.children_num.entry(parent_node_id).or_insert(0) += 1;
+    }
+
+    fn compute_max_queue_size(&self) -> u32 {
+        // number of steps enqueued after each number of extractions
+        let mut enqueued : Vec<u32> = vec![0; (self.dequeued_num + 1) as usize];
+        for (node_id,children_num) in &self.children_num {
+            if let Some(after) = self.enqueued_after.get(node_id) {
+                enqueued[*after as usize] += children_num;
+            }
+        }
+        let mut queue_size : u32 = 0;
+        let mut max_queue_size : u32 = 0;
+        for enqueued_num in enqueued {
+            queue_size += enqueued_num;
+            max_queue_size = max_queue_size.max(queue_size);
+            queue_size = queue_size.saturating_sub(1);
+        }
+        return max_queue_size;
+    }
+
+}
+
+impl<Conf : AbstractProcessConfiguration + 'static> AbstractProcessLogger<Conf> for GenericProcessStatsLogger<Conf> {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.nodes_num = 0;
+        self.edges_num = 0;
+        self.filtered = BTreeMap::new();
+        self.max_queue_size = 0;
+        self.verdict = None;
+        self.dequeued_num = 0;
+        self.enqueued_after = HashMap::new();
+        self.children_num = HashMap::new();
+    }
+
+    fn log_parameterization(&mut self,
+                            strategy: &QueueSearchStrategy,
+                            priorities: &GenericProcessPriorities<Conf::Priorities>,
+                            filters: &[Box<dyn AbstractFilter<Conf::FilterCriterion, Conf::FilterEliminationKind>>],
+                            goal: &Option<Conf::GlobalVerdict>,
+                            use_memoization: bool,
+                            parameterization: &Conf::Parameterization) {
+        self.strategy = strategy.to_string();
+        self.priorities = priorities.to_string();
+        self.filters = filters.iter().map(|f| f.to_string()).collect();
+        self.goal = goal.as_ref().map(|g| g.to_string());
+        self.use_memoization = use_memoization;
+        self.parameterization = parameterization.get_param_as_strings();
+    }
+
+    fn log_filtered(&mut self,
+                    context: &Conf::Context,
+                    parent_node_id: u32,
+                    new_node_id: u32,
+                    elim_kind: &Conf::FilterEliminationKind) {
+        self.on_step_dequeued(parent_node_id);
+        *self.filtered.entry(elim_kind.to_string()).or_insert(0) += 1;
+    }
+
+    fn log_new_node(&mut self,
+                    context: &Conf::Context,
+                    param: &Conf::Parameterization,
+                    new_node_id: u32,
+                    new_node: &Conf::NodeKind) {
+        // a new node other than the initial one is notified before the step from which it is reached
+        let enqueued_after = if self.nodes_num == 0 {0} else {self.dequeued_num + 1};
+        self.enqueued_after.insert(new_node_id,enqueued_after);
+        self.nodes_num += 1;
+    }
+
+    fn log_new_step(&mut self,
+                    context: &Conf::Context,
+                    param: &Conf::Parameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &Conf::StepKind,
+                    target_node: &Conf::NodeKind,
+                    target_depth: u32) {
+        self.on_step_dequeued(origin_node_id);
+        self.edges_num += 1;
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               context: &Conf::Context,
+                               param: &Conf::Parameterization,
+                               parent_node_id: u32,
+                               verdict: &Conf::LocalVerdict) {
+        // nothing
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      context: &Conf::Context,
+                                      param: &Conf::Parameterization,
+                                      parent_node_id: u32,
+                                      verdict: &Conf::LocalVerdict,
+                                      proof: &Conf::StaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     global_verdict: &Conf::GlobalVerdict) {
+        self.max_queue_size = self.compute_max_queue_size();
+        self.verdict = Some(global_verdict.to_string());
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        context: &Conf::Context,
+                                        node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               context: &Conf::Context,
+                                               parent_node_id: u32) {
+        // nothing
+    }
+
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::process::explo::conf::ExplorationConfig;
+    use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
+    use crate::process::explo::filter::filter::ExplorationFilter;
+    use crate::process::explo::node::ExplorationNodeKind;
+    use crate::process::explo::priorities::ExplorationPriorities;
+    use crate::process::explo::step::ExplorationStepKind;
+    use super::*;
+
+    #[test]
+    fn exploration_statistics_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let int = interaction_from_text(&gen_ctx,"par(l1 -- m1 ->|,par(l2 -- m1 ->|,l3 -- m1 ->|))").unwrap();
+        let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> =
+            GenericProcessDelegate::new(QueueSearchStrategy::BFS,
+                                        GenericProcessPriorities::new(ExplorationPriorities::default(),false));
+        let mut manager : GenericProcessManager<ExplorationConfig> =
+            GenericProcessManager::new(ExplorationContext::new(gen_ctx),
+                                       ExplorationParameterization{},
+                                       delegate,
+                                       vec![Box::new(ExplorationFilter::MaxProcessDepth(1))],
+                                       vec![Box::new(GenericProcessStatsLogger::<ExplorationConfig>::new())],
+                                       None,
+                                       true);
+        manager.start_process(ExplorationNodeKind::new(int,0));
+        let stats : &GenericProcessStatsLogger<ExplorationConfig> = manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<GenericProcessStatsLogger<ExplorationConfig>>().unwrap();
+        // the initial node and the three interactions reached by executing one of its actions
+        assert_eq!(stats.nodes_num, 4);
+        assert_eq!(stats.edges_num, 3);
+        // the two steps from each of these three interactions exceed the maximum depth
+        assert_eq!(stats.filtered_num(), 6);
+        assert_eq!(stats.filtered.get("MaxDepth"), Some(&6));
+        // with BFS, the three steps from the initial node are replaced by two steps each before any is filtered
+        assert_eq!(stats.max_queue_size, 6);
+        assert_eq!(stats.strategy, QueueSearchStrategy::BFS.to_string());
+    }
+
+}
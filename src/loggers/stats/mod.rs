@@ -15,12 +15,4 @@ limitations under the License.
 */
 
 
-
-pub mod step;
-pub mod filter;
-pub mod conf;
-pub mod priorities;
-pub mod node;
-
 pub mod logger;
-
@@ -0,0 +1,44 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::interpretation::witness::{ExecutionWitness, WitnessStep};
+use crate::loggers::witness::object::WitnessLoggerObject;
+
+
+// prints a witness i.e. the sequence of execution steps leading to each accepting node
+pub struct WitnessProcessPrinter {}
+
+impl WitnessProcessPrinter {
+    pub fn new() -> Self {
+        WitnessProcessPrinter {}
+    }
+}
+
+impl WitnessProcessPrinter {
+
+    pub(crate) fn get_initial_witness(&self) -> WitnessLoggerObject {
+        WitnessLoggerObject::new(ExecutionWitness::new_empty())
+    }
+
+    pub(crate) fn add_step_to_witness(&self,
+                                      object : &WitnessLoggerObject,
+                                      step : WitnessStep) -> WitnessLoggerObject {
+        WitnessLoggerObject::new(object.witness.add_step(step))
+    }
+
+}
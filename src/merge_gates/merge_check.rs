@@ -15,128 +15,87 @@ limitations under the License.
 */
 
 
-use std::collections::{HashMap, HashSet};
-use crate::core::language::syntax::interaction::{Interaction};
-use crate::core::language::syntax::action::*;
-use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use std::collections::HashSet;
 
+use crate::core::language::involve::involves::InvolvesLifelines;
+use crate::core::language::syntax::action::EmissionTargetRef;
+use crate::core::language::syntax::interaction::Interaction;
 
 
-
-
-pub fn check_if_merge_possible(i1 : &Interaction, i2 : &Interaction, connections : &mut HashMap<usize,usize>) -> bool {
-    let (mut i1_em_acts,mut i1_rc_acts) = i1.contained_model_actions();
-    let (mut i2_em_acts,mut i2_rc_acts) = i2.contained_model_actions();
-    check_matching_ems_rcs(i1_em_acts,i2_rc_acts,connections);
-    check_matching_ems_rcs(i2_em_acts,i1_rc_acts,connections);
-    if connections.len() > 0 {
-        // it is not possible to find all required connections
-        return false;
-    } else {
-        // it may be possible to find all required connections
-        return true;
+/**
+Two interactions can be merged if they have disjoint sets of lifelines
+and if each gate is used at most once by each of them.
+**/
+pub fn check_models_for_merge(i1 : &Interaction, i2 : &Interaction) -> Result<(),String> {
+    if !i1.involved_lifelines().is_disjoint(&i2.involved_lifelines()) {
+        return Err( "interactions to merge must have disjoint sets of lifelines".to_string() );
     }
-}
-
-
-
-fn check_matching_ems_rcs(em_acts : HashSet<&EmissionAction>, rc_acts : HashSet<&ReceptionAction>, connections : &mut HashMap<usize,usize>) {
-    let mut got_rc_acts = rc_acts;
-    for em in em_acts {
-        let mut rem_rc_acts = hashset!{};
-        for rc in got_rc_acts {
-            match is_action_merge_impossible(em,rc,connections) {
-                None => {},
-                Some(reason) => {
-                    rem_rc_acts.insert(rc);
+    for interaction in [i1,i2] {
+        let (em_acts,rc_acts) = interaction.contained_model_actions();
+        let mut emitted_to : HashSet<usize> = hashset!{};
+        for em_act in em_acts {
+            for target_ref in &em_act.targets {
+                if let EmissionTargetRef::Gate(gt_id) = target_ref {
+                    if !emitted_to.insert(*gt_id) {
+                        return Err( "each gate must be used at most once in each interaction".to_string() );
+                    }
                 }
             }
         }
-        got_rc_acts = rem_rc_acts;
-    }
-}
-
-
-
-enum WillNotMergeBecause {
-    ReceptionFromEnvironment,
-    MismatchOfMessageID,
-    CouldNotFindConnection
-}
-
-fn is_action_merge_impossible(em_act : &EmissionAction, rc_act : &ReceptionAction, connections : &mut HashMap<usize,usize>) -> Option<WillNotMergeBecause> {
-    match &rc_act.origin_gt_id {
-        None => {
-            return Some(WillNotMergeBecause::ReceptionFromEnvironment);
-        },
-        Some( gt_id_in_rc ) => {
-            for target_ref in &em_act.targets {
-                match target_ref {
-                    EmissionTargetRef::Gate( gt_id_in_em) => {
-                        match connections.get(gt_id_in_em) {
-                            None => {},
-                            Some( target_gt_id ) => {
-                                if target_gt_id == gt_id_in_rc {
-                                    if em_act.ms_id == rc_act.ms_id {
-                                        connections.remove(gt_id_in_em);
-                                        return None;
-                                    } else {
-                                        return Some(WillNotMergeBecause::MismatchOfMessageID);
-                                    }
-                                }
-                            }
-                        }
-                    },
-                    _ => {}
+        let mut received_from : HashSet<usize> = hashset!{};
+        for rc_act in rc_acts {
+            if let Some(gt_id) = rc_act.origin_gt_id {
+                if !received_from.insert(gt_id) {
+                    return Err( "each gate must be used at most once in each interaction".to_string() );
                 }
             }
         }
     }
-    return Some(WillNotMergeBecause::CouldNotFindConnection);
+    return Ok(());
 }
 
-
-
-
-pub fn check_models_for_merge(i1 : &Interaction, i2 : &Interaction) -> bool {
-    let i1_lfs = i1.involved_lifelines();
-    let i2_lfs = i2.involved_lifelines();
-    if i1_lfs.is_disjoint(&i2_lfs) {
-        let (i1_em_acts,i1_rc_acts) = i1.contained_model_actions();
-        let (i2_em_acts,i2_rc_acts) = i2.contained_model_actions();
-        // ***
-        let mut got_gates: HashSet<usize> = hashset!{};
-        for em_act in i1_em_acts.union(&i2_em_acts) {
-            for targ_ref in &em_act.targets {
-                match targ_ref {
-                    EmissionTargetRef::Gate( gt_id ) => {
-                        if got_gates.contains(gt_id) {
-                            println!("Each gate must be used at most once");
-                            return false;
-                        } else {
-                            got_gates.insert(*gt_id);
-                        }
-                    },
-                    _ => {}
+/**
+Whether or not the interaction still uses one of the given gates.
+**/
+pub fn uses_any_gate(interaction : &Interaction, gates : &HashSet<usize>) -> bool {
+    let (em_acts,rc_acts) = interaction.contained_model_actions();
+    for em_act in em_acts {
+        for target_ref in &em_act.targets {
+            if let EmissionTargetRef::Gate(gt_id) = target_ref {
+                if gates.contains(gt_id) {
+                    return true;
                 }
             }
         }
-        for rc_act in i1_rc_acts.union(&i2_rc_acts) {
-            match &rc_act.origin_gt_id {
-                None => {},
-                Some(gt_id) => {
-                    if got_gates.contains(gt_id) {
-                        println!("Each gate must be used at most once");
-                        return false;
-                    } else {
-                        got_gates.insert(*gt_id);
-                    }
-                }
+    }
+    for rc_act in rc_acts {
+        if let Some(gt_id) = rc_act.origin_gt_id {
+            if gates.contains(&gt_id) {
+                return true;
             }
         }
-    } else {
-        println!("Interactions to merge must have disjoint sets of lifelines");
-        return false;
     }
-    return true;
+    return false;
+}
+
+pub fn contains_and(interaction : &Interaction) -> bool {
+    match interaction {
+        &Interaction::Empty | &Interaction::Emission(_) | &Interaction::Reception(_) => {
+            false
+        },
+        &Interaction::And(_, _) => {
+            true
+        },
+        &Interaction::Strict(ref i1, ref i2)
+        | &Interaction::Seq(ref i1, ref i2)
+        | &Interaction::CoReg(_, ref i1, ref i2)
+        | &Interaction::Alt(ref i1, ref i2)
+        | &Interaction::Par(ref i1, ref i2)
+        | &Interaction::Sync(_, ref i1, ref i2) => {
+            contains_and(i1) || contains_and(i2)
+        },
+        &Interaction::Loop(_, ref i1) => {
+            contains_and(i1)
+        }
+    }
 }
@@ -14,6 +14,7 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
+
 pub mod merge_check;
-//pub mod merge;
-//pub mod process;
\ No newline at end of file
+pub mod merge;
+pub mod process;
@@ -14,207 +14,92 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use std::fs;
-use std::fs::File;
-use std::io::{Read,BufReader,BufRead,BufWriter,Write};
 
-use std::collections::{HashSet,HashMap};
+use std::collections::HashSet;
 
-use std::process::Command;
-
-use graphviz_dot_builder::graph::*;
-use graphviz_dot_builder::node_style::*;
-use graphviz_dot_builder::edge_style::*;
-use graphviz_dot_builder::common::*;
-
-
-use crate::core::language::syntax::interaction::{Interaction};
-use crate::core::language::syntax::action::*;
-use crate::core::language::position::position::Position;
-use crate::core::general_context::GeneralContext;
-
-use crate::canonize::term_repr_out::to_term_repr_temp;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::transfofunc::merge::merge_par::get_connecting_gates;
 use crate::merge_gates::merge::get_all_merges_rec;
+use crate::merge_gates::merge_check::{contains_and, uses_any_gate};
+use crate::nfa_translation::canonize::normalize_interaction;
+use crate::process::canon::param::default::DefaultCanonizationProcess;
 
-use crate::canonize::transformations::phases::InteractionTermTransformation;
 
-pub fn merge_process_interaction_term(interaction : &Interaction,
-                                      gen_ctx : &GeneralContext,
-                                      opt_returns : bool,
-                                      opt_complete : bool,
-                                      opt_graphic : bool,
-                                      master_name : &str) {
-    // ***
-    if opt_graphic {
-        // empties temp directory if exists
-        match fs::remove_dir_all("./temp") {
-            Ok(_) => {
-                // do nothing
-            },
-            Err(e) => {
-                // do nothing
-            }
-        }
-        // creates temp directory
-        fs::create_dir_all("./temp").unwrap();
-        // ***
-        let mut file = File::create(&format!("{:}_mergeproc.dot", master_name)).unwrap();
-        file.write(format!("digraph {} {{\n", master_name).as_bytes());
-        file.write("overlap=false;\n".as_bytes());
-        merge_process(&interaction, gen_ctx, opt_returns, opt_complete, &mut Some(&mut file));
-        file.write("}\n".as_bytes());
-        let status = Command::new("dot")
-            .arg("-Tsvg:cairo")
-            .arg(&format!("{:}_mergeproc.dot", master_name))
-            .arg("-o")
-            .arg(&format!("{:}_mergeproc.svg", master_name))
-            .output();
-    } else {
-        merge_process( &interaction, gen_ctx, opt_returns, opt_complete,&mut None);
-    }
+pub static DEFAULT_MERGE_MAX_NODES : u32 = 10000;
+
+pub struct MergeProcessResult {
+    // the distinct composed interactions, simplified, in the order in which they were found
+    pub merged : Vec<Interaction>,
+    pub node_count : u32,
+    // false if the exploration was stopped because of the maximum number of nodes
+    pub is_complete : bool
 }
 
-fn merge_process(init_interaction : &Interaction,gen_ctx : &GeneralContext,opt_returns : bool, opt_complete : bool, graphic_file : &mut Option<&mut File>) {
-    // ***
-    // init process
-    let mut queue : Vec<(u32,Interaction)> = vec![(1,init_interaction.clone())];
-    let mut next_index : u32 = 2;
+/**
+Explores the possible ways of merging the two interactions, starting from "i1 and i2".
+A composed interaction contains no "and" and none of the gates connecting both interactions,
+all the emissions towards these gates having been merged with the corresponding receptions.
+**/
+pub fn merge_interactions(i1 : &Interaction,
+                          i2 : &Interaction,
+                          max_nodes : u32) -> MergeProcessResult {
+    let connecting_gates = get_connecting_gates(i1,i2);
+    let init_interaction = Interaction::And(Box::new(i1.clone()),Box::new(i2.clone()));
     // ***
-    let mut known : HashMap<Interaction,u32> = HashMap::new();
-    known.insert( init_interaction.clone(), 1 );
+    let mut queue : Vec<Interaction> = vec![init_interaction.clone()];
+    let mut known : HashSet<Interaction> = hashset!{init_interaction};
+    let mut merged : Vec<Interaction> = vec![];
+    let mut is_complete = true;
     // ***
-    match graphic_file {
-        None => {},
-        Some( ref mut file ) => {
-            // source node
-            {
-                let mut node_gv_options : GraphvizNodeStyle = Vec::new();
-                node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle) );
-                node_gv_options.push( GraphvizNodeStyleItem::Style(vec![GvNodeStyleKind::Filled]) );
-                node_gv_options.push( GraphvizNodeStyleItem::Label("".to_string()) );
-                let gv_node = GraphVizNode{id : "i0".to_string(), style : node_gv_options};
-                file.write( gv_node.to_dot_string().as_bytes() );
-                file.write("\n".as_bytes() );
-            }
-            // ***
-            // transition from source
-            {
-                let gv_edge = GraphVizEdge{origin_id : "i0".to_string(), target_id : "i1".to_string(), style : Vec::new()};
-                file.write( gv_edge.to_dot_string().as_bytes() );
-                file.write("\n".as_bytes() );
-            }
-            file.write("subgraph cluster_merge {\n".as_bytes() );
-            file.write("style=filled;color=lightgoldenrod1;label=\"merging gates\";\n".as_bytes() );
-            // first node
-            {
-                to_term_repr_temp(&"i1".to_string(),init_interaction,gen_ctx);
-                let mut node_gv_options : GraphvizNodeStyle = Vec::new();
-                node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle) );
-                node_gv_options.push( GraphvizNodeStyleItem::Image( "temp/i1.png".to_string() ) );
-                node_gv_options.push( GraphvizNodeStyleItem::Label( "".to_string() ) );
-                let gv_node = GraphVizNode{id : "i1".to_string(), style : node_gv_options};
-                file.write( gv_node.to_dot_string().as_bytes() );
-                file.write("\n".as_bytes() );
+    while let Some(parent_interaction) = queue.pop() {
+        if !contains_and(&parent_interaction) {
+            if !uses_any_gate(&parent_interaction,&connecting_gates) {
+                let simplified = normalize_interaction(&parent_interaction,DefaultCanonizationProcess::Basic);
+                if !merged.contains(&simplified) {
+                    merged.push(simplified);
+                }
             }
+            continue;
         }
-    }
-    // =====================================================================================================
-    // ***
-    let mut finals : HashSet<(u32,Interaction)> = HashSet::new();
-    while queue.len() > 0 {
-        let (parent_id,parent_interaction) = queue.pop().unwrap();
-        let parent_id_str = format!("i{}", parent_id);
-        // ***
-        let mut available_transfos = get_all_merges_rec(&parent_interaction);
-        // ***
-        if available_transfos.len() > 0 {
-            for transformed in available_transfos {
-                if known.contains_key(&transformed.result) {
-                    let target_id = known.get(&transformed.result).unwrap();
-                    match graphic_file {
-                        None => {},
-                        Some( ref mut file ) => {
-                            // new transition
-                            let mut tran_gv_options : GraphvizEdgeStyle = Vec::new();
-                            tran_gv_options.push( GraphvizEdgeStyleItem::Label( transformed.transformation_str_description() ));
-                            let gv_edge = GraphVizEdge{origin_id : parent_id_str.clone(),
-                                target_id : format!("i{}",&target_id),
-                                style : tran_gv_options};
-                            file.write( gv_edge.to_dot_string().as_bytes() );
-                            file.write("\n".as_bytes() );
-                        }
-                    }
-                    // then discard the new interaction
-                } else {
-                    // ***
-                    let new_id_str = format!("i{}", next_index);
-                    match graphic_file {
-                        None => {},
-                        Some( ref mut file ) => {
-                            // new interaction node
-                            {
-                                to_term_repr_temp(&new_id_str,&transformed.result, gen_ctx);
-                                let mut node_gv_options : GraphvizNodeStyle = Vec::new();
-                                node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle) );
-                                node_gv_options.push( GraphvizNodeStyleItem::Image( format!("temp/{}.png",new_id_str) ) );
-                                node_gv_options.push( GraphvizNodeStyleItem::Label( "".to_string() ) );
-                                let gv_node = GraphVizNode{id : new_id_str.clone(), style : node_gv_options};
-                                file.write( gv_node.to_dot_string().as_bytes() );
-                                file.write("\n".as_bytes() );
-                            }
-                            // new transition
-                            {
-                                let mut tran_gv_options : GraphvizEdgeStyle = Vec::new();
-                                tran_gv_options.push( GraphvizEdgeStyleItem::Label( transformed.transformation_str_description() ));
-                                let gv_edge = GraphVizEdge{origin_id : parent_id_str.clone(),
-                                    target_id : new_id_str,
-                                    style : tran_gv_options};
-                                file.write( gv_edge.to_dot_string().as_bytes() );
-                                file.write("\n".as_bytes() );
-                            }
-                        }
-                    }
-                    // save the new interaction
-                    known.insert( transformed.result.clone(), next_index );
-                    queue.push((next_index,transformed.result) );
-                    next_index = next_index + 1;
-                }
+        for transformed in get_all_merges_rec(&parent_interaction) {
+            if known.contains(&transformed.result) {
+                continue;
             }
-        } else {
-            if opt_returns {
-                if opt_complete {
-                    if !parent_interaction.has_gates() {
-                        //TODO: create file and write
-                        break;
-                    }
-                } else {
-                    //TODO: create file and write
-                    break;
-                }
+            if known.len() as u32 >= max_nodes {
+                is_complete = false;
+                break;
             }
-            finals.insert( (parent_id,parent_interaction) );
-        }
-    }
-    match graphic_file {
-        None => {},
-        Some( ref mut file ) => {
-            file.write("}\n".as_bytes() );
+            known.insert(transformed.result.clone());
+            queue.push(transformed.result);
         }
     }
-
-    // ***
-    // =====================================================================================================
-    //assert!(finals.len() == 1);
-    //let (_,canonical_interaction) = finals.remove(0);
-    //return canonical_interaction;
+    return MergeProcessResult{merged,node_count:known.len() as u32,is_complete};
 }
 
 
 
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
+    use super::*;
+
+    #[test]
+    fn merge_through_gate_test() {
+        // l1 emits m1 towards gate g1 then emits m2 to the environment
+        let em_to_gate = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![EmissionTargetRef::Gate(0)]));
+        let em_to_env = Interaction::Emission(EmissionAction::new(0,1,CommunicationSynchronicity::Asynchronous,vec![]));
+        let i1 = Interaction::Strict(Box::new(em_to_gate),Box::new(em_to_env.clone()));
+        // l2 receives m1 from gate g1
+        let i2 = Interaction::Reception(ReceptionAction::new(Some(0),0,CommunicationSynchronicity::Asynchronous,vec![1]));
+        let result = merge_interactions(&i1,&i2,DEFAULT_MERGE_MAX_NODES);
+        assert!(result.is_complete);
+        let expected = Interaction::Strict(
+            Box::new(Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![EmissionTargetRef::Lifeline(1)]))),
+            Box::new(em_to_env));
+        assert!(result.merged.contains(&expected));
+        for merged in &result.merged {
+            assert!(!contains_and(merged));
+        }
+    }
 
-
-
-
-
-
-
+}
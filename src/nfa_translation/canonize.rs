@@ -28,9 +28,35 @@ use crate::process::canon::param::phase::CanonizationParameterization;
 use crate::process::canon::priorities::CanonizationPriorities;
 use crate::process::canon::step::CanonizationStepKind;
 
+/**
+Unless all the transformations are to be considered (with "search_all"), the phase of the "basic" process is applied
+by the deterministic normalizer, which is much faster on large interactions.
+The other processes are always run as a search given that the normalizer may reach other normal forms
+when the order in which transformations are applied matters (e.g. with defactorizations).
+**/
 pub fn canonize_interaction(gen_ctx : &GeneralContext,
                             int : &Interaction,
-                            def : DefaultCanonizationProcess) -> Interaction {
+                            def : DefaultCanonizationProcess,
+                            search_all : bool) -> Interaction {
+    if def == DefaultCanonizationProcess::Basic && !search_all {
+        return normalize_interaction(int,def);
+    }
+    return canonize_interaction_by_search(gen_ctx,int,def,search_all);
+}
+
+pub fn normalize_interaction(int : &Interaction,
+                             def : DefaultCanonizationProcess) -> Interaction {
+    let mut normalized = int.clone();
+    for phase in def.get_phases(false) {
+        normalized = phase.normalize(&normalized);
+    }
+    return normalized;
+}
+
+pub fn canonize_interaction_by_search(gen_ctx : &GeneralContext,
+                                      int : &Interaction,
+                                      def : DefaultCanonizationProcess,
+                                      search_all : bool) -> Interaction {
     let canon_opts = HibouCanonizeOptions::default();
 
     let canon_ctx = CanonizationContext::new(gen_ctx.clone());
@@ -40,7 +66,7 @@ pub fn canonize_interaction(gen_ctx : &GeneralContext,
             canon_opts.priorities
         );
 
-    let canon_param = CanonizationParameterization::from_default(def,canon_opts.search_all);
+    let canon_param = CanonizationParameterization::from_default(def,search_all);
 
     let mut canon_manager : GenericProcessManager<CanonizationConfig> = GenericProcessManager::new(
         canon_ctx,
@@ -60,3 +86,53 @@ pub fn canonize_interaction(gen_ctx : &GeneralContext,
     }
     verdict.canonized_ints.remove(0)
 }
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::experiments::interaction_random_gen::gen::generate_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use super::*;
+
+    #[test]
+    fn normalizer_equals_search_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let mut rng = StdRng::seed_from_u64(0);
+        for probas in [InteractionSymbolsProbabilities::default_regular(),
+                       InteractionSymbolsProbabilities::conservative()] {
+            for _ in 0..50 {
+                let int = generate_random_interaction(&probas,0,4,&gen_ctx,&mut rng);
+                assert_eq!(normalize_interaction(&int,DefaultCanonizationProcess::Basic),
+                           canonize_interaction_by_search(&gen_ctx,&int,DefaultCanonizationProcess::Basic,false));
+            }
+        }
+    }
+
+    #[test]
+    fn canonization_equals_search_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let mut rng = StdRng::seed_from_u64(0);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        for _ in 0..20 {
+            let int = generate_random_interaction(&probas,0,3,&gen_ctx,&mut rng);
+            // without "search_all" the normalizer is used
+            assert_eq!(canonize_interaction(&gen_ctx,&int,DefaultCanonizationProcess::Basic,false),
+                       canonize_interaction_by_search(&gen_ctx,&int,DefaultCanonizationProcess::Basic,false));
+        }
+    }
+
+}
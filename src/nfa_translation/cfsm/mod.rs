@@ -0,0 +1,160 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::traits::transform::AutTransformable;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::nfa_translation::realizability::channels::AsyncChannels;
+
+
+pub struct CfsmTransition {
+    pub source : usize,
+    pub target : usize,
+    pub act_kind : TraceActionKind,
+    pub ms_id : usize,
+    // receivers of an emission or possible emitters of a reception (empty for the environment)
+    pub peers : Vec<usize>
+}
+
+/**
+ * Communicating finite state machine of a lifeline i.e. its local automaton
+ * in which actions are labelled with the other ends of the communications.
+ * States are numbered in breadth-first order from the initial state 0.
+ **/
+pub struct CommunicatingMachine {
+    pub lf_id : usize,
+    pub num_states : usize,
+    pub finals : Vec<usize>,
+    pub transitions : Vec<CfsmTransition>
+}
+
+impl CommunicatingMachine {
+
+    /**
+     * Builds the machine of lifeline "lf_id" from its (minimized) local automaton.
+     * Peers are given by the addressing of messages inferred in "channels".
+     **/
+    pub fn new(lf_id : usize,
+               local_dfa : AutDFA<usize>,
+               alphabet : &Vec<BTreeSet<TraceAction>>,
+               channels : &AsyncChannels) -> CommunicatingMachine {
+        let dfa = local_dfa.minimize();
+        let mut states_ids : HashMap<usize,usize> = HashMap::new();
+        states_ids.insert(dfa.initial,0);
+        let mut queue : VecDeque<usize> = VecDeque::new();
+        queue.push_back(dfa.initial);
+        let mut finals = vec![];
+        let mut transitions = vec![];
+        while let Some(dfa_state) = queue.pop_front() {
+            let source = *states_ids.get(&dfa_state).unwrap();
+            if dfa.finals.contains(&dfa_state) {
+                finals.push(source);
+            }
+            let mut outgoing : Vec<(usize,usize)> = dfa.transitions.get(dfa_state).unwrap()
+                .iter()
+                .map(|(letter,target)| (*letter,*target))
+                .collect();
+            outgoing.sort();
+            for (letter,dfa_target) in outgoing {
+                let target = match states_ids.get(&dfa_target) {
+                    Some(got_id) => {
+                        *got_id
+                    },
+                    None => {
+                        let new_id = states_ids.len();
+                        states_ids.insert(dfa_target,new_id);
+                        queue.push_back(dfa_target);
+                        new_id
+                    }
+                };
+                let action = alphabet.get(letter).unwrap().iter().next().unwrap();
+                let peers : Vec<usize> = match action.act_kind {
+                    TraceActionKind::Emission => {
+                        match channels.addressing.get(&(action.lf_id,action.ms_id)) {
+                            None => {
+                                vec![]
+                            },
+                            Some(receivers) => {
+                                receivers.iter().cloned().collect()
+                            }
+                        }
+                    },
+                    TraceActionKind::Reception => {
+                        channels.addressing.iter()
+                            .filter(|((_,ms_id),receivers)| *ms_id == action.ms_id && receivers.contains(&action.lf_id))
+                            .map(|((emitter,_),_)| *emitter)
+                            .collect()
+                    }
+                };
+                transitions.push(CfsmTransition{source,target,act_kind:action.act_kind,ms_id:action.ms_id,peers});
+            }
+        }
+        CommunicatingMachine{lf_id,num_states:states_ids.len(),finals,transitions}
+    }
+
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef};
+    use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+    use crate::nfa_translation::realizability::local::{get_lifelines_local_interactions, get_local_dfa};
+    use super::*;
+
+    #[test]
+    fn communicating_machines_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("a".to_string());
+        gen_ctx.add_lf("b".to_string());
+        gen_ctx.add_msg("m".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let message = Interaction::Emission(EmissionAction::new(0,
+                                                                0,
+                                                                CommunicationSynchronicity::Asynchronous,
+                                                                vec![EmissionTargetRef::Lifeline(1)]));
+        let int = Interaction::Strict(Box::new(message.clone()),
+                                      Box::new(Interaction::Loop(LoopKind::WWeakSeq,Box::new(message))));
+        let channels = AsyncChannels::from_interaction(&gen_ctx,&int).unwrap();
+        let machines : Vec<CommunicatingMachine> = get_lifelines_local_interactions(&gen_ctx,&int).into_iter()
+            .enumerate()
+            .map(|(lf_id,local_int)| {
+                let (local_nfa,_) = get_nfa_from_interaction_exploration(&gen_ctx,&local_int,alphabet.clone());
+                CommunicatingMachine::new(lf_id,get_local_dfa(local_nfa),&alphabet,&channels)
+            })
+            .collect();
+        // both lifelines follow "m.m*" which requires two states once minimized
+        for (lf_id,machine) in machines.iter().enumerate() {
+            assert_eq!(machine.num_states, 2);
+            assert_eq!(machine.finals, vec![1]);
+            assert_eq!(machine.transitions.len(), 2);
+            for transition in &machine.transitions {
+                assert_eq!(transition.peers, vec![1 - lf_id]);
+            }
+        }
+    }
+
+}
@@ -0,0 +1,177 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{HashMap, VecDeque};
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+
+
+/**
+ * Returns a shortest word accepted by "left" and not by "right", if any.
+ * It is found by a breadth-first search on the product of their determinizations,
+ * in which the right DFA may be in a sink state (None) once it can no longer read the word.
+ * Letters are tried in increasing order so that the returned word is also the least one of its length.
+ **/
+pub fn get_shortest_word_in_difference(left : &AutNFA<usize>,
+                                       right : &AutNFA<usize>) -> Option<Vec<usize>> {
+    let left_dfa = left.to_dfa();
+    let right_dfa = right.to_dfa();
+    let init = (left_dfa.initial,Some(right_dfa.initial));
+    // each reached pair is associated with the pair and letter it was reached from
+    let mut parents : HashMap<(usize,Option<usize>),Option<((usize,Option<usize>),usize)>> = HashMap::new();
+    parents.insert(init,None);
+    let mut queue = VecDeque::new();
+    queue.push_back(init);
+    while let Some((left_state,right_state)) = queue.pop_front() {
+        let right_accepts = match right_state {
+            None => {
+                false
+            },
+            Some(state) => {
+                right_dfa.finals.contains(&state)
+            }
+        };
+        if left_dfa.finals.contains(&left_state) && !right_accepts {
+            let mut word = vec![];
+            let mut current = (left_state,right_state);
+            while let Some(Some((parent,letter))) = parents.get(&current) {
+                word.push(*letter);
+                current = *parent;
+            }
+            word.reverse();
+            return Some(word);
+        }
+        let mut outgoing : Vec<(usize,usize)> = left_dfa.transitions.get(left_state).unwrap()
+            .iter()
+            .map(|(letter,target)| (*letter,*target))
+            .collect();
+        outgoing.sort();
+        for (letter,left_target) in outgoing {
+            let right_target = match right_state {
+                None => {
+                    None
+                },
+                Some(state) => {
+                    right_dfa.transitions.get(state).unwrap().get(&letter).cloned()
+                }
+            };
+            let next = (left_target,right_target);
+            if !parents.contains_key(&next) {
+                parents.insert(next,Some(((left_state,right_state),letter)));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+
+
+
+/**
+ * Whether both interactions accept the same traces, as checked on the NFAs built from the exploration of their semantics.
+ **/
+pub fn interactions_accept_same_traces(gen_ctx : &GeneralContext,
+                                       left : &Interaction,
+                                       right : &Interaction) -> bool {
+    let alphabet = get_alphabet_from_gen_ctx(gen_ctx);
+    let (left_nfa,_) = get_nfa_from_interaction_exploration(gen_ctx,left,alphabet.clone());
+    let (right_nfa,_) = get_nfa_from_interaction_exploration(gen_ctx,right,alphabet);
+    return get_shortest_word_in_difference(&left_nfa,&right_nfa).is_none()
+        && get_shortest_word_in_difference(&right_nfa,&left_nfa).is_none();
+}
+
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::experiments::interaction_random_gen::interface::generate_canonical_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+    use crate::nfa_translation::multitrace_ana::nfa::get_nfa_from_interaction_terms;
+    use super::*;
+
+    #[test]
+    fn nfa_constructions_are_equivalent_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut num_compared = 0;
+        while num_compared < 20 {
+            if let Some(int) = generate_canonical_random_interaction(&gen_ctx,&mut rng,3,2,&probas) {
+                let (explo_nfa,_) = get_nfa_from_interaction_exploration(&gen_ctx,&int,alphabet.clone());
+                let (terms_nfa,is_complete) = get_nfa_from_interaction_terms(&int,&alphabet,10000);
+                assert!(is_complete);
+                assert_eq!(get_shortest_word_in_difference(&explo_nfa,&terms_nfa), None);
+                assert_eq!(get_shortest_word_in_difference(&terms_nfa,&explo_nfa), None);
+                num_compared += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_counterexamples_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        // letters of the emissions of m1 and m2 by l1
+        let emit_m1 = 0;
+        let emit_m2 = 2;
+        let get_nfa = |hif_str : &str| {
+            let int = interaction_from_text(&gen_ctx,hif_str).unwrap();
+            let (nfa,is_complete) = get_nfa_from_interaction_terms(&int,&alphabet,10000);
+            assert!(is_complete);
+            nfa
+        };
+        // the first language strictly includes the second one
+        let repeated = get_nfa("seq(l1 -- m1 ->|,loopS(l1 -- m2 ->|))");
+        let once = get_nfa("seq(l1 -- m1 ->|,l1 -- m2 ->|)");
+        assert_eq!(get_shortest_word_in_difference(&repeated,&once), Some(vec![emit_m1]));
+        assert_eq!(get_shortest_word_in_difference(&once,&repeated), None);
+        // the empty trace is the shortest counterexample
+        let looped = get_nfa("loopS(l1 -- m1 ->|)");
+        let twice = get_nfa("seq(l1 -- m1 ->|,l1 -- m1 ->|)");
+        assert_eq!(get_shortest_word_in_difference(&looped,&twice), Some(vec![]));
+        assert_eq!(get_shortest_word_in_difference(&twice,&looped), None);
+        // incomparable languages
+        let first = get_nfa("l1 -- m1 ->|");
+        let second = get_nfa("l1 -- m2 ->|");
+        assert_eq!(get_shortest_word_in_difference(&first,&second), Some(vec![emit_m1]));
+        assert_eq!(get_shortest_word_in_difference(&second,&first), Some(vec![emit_m2]));
+    }
+
+}
@@ -0,0 +1,157 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::access::AutAccessible;
+use autour_core::traits::translate::AutTranslatable;
+use num_bigint::BigUint;
+
+use crate::nfa_translation::count::growth::{get_infinite_language_growth, get_useful_targets, TraceLanguageGrowth};
+
+
+pub enum LongestAcceptedTrace {
+    // no trace is accepted
+    EmptyLanguage,
+    // finitely many traces are accepted, the longest of which has the given length
+    Finite(usize),
+    // there are accepted traces of any length
+    Unbounded
+}
+
+/**
+ * Counts the words of a given length that are accepted by an NFA.
+ * Because the NFA may accept the same word along several paths, it is first determinized
+ * so that counting paths amounts to counting distinct words.
+ * "counts[k][q]" is the number of words of length k which lead from state q to a final state.
+ **/
+pub struct AcceptedTracesCounter {
+    pub dfa : AutDFA<usize>,
+    // the states that are both accessible and co-accessible
+    pub useful_states : HashSet<usize>,
+    counts : Vec<Vec<BigUint>>
+}
+
+impl AcceptedTracesCounter {
+
+    pub fn new(nfa : &AutNFA<usize>) -> AcceptedTracesCounter {
+        let dfa = nfa.to_dfa();
+        let accessible = dfa.get_all_accessible_states();
+        let useful_states : HashSet<usize> = dfa.get_all_coaccessible_states()
+            .into_iter()
+            .filter(|x| accessible.contains(x))
+            .collect();
+        let init_counts : Vec<BigUint> = (0..dfa.transitions.len()).map(
+            |state| if dfa.finals.contains(&state) && useful_states.contains(&state) {
+                BigUint::from(1_u32)
+            } else {
+                BigUint::from(0_u32)
+            }
+        ).collect();
+        AcceptedTracesCounter{dfa,useful_states,counts:vec![init_counts]}
+    }
+
+    pub fn extend_to_length(&mut self, length : usize) {
+        while self.counts.len() <= length {
+            let previous = self.counts.last().unwrap();
+            let mut next = vec![];
+            for (state,outgoing) in self.dfa.transitions.iter().enumerate() {
+                let mut count = BigUint::from(0_u32);
+                if self.useful_states.contains(&state) {
+                    for target in outgoing.values() {
+                        count += previous.get(*target).unwrap();
+                    }
+                }
+                next.push(count);
+            }
+            self.counts.push(next);
+        }
+    }
+
+    /**
+     * The number of words of length "length" which lead from "state" to a final state.
+     * Requires the counts to have been extended up to "length".
+     **/
+    pub fn get_count_from_state(&self, state : usize, length : usize) -> &BigUint {
+        self.counts.get(length).unwrap().get(state).unwrap()
+    }
+
+    pub fn count_accepted_traces(&mut self, length : usize) -> BigUint {
+        self.extend_to_length(length);
+        self.get_count_from_state(self.dfa.initial, length).clone()
+    }
+
+    /**
+     * Returns the length of the longest accepted word if there are finitely many of them
+     * i.e. if there is no cycle among the useful states of the DFA.
+     **/
+    pub fn get_longest_accepted_length(&self) -> LongestAcceptedTrace {
+        if !self.useful_states.contains(&self.dfa.initial) {
+            return LongestAcceptedTrace::EmptyLanguage;
+        }
+        // depth-first search in which "longest" is set once all the successors of a state are treated
+        let mut longest : Vec<Option<usize>> = vec![None;self.dfa.transitions.len()];
+        let mut on_stack : Vec<bool> = vec![false;self.dfa.transitions.len()];
+        let mut stack : Vec<(usize,Vec<usize>)> = vec![(self.dfa.initial,self.get_useful_targets(self.dfa.initial))];
+        on_stack[self.dfa.initial] = true;
+        while let Some((state,mut targets)) = stack.pop() {
+            match targets.pop() {
+                None => {
+                    on_stack[state] = false;
+                    let mut got = 0;
+                    for target in self.get_useful_targets(state) {
+                        got = got.max(longest[target].unwrap() + 1);
+                    }
+                    longest[state] = Some(got);
+                },
+                Some(target) => {
+                    stack.push((state,targets));
+                    if on_stack[target] {
+                        return LongestAcceptedTrace::Unbounded;
+                    }
+                    if longest[target].is_none() {
+                        on_stack[target] = true;
+                        stack.push((target,self.get_useful_targets(target)));
+                    }
+                }
+            }
+        }
+        LongestAcceptedTrace::Finite(longest[self.dfa.initial].unwrap())
+    }
+
+    fn get_useful_targets(&self, state : usize) -> Vec<usize> {
+        get_useful_targets(&self.dfa,&self.useful_states,state)
+    }
+
+    pub fn get_language_growth(&self) -> TraceLanguageGrowth {
+        match self.get_longest_accepted_length() {
+            LongestAcceptedTrace::EmptyLanguage => {
+                TraceLanguageGrowth::Empty
+            },
+            LongestAcceptedTrace::Finite(longest) => {
+                TraceLanguageGrowth::Finite(longest)
+            },
+            LongestAcceptedTrace::Unbounded => {
+                get_infinite_language_growth(&self.dfa,&self.useful_states)
+            }
+        }
+    }
+
+}
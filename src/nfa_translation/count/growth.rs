@@ -0,0 +1,214 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+use std::fmt;
+
+use autour_core::dfa::dfa::AutDFA;
+
+
+pub enum TraceLanguageGrowth {
+    // no trace is accepted
+    Empty,
+    // finitely many traces are accepted, the longest of which has the given length
+    Finite(usize),
+    // the number of accepted traces of length n grows like n^k
+    Polynomial(usize),
+    // the number of accepted traces of length n grows like rate^n
+    Exponential(f64)
+}
+
+impl fmt::Display for TraceLanguageGrowth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceLanguageGrowth::Empty => {
+                write!(f,"empty language")
+            },
+            TraceLanguageGrowth::Finite(longest) => {
+                write!(f,"finite (longest accepted trace of length {:})", longest)
+            },
+            TraceLanguageGrowth::Polynomial(degree) => {
+                write!(f,"infinite (polynomial growth of degree {:})", degree)
+            },
+            TraceLanguageGrowth::Exponential(rate) => {
+                write!(f,"infinite (exponential growth with estimated rate {:.4})", rate)
+            }
+        }
+    }
+}
+
+const GROWTH_RATE_PRECISION : f64 = 0.000001;
+
+const GROWTH_RATE_MAX_ITERATIONS : u32 = 100000;
+
+// the successors of a state among the useful states
+pub fn get_useful_targets(dfa : &AutDFA<usize>,
+                      useful_states : &HashSet<usize>,
+                      state : usize) -> Vec<usize> {
+    dfa.transitions.get(state).unwrap().values()
+        .filter(|x| useful_states.contains(x))
+        .cloned()
+        .collect()
+}
+
+/**
+ * Returns the strongly connected components of the DFA restricted to its useful states (Kosaraju's algorithm)
+ * in a topological order of the condensation i.e. a component may only lead to those which come after it.
+ **/
+fn get_useful_components(dfa : &AutDFA<usize>,
+                         useful_states : &HashSet<usize>) -> Vec<Vec<usize>> {
+    let states_num = dfa.transitions.len();
+    let mut sorted_states : Vec<usize> = useful_states.iter().cloned().collect();
+    sorted_states.sort();
+    // post-order of a depth-first search
+    let mut visited = vec![false;states_num];
+    let mut post_order = vec![];
+    for root in &sorted_states {
+        if visited[*root] {
+            continue;
+        }
+        visited[*root] = true;
+        let mut stack = vec![(*root,get_useful_targets(dfa,useful_states,*root))];
+        while let Some((state,mut targets)) = stack.pop() {
+            match targets.pop() {
+                None => {
+                    post_order.push(state);
+                },
+                Some(target) => {
+                    stack.push((state,targets));
+                    if !visited[target] {
+                        visited[target] = true;
+                        stack.push((target,get_useful_targets(dfa,useful_states,target)));
+                    }
+                }
+            }
+        }
+    }
+    // depth-first searches on the reversed graph in decreasing post-order
+    let mut predecessors : Vec<Vec<usize>> = vec![vec![];states_num];
+    for state in &sorted_states {
+        for target in get_useful_targets(dfa,useful_states,*state) {
+            predecessors[target].push(*state);
+        }
+    }
+    let mut assigned = vec![false;states_num];
+    let mut components = vec![];
+    for root in post_order.iter().rev() {
+        if assigned[*root] {
+            continue;
+        }
+        assigned[*root] = true;
+        let mut component = vec![];
+        let mut stack = vec![*root];
+        while let Some(state) = stack.pop() {
+            component.push(state);
+            for pred in &predecessors[state] {
+                if !assigned[*pred] {
+                    assigned[*pred] = true;
+                    stack.push(*pred);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/**
+ * The spectral radius of the adjacency matrix A of a strongly connected component,
+ * computed by power iteration on A+I, whose spectral radius is that of A plus one.
+ * A+I is aperiodic so that the iteration does not oscillate on periodic languages (e.g. with only even lengths).
+ **/
+fn get_component_spectral_radius(dfa : &AutDFA<usize>,
+                                 component : &HashSet<usize>) -> f64 {
+    let states : Vec<usize> = component.iter().cloned().collect();
+    let mut vector : Vec<f64> = vec![1.0;dfa.transitions.len()];
+    let mut rate = 0.0;
+    for _ in 0..GROWTH_RATE_MAX_ITERATIONS {
+        let mut next = vector.clone();
+        for state in &states {
+            for target in dfa.transitions.get(*state).unwrap().values() {
+                if component.contains(target) {
+                    next[*state] += vector[*target];
+                }
+            }
+        }
+        let norm : f64 = states.iter().map(|x| next[*x]).sum();
+        let previous_norm : f64 = states.iter().map(|x| vector[*x]).sum();
+        let next_rate = norm / previous_norm - 1.0;
+        for state in &states {
+            next[*state] = next[*state] / norm;
+        }
+        vector = next;
+        if (next_rate - rate).abs() < GROWTH_RATE_PRECISION {
+            return next_rate;
+        }
+        rate = next_rate;
+    }
+    rate
+}
+
+/**
+ * Characterizes the growth of the number of accepted traces of an infinite language
+ * from the strongly connected components of the DFA restricted to its useful states :
+ * - if each component contains at most one cycle, the growth is polynomial,
+ *   its degree being the maximum number of cyclic components along a path minus one
+ * - otherwise the growth is exponential, its rate being the maximal spectral radius of the components
+ **/
+pub fn get_infinite_language_growth(dfa : &AutDFA<usize>,
+                                    useful_states : &HashSet<usize>) -> TraceLanguageGrowth {
+    let components = get_useful_components(dfa,useful_states);
+    let mut component_of = vec![0;dfa.transitions.len()];
+    for (comp_id,component) in components.iter().enumerate() {
+        for state in component {
+            component_of[*state] = comp_id;
+        }
+    }
+    let mut max_rate : f64 = 1.0;
+    let mut is_polynomial = true;
+    let mut is_cyclic = vec![false;components.len()];
+    for (comp_id,component) in components.iter().enumerate() {
+        let internal_edges : usize = component.iter()
+            .map(|x| get_useful_targets(dfa,useful_states,*x).into_iter().filter(|y| component_of[*y] == comp_id).count())
+            .sum();
+        is_cyclic[comp_id] = internal_edges > 0;
+        if internal_edges > component.len() {
+            is_polynomial = false;
+            let as_set : HashSet<usize> = component.iter().cloned().collect();
+            max_rate = max_rate.max(get_component_spectral_radius(dfa,&as_set));
+        }
+    }
+    if !is_polynomial {
+        return TraceLanguageGrowth::Exponential(max_rate);
+    }
+    // components are in topological order so the longest paths can be computed backwards
+    let mut cyclic_along_path = vec![0;components.len()];
+    for comp_id in (0..components.len()).rev() {
+        let mut best = 0;
+        for state in components.get(comp_id).unwrap() {
+            for target in get_useful_targets(dfa,useful_states,*state) {
+                if component_of[target] != comp_id {
+                    best = best.max(cyclic_along_path[component_of[target]]);
+                }
+            }
+        }
+        cyclic_along_path[comp_id] = best + if is_cyclic[comp_id] {1} else {0};
+    }
+    let max_cyclic = cyclic_along_path.into_iter().max().unwrap_or(1);
+    TraceLanguageGrowth::Polynomial(max_cyclic.max(1) - 1)
+}
@@ -0,0 +1,201 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use num_bigint::BigUint;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::nfa_translation::count::counter::AcceptedTracesCounter;
+
+
+// a multi-trace where each action is represented by its letter in the alphabet
+type LetterMultiTrace = Vec<Vec<usize>>;
+
+pub fn get_letters_coloc_ids(alphabet : &Vec<BTreeSet<TraceAction>>,
+                             co_localizations : &CoLocalizations) -> Vec<usize> {
+    alphabet.iter().map(
+        |actions| {
+            let lf_id = actions.iter().next().unwrap().lf_id;
+            co_localizations.get_lf_coloc_id(lf_id).unwrap()
+        }
+    ).collect()
+}
+
+/**
+ * Counts the distinct multi-traces of each length 0..N that are the projection of an accepted global trace.
+ * Because the set of accepted traces is in general not closed under the permutation of actions occurring
+ * on distinct co-localizations, this cannot be done on the DFA alone.
+ * Instead, multi-traces are enumerated length by length, each one being associated with the set of DFA states
+ * reachable via the global traces which project onto it :
+ * any such global trace ends with the last action of one of the components, hence
+ * states(mu) = U_{c} delta( states(mu without the last action of c), last action of c )
+ * Because this enumeration is exponential, it is stopped if a length has more than "max_multi_traces" prefixes.
+ * Returns the counts and whether or not all lengths up to "max_length" could be treated.
+ **/
+pub fn count_accepted_multi_traces(counter : &AcceptedTracesCounter,
+                                   letters_coloc_ids : &Vec<usize>,
+                                   num_colocs : usize,
+                                   max_length : usize,
+                                   max_multi_traces : usize) -> (Vec<BigUint>,bool) {
+    let dfa = &counter.dfa;
+    let mut counts = vec![];
+    let mut level : HashMap<LetterMultiTrace,HashSet<usize>> = HashMap::new();
+    if counter.useful_states.contains(&dfa.initial) {
+        level.insert(vec![vec![];num_colocs],hashset!{dfa.initial});
+    }
+    for length in 0..=max_length {
+        let accepted = level.values()
+            .filter(|states| states.iter().any(|x| dfa.finals.contains(x)))
+            .count();
+        counts.push(BigUint::from(accepted));
+        if length == max_length {
+            break;
+        }
+        // the candidates are the multi-traces obtained by adding an action to a prefix
+        let mut candidates : HashSet<LetterMultiTrace> = HashSet::new();
+        for (multi_trace,states) in &level {
+            for state in states {
+                for letter in dfa.transitions.get(*state).unwrap().keys() {
+                    let mut candidate = multi_trace.clone();
+                    candidate[*letters_coloc_ids.get(*letter).unwrap()].push(*letter);
+                    candidates.insert(candidate);
+                }
+            }
+        }
+        let mut next_level : HashMap<LetterMultiTrace,HashSet<usize>> = HashMap::new();
+        for candidate in candidates {
+            let mut states = HashSet::new();
+            for coloc_id in 0..num_colocs {
+                let mut previous = candidate.clone();
+                if let Some(letter) = previous[coloc_id].pop() {
+                    if let Some(previous_states) = level.get(&previous) {
+                        for previous_state in previous_states {
+                            if let Some(target) = dfa.transitions.get(*previous_state).unwrap().get(&letter) {
+                                if counter.useful_states.contains(target) {
+                                    states.insert(*target);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !states.is_empty() {
+                next_level.insert(candidate,states);
+            }
+        }
+        if next_level.len() > max_multi_traces {
+            return (counts,false);
+        }
+        level = next_level;
+    }
+    (counts,true)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use autour_core::traits::access::AutAccessible;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::experiments::interaction_random_gen::interface::generate_canonical_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::multitrace_ana::nfa::get_nfa_from_interaction_terms;
+    use super::*;
+
+    // enumerates all the words of a given length accepted by the DFA and returns their projections
+    fn enumerate_projections(counter : &AcceptedTracesCounter,
+                             letters_coloc_ids : &Vec<usize>,
+                             num_colocs : usize,
+                             length : usize) -> (usize,HashSet<LetterMultiTrace>) {
+        let mut num_words = 0;
+        let mut projections = HashSet::new();
+        let mut stack : Vec<(usize,Vec<usize>)> = vec![(counter.dfa.initial,vec![])];
+        while let Some((state,word)) = stack.pop() {
+            if word.len() == length {
+                if counter.dfa.finals.contains(&state) {
+                    num_words += 1;
+                    let mut projection = vec![vec![];num_colocs];
+                    for letter in &word {
+                        projection[letters_coloc_ids[*letter]].push(*letter);
+                    }
+                    projections.insert(projection);
+                }
+                continue;
+            }
+            for (letter,target) in counter.dfa.transitions.get(state).unwrap() {
+                let mut next_word = word.clone();
+                next_word.push(*letter);
+                stack.push((*target,next_word));
+            }
+        }
+        (num_words,projections)
+    }
+
+    #[test]
+    fn count_traces_cross_check_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let all_colocs = vec![CoLocalizations::get_trivial_partition(3),
+                              CoLocalizations::get_discrete_partition(3),
+                              CoLocalizations::new(vec![btreeset!{0,1},btreeset!{2}])];
+        let mut rng = StdRng::seed_from_u64(0);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        for _ in 0..20 {
+            let int = match generate_canonical_random_interaction(&gen_ctx,&mut rng,3,1,&probas) {
+                None => {
+                    continue;
+                },
+                Some(got_int) => {
+                    got_int
+                }
+            };
+            let (nfa,is_complete) = get_nfa_from_interaction_terms(&int,&alphabet,1000);
+            if !is_complete {
+                continue;
+            }
+            let mut counter = AcceptedTracesCounter::new(&nfa.trim());
+            for co_localizations in &all_colocs {
+                let letters_coloc_ids = get_letters_coloc_ids(&alphabet,co_localizations);
+                let num_colocs = co_localizations.num_colocs();
+                let (multi_counts,is_complete) = count_accepted_multi_traces(&counter,&letters_coloc_ids,num_colocs,5,100000);
+                assert!(is_complete);
+                for length in 0..=5 {
+                    let (num_words,projections) = enumerate_projections(&counter,&letters_coloc_ids,num_colocs,length);
+                    assert_eq!(counter.count_accepted_traces(length),BigUint::from(num_words));
+                    assert_eq!(multi_counts[length],BigUint::from(projections.len()));
+                    if co_localizations.num_colocs() == 1 {
+                        assert_eq!(multi_counts[length],BigUint::from(num_words));
+                    }
+                }
+            }
+        }
+    }
+
+}
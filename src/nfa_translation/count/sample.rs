@@ -0,0 +1,99 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::rngs::StdRng;
+
+use crate::nfa_translation::count::counter::AcceptedTracesCounter;
+
+
+/**
+ * Draws a word of length "length" uniformly at random among those accepted by the DFA of the counter.
+ * A rank is drawn below the number of accepted words and the corresponding word is then rebuilt
+ * letter by letter, letters being ordered, from the number of accepted completions after each letter.
+ * Returns None if no word of that length is accepted.
+ **/
+pub fn sample_accepted_word(counter : &mut AcceptedTracesCounter,
+                            length : usize,
+                            rng : &mut StdRng) -> Option<Vec<usize>> {
+    let total = counter.count_accepted_traces(length);
+    if total == BigUint::from(0_u32) {
+        return None;
+    }
+    let mut rank = rng.gen_biguint_below(&total);
+    let mut state = counter.dfa.initial;
+    let mut word = vec![];
+    for remaining in (0..length).rev() {
+        let mut outgoing : Vec<(usize,usize)> = counter.dfa.transitions.get(state).unwrap()
+            .iter()
+            .map(|(letter,target)| (*letter,*target))
+            .collect();
+        outgoing.sort();
+        for (letter,target) in outgoing {
+            let completions = counter.get_count_from_state(target,remaining);
+            if rank < *completions {
+                word.push(letter);
+                state = target;
+                break;
+            }
+            rank -= completions;
+        }
+    }
+    Some(word)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use autour_core::nfa::nfa::AutNFA;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn uniform_sampling_test() {
+        // accepts the words of (0 + 1 (0 + 1 + 2))* i.e. 1 word of length 0, 1 of length 1, 4 of length 2, 7 of length 3
+        let nfa = AutNFA::from_raw(hashset!{0,1,2},
+                                   hashset!{0},
+                                   hashset!{0},
+                                   vec![hashmap!{0 => hashset!{0}, 1 => hashset!{1}},
+                                        hashmap!{0 => hashset!{0}, 1 => hashset!{0}, 2 => hashset!{0}}]).unwrap();
+        let mut counter = AcceptedTracesCounter::new(&nfa);
+        assert_eq!(counter.count_accepted_traces(3),BigUint::from(7_u32));
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut occurrences : HashMap<Vec<usize>,u32> = HashMap::new();
+        for _ in 0..7000 {
+            let word = sample_accepted_word(&mut counter,3,&mut rng).unwrap();
+            assert_eq!(word.len(),3);
+            *occurrences.entry(word).or_insert(0) += 1;
+        }
+        assert_eq!(occurrences.len(),7);
+        for count in occurrences.values() {
+            assert!(*count > 800 && *count < 1200);
+        }
+        // the same seed gives the same word
+        let first = sample_accepted_word(&mut counter,3,&mut StdRng::seed_from_u64(1));
+        let second = sample_accepted_word(&mut counter,3,&mut StdRng::seed_from_u64(1));
+        assert_eq!(first,second);
+    }
+
+}
@@ -20,4 +20,11 @@ pub mod compositional;
 pub mod experiments;
 pub mod canonize;
 pub mod experiments2;
+pub mod multitrace_ana;
+pub mod count;
+pub mod compare;
+pub mod regex;
+pub mod realizability;
+pub mod cfsm;
+pub mod monitor;
 
@@ -0,0 +1,126 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{trace_as_multi_trace, Trace};
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::nfa_translation::monitor::table::{MonitorTable, MonitorVerdict};
+use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+/**
+ * Verdict of the analysis of a global trace against the interaction,
+ * which is used as a reference for the verdicts of the monitor.
+ * A "prefix" analysis which looks for a "Pass" verdict distinguishes
+ * accepted traces ("Pass") from strict prefixes of accepted traces ("WeakPass").
+ **/
+pub fn get_reference_verdict(gen_ctx : &GeneralContext,
+                             int : &Interaction,
+                             alphabet : &Vec<BTreeSet<TraceAction>>,
+                             word : &[usize]) -> MonitorVerdict {
+    let trace : Trace = word.iter().map(|letter| alphabet.get(*letter).unwrap().clone()).collect();
+    let co_localizations = CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num());
+    let mut ana_opts = HibouAnalyzeOptions::default();
+    ana_opts.ana_param.ana_kind = AnalysisKind::Prefix;
+    ana_opts.goal = Some(AnalysisGlobalVerdict::Pass);
+    let (_,verdict) = analyze_multi_trace_without_loggers(gen_ctx,
+                                                          int,
+                                                          &co_localizations,
+                                                          &trace_as_multi_trace(&trace,&co_localizations),
+                                                          ana_opts);
+    match verdict {
+        AnalysisGlobalVerdict::Pass => {
+            MonitorVerdict::Accepted
+        },
+        AnalysisGlobalVerdict::WeakPass => {
+            MonitorVerdict::Pending
+        },
+        _ => {
+            MonitorVerdict::Rejected
+        }
+    }
+}
+
+/**
+ * Generates "num_traces" distinct traces of at most "max_length" actions from a generator seeded with "seed".
+ * Each one is obtained by a random walk in the monitor (hence it is accepted or pending)
+ * and then, one time out of two, mutated by replacing, inserting or removing a random action.
+ * Returns them with their reference verdicts.
+ **/
+pub fn get_checked_traces(gen_ctx : &GeneralContext,
+                          int : &Interaction,
+                          alphabet : &Vec<BTreeSet<TraceAction>>,
+                          table : &MonitorTable,
+                          num_traces : usize,
+                          max_length : usize,
+                          seed : u64) -> Vec<(Vec<usize>,MonitorVerdict)> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut words : Vec<Vec<usize>> = vec![];
+    // bounded number of attempts given that small languages have few distinct traces
+    for _ in 0..(num_traces*10) {
+        if words.len() >= num_traces {
+            break;
+        }
+        let mut word = vec![];
+        let mut state = if table.num_states > 0 {Some(0)} else {None};
+        while let Some(current) = state {
+            let outgoing = table.transitions.get(current).unwrap();
+            if word.len() >= max_length || outgoing.is_empty() || (table.finals.contains(&current) && rng.gen_bool(0.3)) {
+                break;
+            }
+            let (letter,target) = outgoing[rng.gen_range(0..outgoing.len())];
+            word.push(letter);
+            state = Some(target);
+        }
+        if !alphabet.is_empty() && rng.gen_bool(0.5) {
+            let random_letter = rng.gen_range(0..alphabet.len());
+            match rng.gen_range(0..3) {
+                0 if !word.is_empty() => {
+                    let position = rng.gen_range(0..word.len());
+                    word[position] = random_letter;
+                },
+                1 if !word.is_empty() => {
+                    word.remove(rng.gen_range(0..word.len()));
+                },
+                _ => {
+                    let position = rng.gen_range(0..(word.len()+1));
+                    word.insert(position,random_letter);
+                }
+            }
+        }
+        if !words.contains(&word) {
+            words.push(word);
+        }
+    }
+    words.into_iter()
+        .map(|word| {
+            let verdict = get_reference_verdict(gen_ctx,int,alphabet,&word);
+            (word,verdict)
+        })
+        .collect()
+}
@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{HashMap, VecDeque};
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::access::AutAccessible;
+use autour_core::traits::transform::AutTransformable;
+use autour_core::traits::translate::AutTranslatable;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MonitorVerdict {
+    // the trace is accepted
+    Accepted,
+    // the trace is not accepted but is the prefix of an accepted trace
+    Pending,
+    // the trace is not the prefix of any accepted trace
+    Rejected
+}
+
+/**
+ * Transition table of a runtime monitor i.e. of the minimal DFA of an interaction
+ * in which all states can reach an accepting state.
+ * States are numbered in breadth-first order from the initial state 0.
+ * Any missing transition leads to an implicit rejecting sink,
+ * which is also the initial state if "num_states" is zero (i.e. if no trace is accepted).
+ **/
+pub struct MonitorTable {
+    pub num_states : usize,
+    pub finals : Vec<usize>,
+    // for each state, its outgoing transitions as (letter,target) sorted by letter
+    pub transitions : Vec<Vec<(usize,usize)>>
+}
+
+impl MonitorTable {
+
+    pub fn from_nfa(nfa : &AutNFA<usize>) -> MonitorTable {
+        let dfa = nfa.clone().trim().to_dfa().minimize();
+        let coaccessible = dfa.get_all_coaccessible_states();
+        let mut states_ids : HashMap<usize,usize> = HashMap::new();
+        let mut queue : VecDeque<usize> = VecDeque::new();
+        if coaccessible.contains(&dfa.initial) {
+            states_ids.insert(dfa.initial,0);
+            queue.push_back(dfa.initial);
+        }
+        let mut finals = vec![];
+        let mut transitions = vec![];
+        while let Some(dfa_state) = queue.pop_front() {
+            if dfa.finals.contains(&dfa_state) {
+                finals.push(*states_ids.get(&dfa_state).unwrap());
+            }
+            let mut outgoing : Vec<(usize,usize)> = dfa.transitions.get(dfa_state).unwrap()
+                .iter()
+                .filter(|(_,target)| coaccessible.contains(*target))
+                .map(|(letter,target)| (*letter,*target))
+                .collect();
+            outgoing.sort();
+            let mut state_transitions = vec![];
+            for (letter,dfa_target) in outgoing {
+                let target = match states_ids.get(&dfa_target) {
+                    Some(got_id) => {
+                        *got_id
+                    },
+                    None => {
+                        let new_id = states_ids.len();
+                        states_ids.insert(dfa_target,new_id);
+                        queue.push_back(dfa_target);
+                        new_id
+                    }
+                };
+                state_transitions.push((letter,target));
+            }
+            // states are processed in the order of their creation
+            transitions.push(state_transitions);
+        }
+        MonitorTable{num_states:states_ids.len(),finals,transitions}
+    }
+
+    // state reached after reading "word", None standing for the rejecting sink
+    pub fn run(&self, word : &[usize]) -> Option<usize> {
+        let mut state = if self.num_states > 0 {Some(0)} else {None};
+        for letter in word {
+            state = match state {
+                None => {
+                    None
+                },
+                Some(current) => {
+                    self.transitions.get(current).unwrap()
+                        .iter()
+                        .find(|(x,_)| x == letter)
+                        .map(|(_,target)| *target)
+                }
+            };
+        }
+        state
+    }
+
+    pub fn get_verdict(&self, word : &[usize]) -> MonitorVerdict {
+        match self.run(word) {
+            None => {
+                MonitorVerdict::Rejected
+            },
+            Some(state) => {
+                if self.finals.contains(&state) {
+                    MonitorVerdict::Accepted
+                } else {
+                    MonitorVerdict::Pending
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,316 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::access::AutAccessible;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::nfa_translation::multitrace_ana::kind::NfaMultiTraceAnalysisKind;
+use crate::nfa_translation::multitrace_ana::projection::{is_word_compatible_with_projection, project_nfa_on_letters};
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+pub struct NfaMultiTraceAnalysisResult {
+    pub verdict : AnalysisGlobalVerdict,
+    // the first component which is found to be incompatible with the projection of the NFA on its lifelines
+    pub rejecting_component : Option<usize>,
+    // the number of (state, consumed prefixes) configurations explored while interleaving the components
+    pub configurations_num : usize
+}
+
+impl NfaMultiTraceAnalysisResult {
+    pub fn new(verdict: AnalysisGlobalVerdict,
+               rejecting_component: Option<usize>,
+               configurations_num: usize) -> NfaMultiTraceAnalysisResult {
+        NfaMultiTraceAnalysisResult { verdict, rejecting_component, configurations_num }
+    }
+}
+
+fn get_letters_colocs(alphabet : &Vec<BTreeSet<TraceAction>>,
+                      co_localizations : &CoLocalizations) -> Vec<Option<usize>> {
+    alphabet.iter().map(
+        |actions| {
+            let lf_ids : BTreeSet<usize> = actions.iter().map(|x| x.lf_id).collect();
+            let mut coloc_ids : HashSet<Option<usize>> = lf_ids.iter().map(|x| co_localizations.get_lf_coloc_id(*x)).collect();
+            if coloc_ids.len() == 1 {
+                coloc_ids.drain().next().unwrap()
+            } else {
+                None
+            }
+        }
+    ).collect()
+}
+
+/**
+ * Analyzes a multi-trace against the NFA "nfa" whose letters are the indices of the actions in "alphabet".
+ * Each component of the multi-trace is first checked against the projection of the NFA on its lifelines.
+ * If all the components pass this test, their interleavings are searched for in the NFA
+ * by exploring configurations made of a state of the NFA and of the number of actions consumed in each component.
+ **/
+pub fn analyze_multi_trace_with_nfa(nfa : &AutNFA<usize>,
+                                    alphabet : &Vec<BTreeSet<TraceAction>>,
+                                    co_localizations : &CoLocalizations,
+                                    multi_trace : &MultiTrace,
+                                    kind : &NfaMultiTraceAnalysisKind) -> NfaMultiTraceAnalysisResult {
+    let nfa = nfa.clone().trim();
+    let letters_colocs = get_letters_colocs(alphabet,co_localizations);
+    let mut components_letters : Vec<HashSet<usize>> = vec![hashset!{};co_localizations.num_colocs()];
+    for (letter,coloc) in letters_colocs.iter().enumerate() {
+        if let Some(coloc_id) = coloc {
+            components_letters.get_mut(*coloc_id).unwrap().insert(letter);
+        }
+    }
+    // ***
+    let mut words : Vec<Vec<usize>> = vec![];
+    for (coloc_id,trace) in multi_trace.iter().enumerate() {
+        let mut word = vec![];
+        for actions in trace {
+            match alphabet.iter().position(|x| x == actions) {
+                None => {
+                    return NfaMultiTraceAnalysisResult::new(AnalysisGlobalVerdict::Fail,Some(coloc_id),0);
+                },
+                Some(letter) => {
+                    word.push(letter);
+                }
+            }
+        }
+        words.push(word);
+    }
+    // ***
+    for (coloc_id,word) in words.iter().enumerate() {
+        let kept_letters = components_letters.get(coloc_id).unwrap();
+        let mut hidden_letters : HashSet<usize> = hashset!{};
+        for (other_id,other_letters) in components_letters.iter().enumerate() {
+            if other_id != coloc_id {
+                hidden_letters.extend(other_letters.iter().cloned());
+            }
+        }
+        let projection = project_nfa_on_letters(&nfa,kept_letters,&hidden_letters);
+        if !is_word_compatible_with_projection(&projection,word,kind) {
+            return NfaMultiTraceAnalysisResult::new(AnalysisGlobalVerdict::Fail,Some(coloc_id),0);
+        }
+    }
+    // ***
+    let lengths : Vec<usize> = words.iter().map(|x| x.len()).collect();
+    let mut verdict = AnalysisGlobalVerdict::Fail;
+    // a configuration also records whether or not actions have been skipped to reach it
+    let mut visited : HashSet<(usize,Vec<usize>,bool)> = hashset!{};
+    let mut queue : VecDeque<(usize,Vec<usize>,bool)> = VecDeque::new();
+    for init in &nfa.initials {
+        let config = (*init,vec![0;words.len()],false);
+        if visited.insert(config.clone()) {
+            queue.push_back(config);
+        }
+    }
+    while let Some((state,consumed,skipped)) = queue.pop_front() {
+        if consumed == lengths {
+            if !skipped && nfa.finals.contains(&state) {
+                return NfaMultiTraceAnalysisResult::new(AnalysisGlobalVerdict::Pass,None,visited.len());
+            }
+            if *kind != NfaMultiTraceAnalysisKind::Accept {
+                verdict = AnalysisGlobalVerdict::WeakPass;
+            }
+        }
+        for (letter,targets) in nfa.transitions.get(state).unwrap() {
+            if let Some(coloc_id) = letters_colocs.get(*letter).unwrap() {
+                let coloc_consumed = *consumed.get(*coloc_id).unwrap();
+                let coloc_length = *lengths.get(*coloc_id).unwrap();
+                let mut next_configs = vec![];
+                if coloc_consumed < coloc_length && words.get(*coloc_id).unwrap().get(coloc_consumed).unwrap() == letter {
+                    let mut new_consumed = consumed.clone();
+                    new_consumed[*coloc_id] += 1;
+                    for target in targets {
+                        next_configs.push((*target,new_consumed.clone(),skipped));
+                    }
+                }
+                if (coloc_consumed == 0 && kind.may_skip_before_component_start())
+                    || (coloc_consumed == coloc_length && kind.may_skip_after_component_end()) {
+                    for target in targets {
+                        next_configs.push((*target,consumed.clone(),true));
+                    }
+                }
+                for config in next_configs {
+                    if visited.insert(config.clone()) {
+                        queue.push_back(config);
+                    }
+                }
+            }
+        }
+    }
+    NfaMultiTraceAnalysisResult::new(verdict,None,visited.len())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+    use crate::core::execution::trace::multitrace::multi_trace_length;
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::experiments::interaction_random_gen::interface::generate_canonical_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::multitrace_ana::nfa::get_nfa_from_interaction_terms;
+    use crate::process::ana::conf::AnalysisConfig;
+    use crate::process::ana::context::AnalysisContext;
+    use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+    use crate::process::ana::node::node::AnalysisNodeKind;
+    use crate::process::ana::param::anakind::AnalysisKind;
+    use crate::process::ana::priorities::AnalysisPriorities;
+    use crate::process::ana::step::AnalysisStepKind;
+    use super::*;
+
+    fn analyze_with_interaction_semantics(gen_ctx : &GeneralContext,
+                                          int : &Interaction,
+                                          co_localizations : &CoLocalizations,
+                                          multi_trace : &MultiTrace,
+                                          ana_kind : AnalysisKind) -> AnalysisGlobalVerdict {
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.ana_param.ana_kind = ana_kind;
+        // memoization is not used so that the reference verdict does not depend on the order in which nodes are explored
+        let mu_length = multi_trace_length(multi_trace);
+        let ana_ctx = AnalysisContext::new(gen_ctx.clone(),co_localizations.clone(),multi_trace.clone(),mu_length);
+        let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
+        let init_flags = MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),0,0);
+        let mut manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
+                                                                                             ana_opts.ana_param,
+                                                                                             delegate,
+                                                                                             ana_opts.filters,
+                                                                                             vec![],
+                                                                                             Some(AnalysisGlobalVerdict::Pass),
+                                                                                             false);
+        let (_,verdict) = manager.start_process(AnalysisNodeKind::new(int.clone(),init_flags,0));
+        verdict
+    }
+
+    // the length of the words is bounded so that the reference analysis remains fast without memoization
+    fn generate_random_word(nfa : &AutNFA<usize>, rng : &mut StdRng, max_length : usize) -> Vec<usize> {
+        let initials : Vec<usize> = nfa.initials.iter().cloned().collect();
+        let mut state = initials[rng.gen_range(0..initials.len())];
+        let mut word = vec![];
+        loop {
+            if word.len() >= max_length || (nfa.finals.contains(&state) && rng.gen_bool(0.3)) {
+                return word;
+            }
+            let mut outgoing : Vec<(usize,usize)> = vec![];
+            for (letter,targets) in nfa.transitions.get(state).unwrap() {
+                for target in targets {
+                    outgoing.push((*letter,*target));
+                }
+            }
+            if outgoing.is_empty() {
+                return word;
+            }
+            outgoing.sort();
+            let (letter,target) = outgoing[rng.gen_range(0..outgoing.len())];
+            word.push(letter);
+            state = target;
+        }
+    }
+
+    fn project_word(word : &[usize],
+                    alphabet : &Vec<BTreeSet<TraceAction>>,
+                    co_localizations : &CoLocalizations) -> MultiTrace {
+        let mut multi_trace : MultiTrace = vec![vec![];co_localizations.num_colocs()];
+        for letter in word {
+            let actions = alphabet.get(*letter).unwrap();
+            let lf_id = actions.iter().next().unwrap().lf_id;
+            let coloc_id = co_localizations.get_lf_coloc_id(lf_id).unwrap();
+            multi_trace[coloc_id].push(actions.clone());
+        }
+        multi_trace
+    }
+
+    #[test]
+    fn nfa_multitrace_ana_cross_check_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let all_colocs = vec![CoLocalizations::get_trivial_partition(3),
+                              CoLocalizations::get_discrete_partition(3),
+                              CoLocalizations::new(vec![btreeset!{0,1},btreeset!{2}])];
+        let mut rng = StdRng::seed_from_u64(0);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        for _ in 0..20 {
+            let int = match generate_canonical_random_interaction(&gen_ctx,&mut rng,3,1,&probas) {
+                None => {
+                    continue;
+                },
+                Some(got_int) => {
+                    got_int
+                }
+            };
+            let (nfa,is_complete) = get_nfa_from_interaction_terms(&int,&alphabet,1000);
+            let nfa = nfa.trim();
+            if !is_complete || nfa.initials.is_empty() {
+                continue;
+            }
+            for co_localizations in &all_colocs {
+                for _ in 0..5 {
+                    let word = generate_random_word(&nfa,&mut rng,8);
+                    let mut multi_traces = vec![project_word(&word,&alphabet,co_localizations)];
+                    if !word.is_empty() {
+                        // a prefix of the accepted word
+                        let cut = rng.gen_range(0..word.len());
+                        multi_traces.push(project_word(&word[..cut],&alphabet,co_localizations));
+                        // the accepted word in which an action is removed
+                        let mut mutated = word.clone();
+                        mutated.remove(rng.gen_range(0..word.len()));
+                        multi_traces.push(project_word(&mutated,&alphabet,co_localizations));
+                    }
+                    for multi_trace in &multi_traces {
+                        for ana_kind in [AnalysisKind::Accept,AnalysisKind::Prefix] {
+                            let nfa_kind = NfaMultiTraceAnalysisKind::from_analysis_kind(&ana_kind);
+                            let got_nfa = analyze_multi_trace_with_nfa(&nfa,&alphabet,co_localizations,multi_trace,&nfa_kind).verdict;
+                            let got_int = analyze_with_interaction_semantics(&gen_ctx,&int,co_localizations,multi_trace,ana_kind);
+                            match got_int {
+                                AnalysisGlobalVerdict::Inconc(_) => {
+                                    assert_eq!(got_nfa,AnalysisGlobalVerdict::Fail);
+                                },
+                                _ => {
+                                    assert_eq!(got_nfa,got_int);
+                                }
+                            }
+                        }
+                        if co_localizations.are_colocalizations_singletons() {
+                            // lifeline removal may report an exactly accepted multi-trace as a multi-prefix
+                            let nfa_kind = NfaMultiTraceAnalysisKind::from_analysis_kind(&AnalysisKind::Eliminate);
+                            let got_nfa = analyze_multi_trace_with_nfa(&nfa,&alphabet,co_localizations,multi_trace,&nfa_kind).verdict;
+                            let got_int = analyze_with_interaction_semantics(&gen_ctx,&int,co_localizations,multi_trace,AnalysisKind::Eliminate);
+                            assert_eq!(got_nfa >= AnalysisGlobalVerdict::WeakPass, got_int >= AnalysisGlobalVerdict::WeakPass);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+}
@@ -0,0 +1,107 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+use crate::process::ana::param::anakind::AnalysisKind;
+
+
+/**
+ * Which multi-traces are considered to be (weakly) accepted:
+ * - Accept : only the projections of accepted global traces
+ * - Prefix : also the projections of prefixes of accepted global traces
+ * - MultiPrefix : also the multi-traces in which each component is a prefix of the projection of an accepted global trace
+ * - Slice : also the multi-traces in which each component is a factor of the projection of an accepted global trace
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub enum NfaMultiTraceAnalysisKind {
+    Accept,
+    Prefix,
+    MultiPrefix,
+    Slice
+}
+
+impl fmt::Display for NfaMultiTraceAnalysisKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaMultiTraceAnalysisKind::Accept => {
+                write!(f,"accept")
+            },
+            NfaMultiTraceAnalysisKind::Prefix => {
+                write!(f,"prefix")
+            },
+            NfaMultiTraceAnalysisKind::MultiPrefix => {
+                write!(f,"multi-prefix")
+            },
+            NfaMultiTraceAnalysisKind::Slice => {
+                write!(f,"slice")
+            }
+        }
+    }
+}
+
+impl NfaMultiTraceAnalysisKind {
+
+    pub fn from_analysis_kind(ana_kind : &AnalysisKind) -> NfaMultiTraceAnalysisKind {
+        match ana_kind {
+            AnalysisKind::Accept => {
+                NfaMultiTraceAnalysisKind::Accept
+            },
+            AnalysisKind::Prefix => {
+                NfaMultiTraceAnalysisKind::Prefix
+            },
+            AnalysisKind::Eliminate => {
+                NfaMultiTraceAnalysisKind::MultiPrefix
+            },
+            AnalysisKind::Simulate(sim_config) => {
+                if sim_config.sim_before {
+                    NfaMultiTraceAnalysisKind::Slice
+                } else {
+                    NfaMultiTraceAnalysisKind::MultiPrefix
+                }
+            }
+        }
+    }
+
+    // whether or not actions of a component which has not yet been observed can be ignored
+    pub fn may_skip_before_component_start(&self) -> bool {
+        match self {
+            NfaMultiTraceAnalysisKind::Slice => {
+                true
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+    // whether or not actions of a component which has been entirely observed can be ignored
+    pub fn may_skip_after_component_end(&self) -> bool {
+        match self {
+            NfaMultiTraceAnalysisKind::MultiPrefix => {
+                true
+            },
+            NfaMultiTraceAnalysisKind::Slice => {
+                true
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+}
@@ -0,0 +1,99 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+pub const DEFAULT_NFA_MAX_STATES_NUM : usize = 100000;
+
+/**
+ * Builds a NFA whose states are the distinct interaction terms that are reachable from "int" via the execution of actions.
+ * Letters are the indices of the executed actions in "alphabet".
+ * Contrary to the NFA obtained from a bounded exploration of the interaction,
+ * loops are not unrolled a bounded number of times so that the resulting NFA is exact
+ * as long as all reachable terms have been explored.
+ * If the number of distinct terms exceeds "max_states_num" (which may happen if the language is not regular)
+ * the remaining terms are not explored and the returned boolean is false.
+ **/
+pub fn get_nfa_from_interaction_terms(int : &Interaction,
+                                      alphabet : &Vec<BTreeSet<TraceAction>>,
+                                      max_states_num : usize) -> (AutNFA<usize>,bool) {
+    let (nfa,_,is_complete) = get_nfa_and_states_from_interaction_terms(int,alphabet,max_states_num);
+    (nfa,is_complete)
+}
+
+// same as above but also returns the interaction term of each state
+pub fn get_nfa_and_states_from_interaction_terms(int : &Interaction,
+                                                 alphabet : &Vec<BTreeSet<TraceAction>>,
+                                                 max_states_num : usize) -> (AutNFA<usize>,Vec<Interaction>,bool) {
+    let mut is_complete = true;
+    let mut states_ids : HashMap<Interaction,usize> = hashmap!{int.clone() => 0};
+    let mut states : Vec<Interaction> = vec![int.clone()];
+    let mut finals : HashSet<usize> = hashset!{};
+    let mut transitions : Vec<HashMap<usize,HashSet<usize>>> = vec![];
+    let mut queue : VecDeque<usize> = VecDeque::new();
+    queue.push_back(0);
+    while let Some(state_id) = queue.pop_front() {
+        let term = states.get(state_id).unwrap().clone();
+        if term.express_empty() {
+            finals.insert(state_id);
+        }
+        let mut outgoing : HashMap<usize,HashSet<usize>> = hashmap!{};
+        for frt_elt in global_frontier(&term,true) {
+            let letter = match alphabet.iter().position(|x| *x == frt_elt.target_actions) {
+                None => {
+                    continue;
+                },
+                Some(got_letter) => {
+                    got_letter
+                }
+            };
+            let new_term = execute_interaction(&term,&frt_elt.position,&frt_elt.target_lf_ids,false).interaction;
+            let target_id = match states_ids.get(&new_term) {
+                Some(got_id) => {
+                    *got_id
+                },
+                None => {
+                    if states.len() >= max_states_num {
+                        is_complete = false;
+                        continue;
+                    }
+                    let new_id = states.len();
+                    states_ids.insert(new_term.clone(),new_id);
+                    states.push(new_term);
+                    queue.push_back(new_id);
+                    new_id
+                }
+            };
+            outgoing.entry(letter).or_insert(hashset!{}).insert(target_id);
+        }
+        // states are processed in the order of their creation
+        transitions.push(outgoing);
+    }
+    let nfa = AutNFA{alphabet:(0..alphabet.len()).collect(),
+                     initials:hashset!{0},
+                     finals,
+                     transitions};
+    (nfa,states,is_complete)
+}
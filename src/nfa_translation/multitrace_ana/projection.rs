@@ -0,0 +1,110 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashMap, HashSet};
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::run::AutRunnable;
+
+use crate::nfa_translation::multitrace_ana::kind::NfaMultiTraceAnalysisKind;
+
+
+fn get_closure_on_hidden_letters(nfa : &AutNFA<usize>,
+                                 hidden_letters : &HashSet<usize>,
+                                 states : &HashSet<usize>) -> HashSet<usize> {
+    let mut closure = states.clone();
+    let mut to_visit : Vec<usize> = states.iter().cloned().collect();
+    while let Some(state) = to_visit.pop() {
+        for (letter,targets) in nfa.transitions.get(state).unwrap() {
+            if hidden_letters.contains(letter) {
+                for target in targets {
+                    if closure.insert(*target) {
+                        to_visit.push(*target);
+                    }
+                }
+            }
+        }
+    }
+    closure
+}
+
+/**
+ * Projects the NFA onto the letters in "kept_letters".
+ * Transitions labelled by the letters in "hidden_letters" are considered to be silent
+ * while transitions labelled by any other letter are removed.
+ * The resulting NFA has the same states as the original one.
+ **/
+pub fn project_nfa_on_letters(nfa : &AutNFA<usize>,
+                              kept_letters : &HashSet<usize>,
+                              hidden_letters : &HashSet<usize>) -> AutNFA<usize> {
+    let closures : Vec<HashSet<usize>> = (0..nfa.transitions.len()).map(
+        |state| get_closure_on_hidden_letters(nfa,hidden_letters,&hashset!{state})
+    ).collect();
+    // ***
+    let mut initials : HashSet<usize> = hashset!{};
+    for init in &nfa.initials {
+        initials.extend(closures.get(*init).unwrap().iter().cloned());
+    }
+    let mut finals : HashSet<usize> = hashset!{};
+    let mut transitions : Vec<HashMap<usize,HashSet<usize>>> = vec![];
+    for (state,closure) in closures.iter().enumerate() {
+        if closure.iter().any(|x| nfa.finals.contains(x)) {
+            finals.insert(state);
+        }
+        let mut outgoing : HashMap<usize,HashSet<usize>> = hashmap!{};
+        for (letter,targets) in nfa.transitions.get(state).unwrap() {
+            if kept_letters.contains(letter) {
+                let letter_targets = outgoing.entry(*letter).or_insert(hashset!{});
+                for target in targets {
+                    letter_targets.extend(closures.get(*target).unwrap().iter().cloned());
+                }
+            }
+        }
+        transitions.push(outgoing);
+    }
+    AutNFA{alphabet:kept_letters.clone(),initials,finals,transitions}
+}
+
+/**
+ * Checks whether or not a (component) trace may be observed on the projection of a trimmed NFA
+ * according to the kind of analysis.
+ **/
+pub fn is_word_compatible_with_projection(projection : &AutNFA<usize>,
+                                          word : &Vec<usize>,
+                                          kind : &NfaMultiTraceAnalysisKind) -> bool {
+    let mut current_states : HashSet<usize>;
+    if kind.may_skip_before_component_start() {
+        current_states = (0..projection.transitions.len()).collect();
+    } else {
+        current_states = projection.initials.clone();
+    }
+    for letter in word {
+        if current_states.is_empty() {
+            return false;
+        }
+        current_states = projection.run_transition(&current_states,letter).unwrap();
+    }
+    match kind {
+        NfaMultiTraceAnalysisKind::Accept => {
+            current_states.iter().any(|x| projection.finals.contains(x))
+        },
+        _ => {
+            // all the states of a trimmed NFA are co-accessible
+            !current_states.is_empty()
+        }
+    }
+}
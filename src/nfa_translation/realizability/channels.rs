@@ -0,0 +1,111 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionTargetRef};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+ * Message buffers of the asynchronous composition of the local automata of an interaction.
+ * Because the actions of a trace do not identify the other end of a communication,
+ * a buffer is associated to each pair of a message and of a receiving lifeline,
+ * and the lifelines an emission is addressed to are statically inferred from the interaction.
+ * Emissions towards gates and receptions from gates or from the environment do not use buffers.
+ **/
+pub struct AsyncChannels {
+    // (emitting lifeline, message) -> receiving lifelines
+    pub addressing : BTreeMap<(usize,usize),BTreeSet<usize>>,
+    // (message, receiving lifeline) of each buffer
+    pub buffers : Vec<(usize,usize)>
+}
+
+impl AsyncChannels {
+
+    pub fn from_interaction(gen_ctx : &GeneralContext, int : &Interaction) -> Result<AsyncChannels,String> {
+        let charac = int.get_characteristics();
+        if charac.has_ands || charac.has_sync {
+            return Err("the realizability analysis does not apply to interactions with 'and' or 'sync' operators".to_string());
+        }
+        let (emissions,receptions) = int.contained_model_actions();
+        let mut addressing : BTreeMap<(usize,usize),BTreeSet<usize>> = BTreeMap::new();
+        for em_act in emissions {
+            if em_act.synchronicity == CommunicationSynchronicity::Synchronous {
+                return Err("the realizability analysis only applies to asynchronous communications".to_string());
+            }
+            let targets : BTreeSet<usize> = em_act.targets.iter()
+                .filter_map(|x| match x {
+                    EmissionTargetRef::Lifeline(lf_id) => Some(*lf_id),
+                    EmissionTargetRef::Gate(_) => None
+                })
+                .collect();
+            let key = (em_act.origin_lf_id,em_act.ms_id);
+            match addressing.get(&key) {
+                None => {
+                    addressing.insert(key,targets);
+                },
+                Some(other_targets) => {
+                    if *other_targets != targets {
+                        return Err(format!("lifeline '{:}' emits message '{:}' towards distinct sets of lifelines",
+                                           gen_ctx.get_lf_name(em_act.origin_lf_id).unwrap(),
+                                           gen_ctx.get_ms_name(em_act.ms_id).unwrap()));
+                    }
+                }
+            }
+        }
+        let mut buffers : BTreeSet<(usize,usize)> = BTreeSet::new();
+        for ((_,ms_id),targets) in &addressing {
+            for lf_id in targets {
+                buffers.insert((*ms_id,*lf_id));
+            }
+        }
+        for rc_act in receptions {
+            if rc_act.synchronicity == CommunicationSynchronicity::Synchronous {
+                return Err("the realizability analysis only applies to asynchronous communications".to_string());
+            }
+            for lf_id in &rc_act.recipients {
+                if buffers.contains(&(rc_act.ms_id,*lf_id)) {
+                    return Err(format!("lifeline '{:}' receives message '{:}' both from the environment and from other lifelines",
+                                       gen_ctx.get_lf_name(*lf_id).unwrap(),
+                                       gen_ctx.get_ms_name(rc_act.ms_id).unwrap()));
+                }
+            }
+        }
+        Ok(AsyncChannels{addressing,buffers:buffers.into_iter().collect()})
+    }
+
+    // index of the buffer from which a reception of "ms_id" by "lf_id" consumes, if any
+    pub fn get_buffer_id(&self, ms_id : usize, lf_id : usize) -> Option<usize> {
+        self.buffers.iter().position(|x| *x == (ms_id,lf_id))
+    }
+
+    // indices of the buffers in which an emission of "ms_id" by "lf_id" is stored
+    pub fn get_target_buffers_ids(&self, lf_id : usize, ms_id : usize) -> Vec<usize> {
+        match self.addressing.get(&(lf_id,ms_id)) {
+            None => {
+                vec![]
+            },
+            Some(targets) => {
+                targets.iter().filter_map(|tar_lf_id| self.get_buffer_id(ms_id,*tar_lf_id)).collect()
+            }
+        }
+    }
+
+}
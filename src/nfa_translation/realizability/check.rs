@@ -0,0 +1,145 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::nfa_translation::compare::get_shortest_word_in_difference;
+use crate::nfa_translation::realizability::channels::AsyncChannels;
+use crate::nfa_translation::realizability::composition::AsyncComposition;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::inconc::InconcReason;
+
+
+pub struct RealizabilityReport {
+    // shortest trace accepted by the composition of the local automata and not by the interaction
+    pub implied_scenario : Option<Vec<usize>>,
+    // shortest trace after which the composition is stuck in a non-accepting configuration
+    pub deadlock : Option<Vec<usize>>,
+    // shortest trace accepted by the interaction and not by the composition
+    pub missing_trace : Option<Vec<usize>>,
+    pub num_configurations : usize,
+    pub is_complete : bool
+}
+
+impl RealizabilityReport {
+
+    /**
+     * Pass if the composition of the local automata accepts exactly the traces of the interaction without deadlocks.
+     * Fail if a counterexample has been found.
+     * Inconc otherwise if the composition could not be entirely built.
+     **/
+    pub fn get_verdict(&self) -> AnalysisGlobalVerdict {
+        if self.implied_scenario.is_some() || self.deadlock.is_some() || self.missing_trace.is_some() {
+            AnalysisGlobalVerdict::Fail
+        } else if !self.is_complete {
+            AnalysisGlobalVerdict::Inconc(InconcReason::FilteredNodes)
+        } else {
+            AnalysisGlobalVerdict::Pass
+        }
+    }
+
+}
+
+/**
+ * Composes the local automata of the lifelines asynchronously and compares the resulting language
+ * with that of the interaction (given by "int_nfa").
+ * Traces which are missing from the composition are only reported if it has been entirely built
+ * given that they may be due to the bound on the size of the buffers.
+ **/
+pub fn check_realizability(int_nfa : &AutNFA<usize>,
+                           local_dfas : &Vec<AutDFA<usize>>,
+                           alphabet : &Vec<BTreeSet<TraceAction>>,
+                           channels : &AsyncChannels,
+                           max_buffer_size : u32,
+                           max_configurations : usize) -> RealizabilityReport {
+    let composition = AsyncComposition::new(local_dfas,alphabet,channels,max_buffer_size,max_configurations);
+    let implied_scenario = get_shortest_word_in_difference(&composition.nfa,int_nfa);
+    let deadlock = composition.deadlocks.first().map(|x| composition.get_trace_to_configuration(*x));
+    let missing_trace = if composition.is_complete {
+        get_shortest_word_in_difference(int_nfa,&composition.nfa)
+    } else {
+        None
+    };
+    RealizabilityReport{implied_scenario,
+                        deadlock,
+                        missing_trace,
+                        num_configurations:composition.nfa.transitions.len(),
+                        is_complete:composition.is_complete}
+}
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef};
+    use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+    use crate::nfa_translation::realizability::composition::DEFAULT_MAX_BUFFER_SIZE;
+    use crate::nfa_translation::realizability::local::{get_lifelines_local_interactions, get_local_dfa};
+    use super::*;
+
+    fn message(origin_lf_id : usize, ms_id : usize, target_lf_id : usize) -> Interaction {
+        Interaction::Emission(EmissionAction::new(origin_lf_id,
+                                                  ms_id,
+                                                  CommunicationSynchronicity::Asynchronous,
+                                                  vec![EmissionTargetRef::Lifeline(target_lf_id)]))
+    }
+
+    fn check_interaction(int : &Interaction) -> RealizabilityReport {
+        let mut gen_ctx = GeneralContext::new();
+        for lf_name in ["a","b","c","d"] {
+            gen_ctx.add_lf(lf_name.to_string());
+        }
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let channels = AsyncChannels::from_interaction(&gen_ctx,int).unwrap();
+        let (int_nfa,_) = get_nfa_from_interaction_exploration(&gen_ctx,int,alphabet.clone());
+        let local_dfas : Vec<AutDFA<usize>> = get_lifelines_local_interactions(&gen_ctx,int).iter()
+            .map(|local_int| get_local_dfa(get_nfa_from_interaction_exploration(&gen_ctx,local_int,alphabet.clone()).0))
+            .collect();
+        check_realizability(&int_nfa,&local_dfas,&alphabet,&channels,DEFAULT_MAX_BUFFER_SIZE,10000)
+    }
+
+    #[test]
+    fn realizability_test() {
+        let realizable = check_interaction(&Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(1,1,2))));
+        assert_eq!(realizable.get_verdict(), AnalysisGlobalVerdict::Pass);
+        // c may receive the first m2 after b has sent the second one
+        let strict_loop = check_interaction(&Interaction::Loop(LoopKind::SStrictSeq,Box::new(message(1,1,2))));
+        assert_eq!(strict_loop.get_verdict(), AnalysisGlobalVerdict::Fail);
+        assert_eq!(strict_loop.implied_scenario.map(|x| x.len()), Some(4));
+        // nothing prevents c from sending m2 before a sends m1
+        let implied = check_interaction(&Interaction::Strict(Box::new(message(0,0,1)),Box::new(message(2,1,3))));
+        assert_eq!(implied.get_verdict(), AnalysisGlobalVerdict::Fail);
+        assert_eq!(implied.implied_scenario.map(|x| x.len()), Some(4));
+        assert_eq!(implied.deadlock, None);
+        // a and c may both send their message while b only receives one of them
+        let deadlock = check_interaction(&Interaction::Alt(Box::new(message(0,0,1)),Box::new(message(2,1,1))));
+        assert_eq!(deadlock.get_verdict(), AnalysisGlobalVerdict::Fail);
+        assert_eq!(deadlock.deadlock.map(|x| x.len()), Some(3));
+    }
+
+}
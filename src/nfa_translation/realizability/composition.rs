@@ -0,0 +1,163 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::nfa_translation::realizability::channels::AsyncChannels;
+
+
+pub const DEFAULT_MAX_BUFFER_SIZE : u32 = 3;
+
+/**
+ * Asynchronous composition of the local automata of the lifelines.
+ * Its states (configurations) are made of a state of each local automaton and of the number of messages in each buffer.
+ * A configuration is accepting iff all local automata are in accepting states and all buffers are empty.
+ **/
+pub struct AsyncComposition {
+    pub nfa : AutNFA<usize>,
+    // for each configuration, the configuration and letter from which it was first reached
+    parents : Vec<Option<(usize,usize)>>,
+    // non-accepting configurations from which no action can be executed, by order of discovery
+    pub deadlocks : Vec<usize>,
+    // false if some buffer would have exceeded its bound or if there are too many configurations
+    pub is_complete : bool
+}
+
+impl AsyncComposition {
+
+    /**
+     * Builds the reachable part of the composition by a breadth-first search,
+     * so that the configurations are reached by shortest traces.
+     * Emissions which would exceed "max_buffer_size" messages in a buffer are not explored.
+     **/
+    pub fn new(local_dfas : &Vec<AutDFA<usize>>,
+               alphabet : &Vec<BTreeSet<TraceAction>>,
+               channels : &AsyncChannels,
+               max_buffer_size : u32,
+               max_configurations : usize) -> AsyncComposition {
+        let letters_actions : Vec<TraceAction> = alphabet.iter().map(|x| *x.iter().next().unwrap()).collect();
+        let init : (Vec<usize>,Vec<u32>) = (local_dfas.iter().map(|x| x.initial).collect(),
+                                            vec![0;channels.buffers.len()]);
+        let mut configurations_ids : HashMap<(Vec<usize>,Vec<u32>),usize> = HashMap::new();
+        configurations_ids.insert(init.clone(),0);
+        let mut configurations = vec![init];
+        let mut parents = vec![None];
+        let mut finals : HashSet<usize> = HashSet::new();
+        let mut transitions : Vec<HashMap<usize,HashSet<usize>>> = vec![];
+        let mut deadlocks = vec![];
+        let mut is_complete = true;
+        let mut queue : VecDeque<usize> = VecDeque::new();
+        queue.push_back(0);
+        while let Some(config_id) = queue.pop_front() {
+            let (local_states,buffers) = configurations.get(config_id).unwrap().clone();
+            let is_accepting = buffers.iter().all(|x| *x == 0)
+                && local_dfas.iter().zip(local_states.iter()).all(|(dfa,state)| dfa.finals.contains(state));
+            if is_accepting {
+                finals.insert(config_id);
+            }
+            let mut outgoing : HashMap<usize,HashSet<usize>> = HashMap::new();
+            let mut is_blocked_by_bound = false;
+            for (lf_id,dfa) in local_dfas.iter().enumerate() {
+                let mut local_outgoing : Vec<(usize,usize)> = dfa.transitions.get(*local_states.get(lf_id).unwrap()).unwrap()
+                    .iter()
+                    .map(|(letter,target)| (*letter,*target))
+                    .collect();
+                local_outgoing.sort();
+                for (letter,local_target) in local_outgoing {
+                    let action = letters_actions.get(letter).unwrap();
+                    let mut new_buffers = buffers.clone();
+                    match action.act_kind {
+                        TraceActionKind::Emission => {
+                            let mut exceeds_bound = false;
+                            for buffer_id in channels.get_target_buffers_ids(action.lf_id,action.ms_id) {
+                                let buffer = new_buffers.get_mut(buffer_id).unwrap();
+                                if *buffer >= max_buffer_size {
+                                    exceeds_bound = true;
+                                }
+                                *buffer += 1;
+                            }
+                            if exceeds_bound {
+                                is_blocked_by_bound = true;
+                                is_complete = false;
+                                continue;
+                            }
+                        },
+                        TraceActionKind::Reception => {
+                            if let Some(buffer_id) = channels.get_buffer_id(action.ms_id,action.lf_id) {
+                                let buffer = new_buffers.get_mut(buffer_id).unwrap();
+                                if *buffer == 0 {
+                                    continue;
+                                }
+                                *buffer -= 1;
+                            }
+                        }
+                    }
+                    let mut new_local_states = local_states.clone();
+                    new_local_states[lf_id] = local_target;
+                    let new_config = (new_local_states,new_buffers);
+                    let target_id = match configurations_ids.get(&new_config) {
+                        Some(got_id) => {
+                            *got_id
+                        },
+                        None => {
+                            if configurations.len() >= max_configurations {
+                                is_complete = false;
+                                is_blocked_by_bound = true;
+                                continue;
+                            }
+                            let new_id = configurations.len();
+                            configurations_ids.insert(new_config.clone(),new_id);
+                            configurations.push(new_config);
+                            parents.push(Some((config_id,letter)));
+                            queue.push_back(new_id);
+                            new_id
+                        }
+                    };
+                    outgoing.entry(letter).or_insert(HashSet::new()).insert(target_id);
+                }
+            }
+            if !is_accepting && outgoing.is_empty() && !is_blocked_by_bound {
+                deadlocks.push(config_id);
+            }
+            // configurations are processed in the order of their creation
+            transitions.push(outgoing);
+        }
+        let nfa = AutNFA{alphabet:(0..alphabet.len()).collect(),
+                         initials:hashset!{0},
+                         finals,
+                         transitions};
+        AsyncComposition{nfa,parents,deadlocks,is_complete}
+    }
+
+    // shortest trace leading to configuration "config_id"
+    pub fn get_trace_to_configuration(&self, config_id : usize) -> Vec<usize> {
+        let mut word = vec![];
+        let mut current = config_id;
+        while let Some(Some((parent,letter))) = self.parents.get(current) {
+            word.push(*letter);
+            current = *parent;
+        }
+        word.reverse();
+        word
+    }
+
+}
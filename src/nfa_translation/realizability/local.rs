@@ -0,0 +1,51 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::access::AutAccessible;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+/**
+ * Returns the projection of "int" on each lifeline of the signature,
+ * obtained by eliminating all the other lifelines.
+ **/
+pub fn get_lifelines_local_interactions(gen_ctx : &GeneralContext, int : &Interaction) -> Vec<Interaction> {
+    let mut local_interactions = vec![];
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        let mut lfs_to_remove : BTreeSet<usize> = (0..gen_ctx.get_lf_num()).collect();
+        lfs_to_remove.remove(&lf_id);
+        local_interactions.push(int.eliminate_lifelines(&lfs_to_remove));
+    }
+    local_interactions
+}
+
+/**
+ * Determinizes the NFA of a local interaction into the automaton of its lifeline.
+ * It is trimmed beforehand so that the local automaton has no state from which it cannot terminate.
+ **/
+pub fn get_local_dfa(local_nfa : AutNFA<usize>) -> AutDFA<usize> {
+    local_nfa.trim().to_dfa()
+}
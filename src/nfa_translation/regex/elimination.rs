@@ -0,0 +1,110 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{BTreeSet, BTreeMap};
+
+use autour_core::bre::term::TermBRE;
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::access::AutAccessible;
+
+
+fn add_edge(edges : &mut BTreeMap<(usize,usize),TermBRE<usize>>,
+            orig : usize,
+            targ : usize,
+            term : TermBRE<usize>) {
+    let united = match edges.remove(&(orig,targ)) {
+        None => {
+            term
+        },
+        Some(existing) => {
+            existing.unite(term)
+        }
+    };
+    edges.insert((orig,targ),united);
+}
+
+/**
+ * Converts an NFA into a regular expression by state elimination.
+ * A fresh initial state and a fresh final state are added, linked by epsilon transitions
+ * to the initial and final states of the NFA, so that cycles going through initial states are preserved.
+ * States are eliminated in the order that creates the fewest new transitions.
+ **/
+pub fn get_regex_from_nfa(nfa : &AutNFA<usize>) -> TermBRE<usize> {
+    let nfa = nfa.clone().trim();
+    let states_num = nfa.transitions.len();
+    let start = states_num;
+    let end = states_num + 1;
+    let mut edges : BTreeMap<(usize,usize),TermBRE<usize>> = BTreeMap::new();
+    for initial in &nfa.initials {
+        add_edge(&mut edges,start,*initial,TermBRE::Epsilon);
+    }
+    for final_state in &nfa.finals {
+        add_edge(&mut edges,*final_state,end,TermBRE::Epsilon);
+    }
+    for (orig,outgoing) in nfa.transitions.iter().enumerate() {
+        for (letter,targets) in outgoing {
+            for targ in targets {
+                add_edge(&mut edges,orig,*targ,TermBRE::Literal(*letter));
+            }
+        }
+    }
+    let mut remaining : BTreeSet<usize> = (0..states_num).collect();
+    while !remaining.is_empty() {
+        let to_eliminate = *remaining.iter().min_by_key(
+            |state| {
+                let ins = edges.keys().filter(|(o,t)| t == *state && o != *state).count();
+                let outs = edges.keys().filter(|(o,t)| o == *state && t != *state).count();
+                ins * outs
+            }
+        ).unwrap();
+        remaining.remove(&to_eliminate);
+        let self_loop = match edges.remove(&(to_eliminate,to_eliminate)) {
+            None => {
+                TermBRE::Epsilon
+            },
+            Some(term) => {
+                TermBRE::Kleene(Box::new(term))
+            }
+        };
+        let incoming : Vec<(usize,TermBRE<usize>)> = edges.iter()
+            .filter(|((_,t),_)| *t == to_eliminate)
+            .map(|((o,_),term)| (*o,term.clone()))
+            .collect();
+        let outgoing : Vec<(usize,TermBRE<usize>)> = edges.iter()
+            .filter(|((o,_),_)| *o == to_eliminate)
+            .map(|((_,t),term)| (*t,term.clone()))
+            .collect();
+        edges.retain(|(o,t),_| *o != to_eliminate && *t != to_eliminate);
+        for (orig,in_term) in &incoming {
+            for (targ,out_term) in &outgoing {
+                let bypass = in_term.clone()
+                    .concatenate(self_loop.clone())
+                    .concatenate(out_term.clone());
+                add_edge(&mut edges,*orig,*targ,bypass);
+            }
+        }
+    }
+    match edges.remove(&(start,end)) {
+        None => {
+            TermBRE::Empty
+        },
+        Some(term) => {
+            term
+        }
+    }
+}
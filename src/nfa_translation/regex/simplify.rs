@@ -0,0 +1,357 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use autour_core::bre::term::TermBRE;
+
+
+const MAX_SIMPLIFICATION_PASSES : u32 = 100;
+
+pub fn get_regex_size(term : &TermBRE<usize>) -> usize {
+    match term {
+        TermBRE::Empty | TermBRE::Epsilon | TermBRE::Literal(_) => {
+            1
+        },
+        TermBRE::Union(sub_terms) => {
+            1 + sub_terms.iter().map(get_regex_size).sum::<usize>()
+        },
+        TermBRE::Concat(sub_terms) => {
+            1 + sub_terms.iter().map(get_regex_size).sum::<usize>()
+        },
+        TermBRE::Kleene(sub_term) => {
+            1 + get_regex_size(sub_term)
+        }
+    }
+}
+
+/**
+ * Simplifies a regular expression by applying the following language preserving rewrites until a fixpoint is reached :
+ * - neutral and absorbing elements : x.ε = x, x.∅ = ∅, x|∅ = x, ε* = ∅* = ε
+ * - flattening of nested unions and concatenations
+ * - stars : (x*)* = x*, (x|ε)* = x*, (x*|y)* = (x|y)*, x*.x* = x*, ε|x.x* = ε|x*.x = x*, x|x* = x*, ε|x = x if x accepts ε
+ * - factorization of the common prefixes and suffixes of the members of a union : x.y|x.z = x.(y|z)
+ **/
+pub fn simplify_regex(term : TermBRE<usize>) -> TermBRE<usize> {
+    let mut current = term;
+    for _ in 0..MAX_SIMPLIFICATION_PASSES {
+        let next = simplify_regex_once(current.clone());
+        if next == current {
+            break;
+        }
+        current = next;
+    }
+    current
+}
+
+fn simplify_regex_once(term : TermBRE<usize>) -> TermBRE<usize> {
+    match term {
+        TermBRE::Empty | TermBRE::Epsilon | TermBRE::Literal(_) => {
+            term
+        },
+        TermBRE::Kleene(sub_term) => {
+            simplify_kleene(simplify_regex_once(*sub_term))
+        },
+        TermBRE::Concat(sub_terms) => {
+            simplify_concat(sub_terms.into_iter().map(simplify_regex_once).collect())
+        },
+        TermBRE::Union(sub_terms) => {
+            simplify_union(sub_terms.into_iter().map(simplify_regex_once).collect())
+        }
+    }
+}
+
+fn simplify_kleene(sub_term : TermBRE<usize>) -> TermBRE<usize> {
+    match sub_term {
+        TermBRE::Empty | TermBRE::Epsilon => {
+            TermBRE::Epsilon
+        },
+        TermBRE::Kleene(_) => {
+            sub_term
+        },
+        TermBRE::Union(members) => {
+            let members : BTreeSet<TermBRE<usize>> = members.into_iter()
+                .filter(|x| *x != TermBRE::Epsilon)
+                .map(|x| match x {
+                    TermBRE::Kleene(inner) => *inner,
+                    _ => x
+                })
+                .collect();
+            TermBRE::Kleene(Box::new(make_union(members)))
+        },
+        _ => {
+            TermBRE::Kleene(Box::new(sub_term))
+        }
+    }
+}
+
+fn make_concat(mut sub_terms : Vec<TermBRE<usize>>) -> TermBRE<usize> {
+    match sub_terms.len() {
+        0 => {
+            TermBRE::Epsilon
+        },
+        1 => {
+            sub_terms.remove(0)
+        },
+        _ => {
+            TermBRE::Concat(sub_terms)
+        }
+    }
+}
+
+fn make_union(mut members : BTreeSet<TermBRE<usize>>) -> TermBRE<usize> {
+    match members.len() {
+        0 => {
+            TermBRE::Empty
+        },
+        1 => {
+            members.pop_first().unwrap()
+        },
+        _ => {
+            TermBRE::Union(members)
+        }
+    }
+}
+
+// the sequence of terms that are concatenated in a term
+fn as_concat_sequence(term : &TermBRE<usize>) -> Vec<TermBRE<usize>> {
+    match term {
+        TermBRE::Epsilon => {
+            vec![]
+        },
+        TermBRE::Concat(sub_terms) => {
+            sub_terms.clone()
+        },
+        _ => {
+            vec![term.clone()]
+        }
+    }
+}
+
+fn simplify_concat(sub_terms : Vec<TermBRE<usize>>) -> TermBRE<usize> {
+    let mut flattened : Vec<TermBRE<usize>> = vec![];
+    for sub_term in sub_terms {
+        match sub_term {
+            TermBRE::Empty => {
+                return TermBRE::Empty;
+            },
+            TermBRE::Epsilon => {},
+            TermBRE::Concat(inner) => {
+                flattened.extend(inner);
+            },
+            _ => {
+                flattened.push(sub_term);
+            }
+        }
+    }
+    let mut merged : Vec<TermBRE<usize>> = vec![];
+    for sub_term in flattened {
+        if let TermBRE::Kleene(_) = sub_term {
+            if merged.last() == Some(&sub_term) {
+                continue;
+            }
+        }
+        merged.push(sub_term);
+    }
+    make_concat(merged)
+}
+
+// whether or not the concatenation of "sequence" is x.x* or x*.x
+fn is_plus(sequence : &Vec<TermBRE<usize>>) -> Option<TermBRE<usize>> {
+    if sequence.len() < 2 {
+        return None;
+    }
+    if let TermBRE::Kleene(inner) = sequence.last().unwrap() {
+        if as_concat_sequence(inner) == sequence[..sequence.len()-1].to_vec() {
+            return Some(sequence.last().unwrap().clone());
+        }
+    }
+    if let TermBRE::Kleene(inner) = sequence.first().unwrap() {
+        if as_concat_sequence(inner) == sequence[1..].to_vec() {
+            return Some(sequence.first().unwrap().clone());
+        }
+    }
+    None
+}
+
+fn simplify_union(sub_terms : Vec<TermBRE<usize>>) -> TermBRE<usize> {
+    let mut members : BTreeSet<TermBRE<usize>> = BTreeSet::new();
+    for sub_term in sub_terms {
+        match sub_term {
+            TermBRE::Empty => {},
+            TermBRE::Union(inner) => {
+                members.extend(inner);
+            },
+            _ => {
+                members.insert(sub_term);
+            }
+        }
+    }
+    // ε|x.x* = x*
+    if members.contains(&TermBRE::Epsilon) {
+        let pluses : Vec<(TermBRE<usize>,TermBRE<usize>)> = members.iter()
+            .filter_map(|x| is_plus(&as_concat_sequence(x)).map(|star| (x.clone(),star)))
+            .collect();
+        if let Some((plus,star)) = pluses.into_iter().next() {
+            members.remove(&plus);
+            members.remove(&TermBRE::Epsilon);
+            members.insert(star);
+        }
+    }
+    // x|x* = x*
+    let starred : Vec<TermBRE<usize>> = members.iter()
+        .filter_map(|x| match x {
+            TermBRE::Kleene(inner) => Some((**inner).clone()),
+            _ => None
+        })
+        .collect();
+    for inner in starred {
+        members.remove(&inner);
+    }
+    // ε|x = x if x accepts ε
+    if members.len() > 1 && members.contains(&TermBRE::Epsilon) {
+        if members.iter().any(|x| *x != TermBRE::Epsilon && x.expresses_epsilon()) {
+            members.remove(&TermBRE::Epsilon);
+        }
+    }
+    let members = factorize_union(members,true);
+    let members = factorize_union(members,false);
+    make_union(members)
+}
+
+/**
+ * Groups the members of a union which share their first (or last) concatenated term
+ * and factorizes this term out of each group whenever this reduces its size.
+ **/
+fn factorize_union(members : BTreeSet<TermBRE<usize>>,
+                   on_prefix : bool) -> BTreeSet<TermBRE<usize>> {
+    let mut groups : Vec<(TermBRE<usize>,Vec<Vec<TermBRE<usize>>>)> = vec![];
+    let mut factorized = BTreeSet::new();
+    for member in members {
+        let mut sequence = as_concat_sequence(&member);
+        if sequence.is_empty() {
+            factorized.insert(member);
+            continue;
+        }
+        let factor = if on_prefix {
+            sequence.remove(0)
+        } else {
+            sequence.pop().unwrap()
+        };
+        match groups.iter_mut().find(|(x,_)| *x == factor) {
+            None => {
+                groups.push((factor,vec![sequence]));
+            },
+            Some((_,rests)) => {
+                rests.push(sequence);
+            }
+        }
+    }
+    for (factor,rests) in groups {
+        let unfactorized : Vec<TermBRE<usize>> = rests.iter().map(
+            |rest| {
+                let mut sequence = rest.clone();
+                if on_prefix {
+                    sequence.insert(0,factor.clone());
+                } else {
+                    sequence.push(factor.clone());
+                }
+                make_concat(sequence)
+            }
+        ).collect();
+        if rests.len() > 1 {
+            let rest = make_union(rests.into_iter().map(make_concat).collect());
+            let sequence = if on_prefix {
+                vec![factor,rest]
+            } else {
+                vec![rest,factor]
+            };
+            let factorized_group = simplify_concat(sequence);
+            // factorizing is only worth it if it makes the expression smaller
+            if get_regex_size(&factorized_group) < unfactorized.iter().map(get_regex_size).sum::<usize>() {
+                factorized.insert(factorized_group);
+                continue;
+            }
+        }
+        factorized.extend(unfactorized);
+    }
+    factorized
+}
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashSet;
+
+    use autour_core::bre::bre::ExpBRE;
+    use autour_core::traits::translate::AutTranslatable;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::experiments::interaction_random_gen::interface::generate_canonical_random_interaction;
+    use crate::experiments::interaction_random_gen::probas::InteractionSymbolsProbabilities;
+    use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+    use crate::nfa_translation::compare::get_shortest_word_in_difference;
+    use crate::nfa_translation::multitrace_ana::nfa::get_nfa_from_interaction_terms;
+    use crate::nfa_translation::regex::elimination::get_regex_from_nfa;
+    use crate::nfa_translation::regex::structural::get_regex_from_interaction_structure;
+    use super::*;
+
+    #[test]
+    fn regex_simplification_preserves_language_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+        let letters : HashSet<usize> = (0..alphabet.len()).collect();
+        let mut rng = StdRng::seed_from_u64(0);
+        let probas = InteractionSymbolsProbabilities::default_regular();
+        for _ in 0..20 {
+            let int = match generate_canonical_random_interaction(&gen_ctx,&mut rng,3,1,&probas) {
+                None => {
+                    continue;
+                },
+                Some(got_int) => {
+                    got_int
+                }
+            };
+            let (nfa,is_complete) = get_nfa_from_interaction_terms(&int,&alphabet,1000);
+            if !is_complete {
+                continue;
+            }
+            let mut regexes = vec![get_regex_from_nfa(&nfa)];
+            if let Some(structural) = get_regex_from_interaction_structure(&int,&alphabet) {
+                regexes.push(structural);
+            }
+            for regex in regexes {
+                let simplified = simplify_regex(regex.clone());
+                assert!(get_regex_size(&simplified) <= get_regex_size(&regex));
+                let simplified_nfa = ExpBRE::from_raw(letters.clone(),simplified).unwrap().to_nfa();
+                assert_eq!(get_shortest_word_in_difference(&nfa,&simplified_nfa),None);
+                assert_eq!(get_shortest_word_in_difference(&simplified_nfa,&nfa),None);
+            }
+        }
+    }
+
+}
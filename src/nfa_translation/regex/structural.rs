@@ -0,0 +1,93 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use autour_core::bre::term::TermBRE;
+
+use crate::core::execution::trace::from_model::from_model::PrimitiveInterpretableAsTraceAction;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::action::CommunicationSynchronicity;
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::core::language::unfold::unfoldable::AtomicUnfoldableAsInteraction;
+
+
+fn get_action_literal(action : TraceAction,
+                      alphabet : &Vec<BTreeSet<TraceAction>>) -> Option<TermBRE<usize>> {
+    let actions = btreeset!{action};
+    alphabet.iter().position(|x| x == &actions).map(TermBRE::Literal)
+}
+
+/**
+ * Builds a regular expression denoting the accepted traces directly from the structure of the interaction.
+ * This is only possible for the fragment made of asynchronous actions, strict sequencing, alternatives and
+ * strictly sequential loops, on which these operators coincide with concatenation, union and the Kleene star.
+ * Returns None if the interaction is outside of this fragment (e.g. if it uses weak sequencing or interleaving).
+ **/
+pub fn get_regex_from_interaction_structure(interaction : &Interaction,
+                                            alphabet : &Vec<BTreeSet<TraceAction>>) -> Option<TermBRE<usize>> {
+    match interaction {
+        Interaction::Empty => {
+            Some(TermBRE::Epsilon)
+        },
+        Interaction::Emission(em_act) => {
+            if em_act.synchronicity != CommunicationSynchronicity::Asynchronous {
+                return None;
+            }
+            if em_act.targets.is_empty() {
+                get_action_literal(em_act.get_first_atomic_action(),alphabet)
+            } else {
+                get_regex_from_interaction_structure(&em_act.unfold_as_interaction(),alphabet)
+            }
+        },
+        Interaction::Reception(rc_act) => {
+            if rc_act.synchronicity != CommunicationSynchronicity::Asynchronous {
+                return None;
+            }
+            match rc_act.recipients.len() {
+                0 => {
+                    Some(TermBRE::Epsilon)
+                },
+                1 => {
+                    get_action_literal(rc_act.get_first_atomic_action(),alphabet)
+                },
+                _ => {
+                    // the receptions are interleaved
+                    None
+                }
+            }
+        },
+        Interaction::Strict(i1,i2) => {
+            let re1 = get_regex_from_interaction_structure(i1,alphabet)?;
+            let re2 = get_regex_from_interaction_structure(i2,alphabet)?;
+            Some(re1.concatenate(re2))
+        },
+        Interaction::Alt(i1,i2) => {
+            let re1 = get_regex_from_interaction_structure(i1,alphabet)?;
+            let re2 = get_regex_from_interaction_structure(i2,alphabet)?;
+            Some(re1.unite(re2))
+        },
+        Interaction::Loop(LoopKind::SStrictSeq,i1) => {
+            let re1 = get_regex_from_interaction_structure(i1,alphabet)?;
+            Some(TermBRE::Kleene(Box::new(re1)))
+        },
+        _ => {
+            None
+        }
+    }
+}
@@ -17,4 +17,3 @@ limitations under the License.
 
 pub mod sequence;
 
-pub mod automata_product;
@@ -27,6 +27,7 @@ use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalys
 use crate::process::ana::context::AnalysisContext;
 use crate::process::ana::filter::filter::AnalysisFilterCriterion;
 use crate::process::ana::handling::local_analysis::{get_local_analysis_starting_data, is_dead_local_analysis};
+use crate::process::ana::heuristic::get_analysis_node_heuristic;
 use crate::process::ana::node::flags::WasMultiTraceConsumedWithSimulation;
 use crate::process::ana::node::node::AnalysisNodeKind;
 use crate::process::ana::param::anakind::AnalysisKind;
@@ -46,32 +47,7 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
                         step_to_process: &GenericStep<AnalysisStepKind>,
                         new_state_id: u32,
                         node_counter: u32) -> AnalysisNodeKind {
-        match step_to_process.kind {
-            AnalysisStepKind::EliminateNoLongerObserved( ref coloc_ids_to_hide ) => {
-                let lfs_to_remove = context.co_localizations.get_lf_ids_from_coloc_ids(coloc_ids_to_hide);
-                let new_interaction = (parent_state.kind.interaction).eliminate_lifelines(&lfs_to_remove);
-                // ***
-                let new_flags = parent_state.kind.flags.update_on_hide(&context.gen_ctx,coloc_ids_to_hide);
-                // ***
-                AnalysisNodeKind::new(new_interaction,new_flags,parent_state.kind.ana_loop_depth)
-            },
-            AnalysisStepKind::Execute( ref frt_elt, ref consu_set, ref sim_map ) => {
-                let exe_result = execute_interaction(&parent_state.kind.interaction,
-                                                     &frt_elt.position,
-                                                     &frt_elt.target_lf_ids,
-                                                     true);
-                let affected_colos = context.co_localizations.get_coloc_ids_from_lf_ids(&exe_result.affected_lifelines);
-                let new_flags = parent_state.kind.flags.update_on_execution(param.ana_kind.get_sim_config(),
-                                                                            consu_set,
-                                                                            sim_map,&affected_colos,
-                                                                            frt_elt.max_loop_depth,
-                                                                            context.init_multitrace_length,
-                                                                            &exe_result.interaction);
-                // ***
-                let new_ana_loop_depth = parent_state.kind.ana_loop_depth + frt_elt.max_loop_depth;
-                AnalysisNodeKind::new(exe_result.interaction,new_flags,new_ana_loop_depth)
-            }
-        }
+        get_child_node_kind(context,param,&parent_state.kind,&step_to_process.kind)
     }
 
     fn get_criterion(context: &AnalysisContext,
@@ -96,7 +72,7 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
                           parent_node_kind: &AnalysisNodeKind)
                 -> Vec<AnalysisStepKind> {
 
-        if !parent_node_kind.flags.is_multi_trace_empty(&context.multi_trace) {
+        let next_steps = if !parent_node_kind.flags.is_multi_trace_empty(&context.multi_trace) {
             match &param.ana_kind {
                 AnalysisKind::Accept => {
                     param.get_action_matches_in_analysis(
@@ -160,6 +136,11 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
             }
         } else {
             vec![]
+        };
+        if param.heuristic_guidance {
+            sort_steps_by_child_heuristic(context,param,parent_node_kind,next_steps)
+        } else {
+            next_steps
         }
     }
 
@@ -313,3 +294,52 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
     }
 }
 
+
+fn get_child_node_kind(context: &AnalysisContext,
+                       param : &AnalysisParameterization,
+                       parent_kind: &AnalysisNodeKind,
+                       step: &AnalysisStepKind) -> AnalysisNodeKind {
+    match *step {
+        AnalysisStepKind::EliminateNoLongerObserved( ref coloc_ids_to_hide ) => {
+            let lfs_to_remove = context.co_localizations.get_lf_ids_from_coloc_ids(coloc_ids_to_hide);
+            let new_interaction = (parent_kind.interaction).eliminate_lifelines(&lfs_to_remove);
+            // ***
+            let new_flags = parent_kind.flags.update_on_hide(&context.gen_ctx,coloc_ids_to_hide);
+            // ***
+            AnalysisNodeKind::new(new_interaction,new_flags,parent_kind.ana_loop_depth)
+        },
+        AnalysisStepKind::Execute( ref frt_elt, ref consu_set, ref sim_map ) => {
+            let exe_result = execute_interaction(&parent_kind.interaction,
+                                                 &frt_elt.position,
+                                                 &frt_elt.target_lf_ids,
+                                                 true);
+            let affected_colos = context.co_localizations.get_coloc_ids_from_lf_ids(&exe_result.affected_lifelines);
+            let new_flags = parent_kind.flags.update_on_execution(param.ana_kind.get_sim_config(),
+                                                                  consu_set,
+                                                                  sim_map,&affected_colos,
+                                                                  frt_elt.max_loop_depth,
+                                                                  context.init_multitrace_length,
+                                                                  &exe_result.interaction);
+            // ***
+            let new_ana_loop_depth = parent_kind.ana_loop_depth + frt_elt.max_loop_depth;
+            AnalysisNodeKind::new(exe_result.interaction,new_flags,new_ana_loop_depth)
+        }
+    }
+}
+
+/**
+ * The steps are sorted by decreasing heuristic of the node they lead to
+ * so that the depth-first queue, which extracts the last steps first, explores the most promising child first.
+ * The sort is stable so that ties are explored in the usual order.
+ * Priorities are then applied on top of this ordering when the steps are enqueued.
+ **/
+fn sort_steps_by_child_heuristic(context: &AnalysisContext,
+                                 param : &AnalysisParameterization,
+                                 parent_kind: &AnalysisNodeKind,
+                                 steps : Vec<AnalysisStepKind>) -> Vec<AnalysisStepKind> {
+    let mut with_heuristic : Vec<(u32,AnalysisStepKind)> = steps.into_iter()
+        .map(|step| (get_analysis_node_heuristic(context,param,&get_child_node_kind(context,param,parent_kind,&step)),step))
+        .collect();
+    with_heuristic.sort_by(|(h1,_),(h2,_)| h2.cmp(h1));
+    return with_heuristic.into_iter().map(|(_,step)| step).collect();
+}
@@ -0,0 +1,106 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+
+
+/**
+ * Estimation of the number of steps that remain to be taken from the node to reach a verdict.
+ * The total number of elements that remain to be consumed in the observed components estimates the number of remaining steps
+ * (it is only exact as long as no step consumes elements of several components at once).
+ * In the Accept mode, the interaction must also be brought to a term that expresses the empty trace,
+ * which requires at least a given number of steps.
+ **/
+pub fn get_analysis_node_heuristic(context: &AnalysisContext,
+                                   param: &AnalysisParameterization,
+                                   node_kind: &AnalysisNodeKind) -> u32 {
+    let mut unconsumed : u32 = 0;
+    for (canal_id,canal_flags) in node_kind.flags.canals.iter().enumerate() {
+        if !canal_flags.no_longer_observed {
+            let canal_length = context.multi_trace.get(canal_id).unwrap().len();
+            unconsumed += (canal_length - canal_flags.consumed) as u32;
+        }
+    }
+    match param.ana_kind {
+        AnalysisKind::Accept => {
+            unconsumed.max(node_kind.interaction.min_steps_to_express_empty())
+        },
+        _ => {
+            unconsumed
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::multitrace::MultiTrace;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+    use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+    use super::*;
+
+    #[test]
+    fn greedy_dfs_verdicts_test() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        let m2 = gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"seq(alt(l1 -- m1 ->|,l1 -- m2 ->|),loopS(l2 -- m1 ->|))").unwrap();
+        let emission = |lf_id : usize, ms_id : usize| -> BTreeSet<TraceAction> {btreeset!{TraceAction::new(lf_id,TraceActionKind::Emission,ms_id)}};
+        let co_localizations = CoLocalizations::get_trivial_partition(2);
+        let accepted : MultiTrace = vec![vec![emission(l1,m2),emission(l2,m1),emission(l2,m1)]];
+        let prefix : MultiTrace = vec![vec![emission(l2,m1)]];
+        let rejected : MultiTrace = vec![vec![emission(l2,m1),emission(l1,m1)]];
+        // ***
+        let ana_ctx = AnalysisContext::new(gen_ctx.clone(),co_localizations.clone(),accepted.clone(),3);
+        let init_node = AnalysisNodeKind::new(int.clone(),MultiTraceAnalysisFlags::new_init(1,0,0),0);
+        let accept_param = AnalysisParameterization::new(AnalysisKind::Accept,None,false);
+        // three actions remain to be consumed whereas the interaction expresses the empty trace after a single step
+        assert_eq!(get_analysis_node_heuristic(&ana_ctx,&accept_param,&init_node), 3);
+        // ***
+        for multi_trace in [accepted,prefix,rejected] {
+            for ana_kind in [AnalysisKind::Accept,AnalysisKind::Prefix] {
+                let mut dfs_opts = HibouAnalyzeOptions::default();
+                dfs_opts.strategy = QueueSearchStrategy::DFS;
+                dfs_opts.ana_param.ana_kind = ana_kind.clone();
+                let mut greedy_opts = HibouAnalyzeOptions::default();
+                greedy_opts.strategy = QueueSearchStrategy::DFS;
+                greedy_opts.ana_param.ana_kind = ana_kind;
+                greedy_opts.ana_param.heuristic_guidance = true;
+                let (_,dfs_verdict) = analyze_multi_trace_without_loggers(&gen_ctx,&int,&co_localizations,&multi_trace,dfs_opts);
+                let (_,greedy_verdict) = analyze_multi_trace_without_loggers(&gen_ctx,&int,&co_localizations,&multi_trace,greedy_opts);
+                // the ordering of the children has no impact on the verdict
+                assert_eq!(greedy_verdict,dfs_verdict);
+            }
+        }
+    }
+
+}
@@ -16,4 +16,5 @@ limitations under the License.
 
 
 pub mod glog;
-pub mod slog;
\ No newline at end of file
+pub mod slog;
+pub mod wlog;
\ No newline at end of file
@@ -0,0 +1,154 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::interpretation::witness::ExecutionWitness;
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::loggers::wlog::step::get_witness_step_from_analysis_step;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+
+
+// keeps in memory the witnesses of all the nodes with a "Cov" local verdict
+pub struct AnalysisWitnessCollector {
+    // witnesses of the nodes which may still have children
+    pub(crate) witnesses_map : HashMap<u32,ExecutionWitness>,
+    pub accepting_witnesses : Vec<ExecutionWitness>
+}
+
+impl AnalysisWitnessCollector {
+    pub fn new() -> Self {
+        AnalysisWitnessCollector { witnesses_map: hashmap!{}, accepting_witnesses: vec![] }
+    }
+
+    fn get_node_witness(&self, node_id : u32) -> ExecutionWitness {
+        match self.witnesses_map.get(&node_id) {
+            None => {
+                ExecutionWitness::new_empty()
+            },
+            Some( witness ) => {
+                witness.clone()
+            }
+        }
+    }
+}
+
+impl AbstractProcessLogger<AnalysisConfig> for AnalysisWitnessCollector {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.witnesses_map = hashmap!{};
+        self.accepting_witnesses = vec![];
+    }
+
+    fn log_parameterization(&mut self,
+                            strategy: &QueueSearchStrategy,
+                            priorities: &GenericProcessPriorities<AnalysisPriorities>,
+                            filters: &[Box<dyn AbstractFilter<AnalysisFilterCriterion, AnalysisFilterEliminationKind>>],
+                            goal: &Option<AnalysisGlobalVerdict>,
+                            use_memoization: bool,
+                            parameterization: &AnalysisParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    context: &AnalysisContext,
+                    parent_node_id: u32,
+                    new_node_id: u32,
+                    elim_kind: &AnalysisFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    context: &AnalysisContext,
+                    param: &AnalysisParameterization,
+                    new_node_id: u32,
+                    new_node: &AnalysisNodeKind) {
+        // nothing
+    }
+
+    fn log_new_step(&mut self,
+                    context: &AnalysisContext,
+                    param: &AnalysisParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &AnalysisStepKind,
+                    target_node: &AnalysisNodeKind,
+                    target_depth: u32) {
+        if !self.witnesses_map.contains_key(&target_node_id) {
+            let witness = self.get_node_witness(origin_node_id)
+                .add_step(get_witness_step_from_analysis_step(context,step));
+            self.witnesses_map.insert(target_node_id,witness);
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               context: &AnalysisContext,
+                               param: &AnalysisParameterization,
+                               parent_node_id: u32,
+                               verdict: &AnalysisLocalVerdict) {
+        if let AnalysisLocalVerdict::Cov = verdict {
+            let witness = self.get_node_witness(parent_node_id);
+            self.accepting_witnesses.push(witness);
+        }
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      context: &AnalysisContext,
+                                      param: &AnalysisParameterization,
+                                      parent_node_id: u32,
+                                      verdict: &AnalysisLocalVerdict,
+                                      proof: &AnalysisStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     global_verdict: &AnalysisGlobalVerdict) {
+        self.witnesses_map = hashmap!{};
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        context: &AnalysisContext,
+                                        node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               context: &AnalysisContext,
+                                               parent_node_id: u32) {
+        self.witnesses_map.remove(&parent_node_id);
+    }
+
+}
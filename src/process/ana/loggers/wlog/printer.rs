@@ -0,0 +1,162 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::Path;
+use graph_process_manager_loggers::stepstrace::printer::StepsTraceProcessPrinter;
+use crate::io::output::to_hfiles::witness::to_hwf::write_witness_into_file;
+use crate::loggers::witness::object::WitnessLoggerObject;
+use crate::loggers::witness::printer::WitnessProcessPrinter;
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::loggers::wlog::step::get_witness_step_from_analysis_step;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::node::flags::WasMultiTraceConsumedWithSimulation;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+impl StepsTraceProcessPrinter<AnalysisConfig,WitnessLoggerObject> for WitnessProcessPrinter {
+
+    fn get_initial_object(&self,
+                          context: &AnalysisContext,
+                          param: &AnalysisParameterization,
+                          node: &AnalysisNodeKind) -> WitnessLoggerObject {
+        self.get_initial_witness()
+    }
+
+    fn add_step_to_object(&self,
+                          context: &AnalysisContext,
+                          param: &AnalysisParameterization,
+                          object: &WitnessLoggerObject,
+                          step: &AnalysisStepKind) -> WitnessLoggerObject {
+        self.add_step_to_witness(object,get_witness_step_from_analysis_step(context,step))
+    }
+
+    fn should_print_on_node_reached(&self,
+                                    context: &AnalysisContext,
+                                    param: &AnalysisParameterization,
+                                    node: &AnalysisNodeKind,
+                                    node_depth: u32) -> bool {
+        // only nodes at which the multi-trace is exactly accepted i.e. those with a "Cov" local verdict
+        if !node.flags.is_multi_trace_empty(&context.multi_trace) {
+            return false;
+        }
+        if !node.interaction.express_empty() || node.flags.is_any_component_hidden() {
+            return false;
+        }
+        match node.flags.is_simulated() {
+            WasMultiTraceConsumedWithSimulation::No => {
+                true
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+    fn print_object(&self,
+                    context: &AnalysisContext,
+                    param: &AnalysisParameterization,
+                    object: &WitnessLoggerObject,
+                    path: &Path) {
+        write_witness_into_file(path,
+                                &context.gen_ctx,
+                                &object.witness);
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::fs;
+
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+    use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+    use graph_process_manager_loggers::stepstrace::logger::GenericStepsTraceLogger;
+
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::interpretation::replay::replay_witness;
+    use crate::core::execution::trace::multitrace::multi_trace_length;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::io::file_extensions::HIBOU_WITNESS_FILE_EXTENSION;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::io::input::hwf::interface::parse_hwf_file;
+    use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+    use crate::process::ana::priorities::AnalysisPriorities;
+    use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+    use super::*;
+
+    #[test]
+    fn witness_round_trip_test() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        let m2 = gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"seq(alt(l1 -- m1 ->|,l1 -- m2 ->|),loopS(l2 -- m1 ->|))").unwrap();
+        let emission = |lf_id : usize, ms_id : usize| -> BTreeSet<TraceAction> {btreeset!{TraceAction::new(lf_id,TraceActionKind::Emission,ms_id)}};
+        // the right branch of the alternative then two instances of the loop
+        let multi_trace = vec![vec![emission(l1,m2),emission(l2,m1),emission(l2,m1)]];
+        let co_localizations = CoLocalizations::get_trivial_partition(2);
+        // ***
+        let parent_folder = std::env::temp_dir().join("hibou_witness_round_trip_test");
+        let logger : GenericStepsTraceLogger<AnalysisConfig,WitnessLoggerObject> =
+            GenericStepsTraceLogger::new(Box::new(WitnessProcessPrinter::new()),
+                                         true,
+                                         "witness".to_string(),
+                                         HIBOU_WITNESS_FILE_EXTENSION.to_string(),
+                                         parent_folder.to_str().unwrap().to_string());
+        let ana_opts = HibouAnalyzeOptions::default();
+        let ana_ctx = AnalysisContext::new(gen_ctx.clone(),co_localizations.clone(),multi_trace.clone(),multi_trace_length(&multi_trace));
+        let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(QueueSearchStrategy::DFS,ana_opts.priorities);
+        let mut manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
+                                                                                             ana_opts.ana_param,
+                                                                                             delegate,
+                                                                                             ana_opts.filters,
+                                                                                             vec![Box::new(logger)],
+                                                                                             None,
+                                                                                             false);
+        let init_flags = MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),0,0);
+        let (_,verdict) = manager.start_process(AnalysisNodeKind::new(int.clone(),init_flags,0));
+        assert_eq!(verdict,AnalysisGlobalVerdict::Pass);
+        // ***
+        let mut num_witnesses = 0;
+        for entry in fs::read_dir(&parent_folder).unwrap() {
+            let hwf_path = entry.unwrap().path();
+            let witness = parse_hwf_file(&gen_ctx,hwf_path.to_str().unwrap()).unwrap();
+            assert_eq!(witness.steps.len(),3);
+            let replay = replay_witness(&int,&witness).unwrap();
+            assert!(replay.is_accepting());
+            let interpretation = replay.interpretation;
+            assert_eq!(interpretation.alts.len(),1);
+            let (_,num_left,num_right) = interpretation.alts.get(0).unwrap();
+            assert_eq!((*num_left,*num_right),(0,1));
+            assert_eq!(interpretation.loops.len(),1);
+            assert_eq!(interpretation.loops.get(0).unwrap().1,2);
+            num_witnesses += 1;
+        }
+        assert!(num_witnesses > 0);
+        fs::remove_dir_all(&parent_folder).unwrap();
+    }
+
+}
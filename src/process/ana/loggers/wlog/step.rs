@@ -0,0 +1,35 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::execution::interpretation::witness::WitnessStep;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+pub(crate) fn get_witness_step_from_analysis_step(context : &AnalysisContext,
+                                                  step : &AnalysisStepKind) -> WitnessStep {
+    match step {
+        AnalysisStepKind::EliminateNoLongerObserved(ref coloc_ids_to_hide) => {
+            let lfs_to_hide = context.co_localizations.get_lf_ids_from_coloc_ids(coloc_ids_to_hide);
+            WitnessStep::Hide(lfs_to_hide)
+        },
+        AnalysisStepKind::Execute(ref frt_elt,_,_) => {
+            WitnessStep::Execute(frt_elt.position.clone(),frt_elt.target_actions.clone())
+        }
+    }
+}
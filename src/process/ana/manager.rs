@@ -0,0 +1,66 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+/**
+ * Analyzes a multi-trace against an interaction with the given options, ignoring their loggers,
+ * and returns the number of explored nodes and the verdict.
+ **/
+pub fn analyze_multi_trace_without_loggers(gen_ctx : &GeneralContext,
+                                           interaction : &Interaction,
+                                           co_localizations : &CoLocalizations,
+                                           multi_trace : &MultiTrace,
+                                           ana_opts : HibouAnalyzeOptions) -> (u32,AnalysisGlobalVerdict) {
+    let multi_trace_length = multi_trace_length(multi_trace);
+    let ana_ctx = AnalysisContext::new(gen_ctx.clone(),co_localizations.clone(),multi_trace.clone(),multi_trace_length);
+    let init_flags = match ana_opts.ana_param.ana_kind.get_sim_config() {
+        None => {
+            MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),0,0)
+        },
+        Some( sim_config ) => {
+            MultiTraceAnalysisFlags::new_init(co_localizations.num_colocs(),
+                                              sim_config.get_reset_rem_loop(multi_trace_length,interaction),
+                                              sim_config.get_reset_rem_act(multi_trace_length,interaction))
+        }
+    };
+    let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
+    let mut analysis_manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
+                                                                                                  ana_opts.ana_param,
+                                                                                                  delegate,
+                                                                                                  ana_opts.filters,
+                                                                                                  vec![],
+                                                                                                  ana_opts.goal,
+                                                                                                  ana_opts.use_memoization);
+    let init_node = AnalysisNodeKind::new(interaction.clone(),init_flags,0);
+    analysis_manager.start_process(init_node)
+}
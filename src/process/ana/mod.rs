@@ -25,3 +25,5 @@ pub mod node;
 pub mod priorities;
 pub mod step;
 pub mod param;
+pub mod heuristic;
+pub mod manager;
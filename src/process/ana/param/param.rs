@@ -74,7 +74,9 @@ impl LocalAnalysisParameterization {
 pub struct AnalysisParameterization {
     pub ana_kind : AnalysisKind,
     pub locana : Option<LocalAnalysisParameterization>,
-    pub partial_order_reduction : bool
+    pub partial_order_reduction : bool,
+    // if true, the children of each node are enqueued in increasing order of their heuristic (GreedyDFS strategy)
+    pub heuristic_guidance : bool
 }
 
 
@@ -83,7 +85,7 @@ impl AnalysisParameterization {
     pub fn new(ana_kind: AnalysisKind,
                locana: Option<LocalAnalysisParameterization>,
                partial_order_reduction : bool) -> Self {
-        AnalysisParameterization{ana_kind, locana, partial_order_reduction}
+        AnalysisParameterization{ana_kind, locana, partial_order_reduction, heuristic_guidance:false}
     }
 }
 
@@ -105,6 +107,9 @@ impl AbstractProcessParameterization for AnalysisParameterization {
         got.push(
             format!("partial order reduction = {:}", self.partial_order_reduction)
         );
+        got.push(
+            format!("heuristic guidance = {:}", self.heuristic_guidance)
+        );
         got
     }
 }
\ No newline at end of file
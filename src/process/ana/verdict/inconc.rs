@@ -4,7 +4,8 @@ use std::fmt;
 pub enum InconcReason {
     LackObs,
     UsingLifelineRemovalWithCoLocalizations,
-    FilteredNodes
+    FilteredNodes,
+    IncompleteCanonization
 }
 
 impl InconcReason {
@@ -19,6 +20,9 @@ impl InconcReason {
             },
             InconcReason::FilteredNodes => {
                 return "due to having set a filter which forcefully limited exploration of the graph : Fails may be false negative".to_string();
+            },
+            InconcReason::IncompleteCanonization => {
+                return "due to canonization not being complete : interactions with distinct normal forms may still be equivalent".to_string();
             }
         }
     }
@@ -37,6 +41,9 @@ impl fmt::Display for InconcReason {
             },
             InconcReason::FilteredNodes => {
                 write!(f,"FilteredNodes")
+            },
+            InconcReason::IncompleteCanonization => {
+                write!(f,"IncompleteCanonization")
             }
         }
     }
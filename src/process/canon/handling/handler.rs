@@ -68,7 +68,7 @@ impl AbstractProcessHandler<CanonizationConfig> for CanonizationProcessHandler {
                 vec![]
             },
             Some(phase) => {
-                let transfos = phase.get_transfos(&parent_node_kind.interaction,param.get_all);
+                let transfos = phase.apply_phase(&parent_node_kind.interaction);
                 if transfos.is_empty() {
                     vec![CanonizationStepKind::GoToNextPhase]
                 } else {
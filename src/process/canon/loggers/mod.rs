@@ -16,3 +16,5 @@ limitations under the License.
 
 
 pub mod glog;
+pub mod proof;
+pub mod phases;
@@ -0,0 +1,75 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+
+use graphviz_dot_builder::colors::GraphvizColor;
+use graphviz_dot_builder::edge::edge::GraphVizEdge;
+use graphviz_dot_builder::edge::style::GraphvizEdgeStyleItem;
+use graphviz_dot_builder::graph::graph::GraphVizDiGraph;
+use graphviz_dot_builder::item::node::node::GraphVizNode;
+use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape, GvNodeStyleKind};
+use graphviz_dot_builder::traits::{DotBuildable, DotPrintable, GraphVizOutputFormat};
+
+use crate::core::general_context::GeneralContext;
+use crate::io::output::draw_interactions::interface::{draw_interaction, InteractionGraphicalRepresentation};
+use crate::process::canon::loggers::phases::logger::CanonizationPhasesLogger;
+
+
+/**
+Draws the graph of the phases into "{output_name}.svg",
+the interactions being drawn as sequence diagrams in the temporary folder.
+The normal forms are circled twice.
+**/
+pub fn draw_canonization_phases(gen_ctx : &GeneralContext,
+                                logger : &CanonizationPhasesLogger,
+                                temp_folder : &String,
+                                output_name : &String) {
+    fs::create_dir_all(temp_folder).unwrap();
+    let mut graph = GraphVizDiGraph::new(vec![]);
+    for node_id in &logger.graph_nodes {
+        let image_name = format!("{}_n{}", output_name, node_id);
+        draw_interaction(gen_ctx,
+                         logger.interactions.get(node_id).unwrap(),
+                         &InteractionGraphicalRepresentation::AsSequenceDiagram,
+                         temp_folder,
+                         temp_folder,
+                         &image_name);
+        let mut style = vec![
+            GraphvizNodeStyleItem::Image(format!("{}/{}.png", temp_folder, image_name)),
+            GraphvizNodeStyleItem::Label("".to_string()),
+            GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle)
+        ];
+        if logger.normal_forms.contains(node_id) {
+            style.push(GraphvizNodeStyleItem::Peripheries(2));
+        } else {
+            style.push(GraphvizNodeStyleItem::Style(vec![GvNodeStyleKind::Filled]));
+            style.push(GraphvizNodeStyleItem::FillColor(GraphvizColor::lightskyblue));
+        }
+        graph.add_node(GraphVizNode::new(format!("n{}", node_id), style));
+    }
+    for (origin_id,target_id,phase) in &logger.graph_edges {
+        graph.add_edge(
+            GraphVizEdge::new(format!("n{}", origin_id),
+                              None,
+                              format!("n{}", target_id),
+                              None,
+                              vec![GraphvizEdgeStyleItem::Label(format!("phase {}", phase + 1))])
+        );
+    }
+    let _ = graph.print_dot(&[".".to_string()],output_name,&GraphVizOutputFormat::svg);
+}
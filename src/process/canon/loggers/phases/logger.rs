@@ -0,0 +1,278 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::canon::conf::{CanonizationConfig, CanonizationStaticLocalVerdictAnalysisProof};
+use crate::process::canon::context::CanonizationContext;
+use crate::process::canon::filter::elim::CanonizationFilterEliminationKind;
+use crate::process::canon::filter::filter::CanonizationFilterCriterion;
+use crate::process::canon::node::CanonizationNodeKind;
+use crate::process::canon::param::phase::CanonizationParameterization;
+use crate::process::canon::priorities::CanonizationPriorities;
+use crate::process::canon::step::CanonizationStepKind;
+use crate::process::canon::verdict::global::CanonizationGlobalVerdict;
+use crate::process::canon::verdict::local::CanonizationLocalVerdict;
+
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CanonizationPhaseStatistics {
+    // the number of distinct interactions reached during the phase
+    pub num_nodes : u32,
+    pub num_transformations : u32,
+    // the number of transformations applied, for each kind of transformation
+    pub transformations : BTreeMap<String,u32>,
+    // the number of distinct interactions with which the phase ends
+    pub num_outputs : u32
+}
+
+/**
+ * Logs the canonization process phase by phase.
+ * Besides the statistics of each phase, it keeps the graph of the phases, the nodes of which are the interactions
+ * with which a phase begins (or the last one ends) and the edges of which are the applications of a phase.
+ * Each node is associated to the phase in which it was first reached.
+ **/
+pub struct CanonizationPhasesLogger {
+    pub interactions : HashMap<u32,Interaction>,
+    // for each node, its phase
+    node_phase : HashMap<u32,u32>,
+    // for each node, the node with which its phase began
+    phase_begin : HashMap<u32,u32>,
+    pub phases : Vec<CanonizationPhaseStatistics>,
+    pub graph_nodes : BTreeSet<u32>,
+    // origin node, target node and phase
+    pub graph_edges : BTreeSet<(u32,u32,u32)>,
+    // the nodes of the normal forms, in the order of the global verdict
+    pub normal_forms : Vec<u32>
+}
+
+impl CanonizationPhasesLogger {
+
+    pub fn new() -> Self {
+        CanonizationPhasesLogger{
+            interactions : HashMap::new(),
+            node_phase : HashMap::new(),
+            phase_begin : HashMap::new(),
+            phases : vec![],
+            graph_nodes : BTreeSet::new(),
+            graph_edges : BTreeSet::new(),
+            normal_forms : vec![]
+        }
+    }
+
+}
+
+impl AbstractProcessLogger<CanonizationConfig> for CanonizationPhasesLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.interactions = HashMap::new();
+        self.node_phase = HashMap::new();
+        self.phase_begin = HashMap::new();
+        self.graph_nodes = BTreeSet::new();
+        self.graph_edges = BTreeSet::new();
+        self.normal_forms = vec![];
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<CanonizationPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<CanonizationFilterCriterion, CanonizationFilterEliminationKind>>],
+                            _goal: &Option<CanonizationGlobalVerdict>,
+                            _use_memoization: bool,
+                            parameterization: &CanonizationParameterization) {
+        self.phases = vec![CanonizationPhaseStatistics::default();parameterization.phases.len()];
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &CanonizationContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &CanonizationFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &CanonizationContext,
+                    _param: &CanonizationParameterization,
+                    new_node_id: u32,
+                    new_node: &CanonizationNodeKind) {
+        if self.interactions.is_empty() {
+            // the initial node begins the first phase
+            self.phase_begin.insert(new_node_id, new_node_id);
+            self.graph_nodes.insert(new_node_id);
+        }
+        self.interactions.insert(new_node_id, new_node.interaction.clone());
+        self.node_phase.insert(new_node_id, new_node.phase);
+        if let Some(phase_stats) = self.phases.get_mut(new_node.phase as usize) {
+            phase_stats.num_nodes += 1;
+        }
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &CanonizationContext,
+                    _param: &CanonizationParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &CanonizationStepKind,
+                    _target_node: &CanonizationNodeKind,
+                    _target_depth: u32) {
+        let origin_phase = match self.node_phase.get(&origin_node_id) {
+            None => {
+                // steps from nodes which have not been logged are ignored
+                return;
+            },
+            Some(phase) => {
+                *phase
+            }
+        };
+        let origin_phase_begin = *self.phase_begin.get(&origin_node_id).unwrap_or(&origin_node_id);
+        // the target node may have already been reached via another step if the process is memoized
+        let is_new_target = !self.phase_begin.contains_key(&target_node_id);
+        match step {
+            CanonizationStepKind::Transform(result) => {
+                if let Some(phase_stats) = self.phases.get_mut(origin_phase as usize) {
+                    phase_stats.num_transformations += 1;
+                    *phase_stats.transformations.entry(result.kind.to_string()).or_insert(0) += 1;
+                }
+                if is_new_target {
+                    self.phase_begin.insert(target_node_id, origin_phase_begin);
+                }
+            },
+            CanonizationStepKind::GoToNextPhase => {
+                if is_new_target {
+                    if let Some(phase_stats) = self.phases.get_mut(origin_phase as usize) {
+                        phase_stats.num_outputs += 1;
+                    }
+                    self.phase_begin.insert(target_node_id, target_node_id);
+                    self.graph_nodes.insert(target_node_id);
+                }
+                self.graph_edges.insert((origin_phase_begin, target_node_id, origin_phase));
+            }
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &CanonizationContext,
+                               _param: &CanonizationParameterization,
+                               parent_node_id: u32,
+                               _verdict: &CanonizationLocalVerdict) {
+        // only the nodes which come after the last phase have no child,
+        // their output having already been counted when going to the next phase
+        self.graph_nodes.insert(parent_node_id);
+        self.normal_forms.push(parent_node_id);
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &CanonizationContext,
+                                      _param: &CanonizationParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &CanonizationLocalVerdict,
+                                      _proof: &CanonizationStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &CanonizationGlobalVerdict) {
+        // nothing
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &CanonizationContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &CanonizationContext,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+    use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::core::transformation::transfokind::InteractionTransformationKind;
+    use crate::core::transformation::transfophase::InteractionTransformationPhase;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use super::*;
+
+    #[test]
+    fn phases_statistics_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let int = interaction_from_text(&gen_ctx,"alt(l1 -- m1 -> l2,l1 -- m1 -> l2)").unwrap();
+        let normal_form = interaction_from_text(&gen_ctx,"l1 -- m1 -> l2").unwrap();
+        // as with the "--all-forms" option, all the transformations of each phase are explored
+        let param = CanonizationParameterization::new(vec![
+            InteractionTransformationPhase::new(vec![InteractionTransformationKind::Deduplicate],true,false),
+            InteractionTransformationPhase::new(vec![InteractionTransformationKind::Simpl],true,false)
+        ]);
+        let delegate : GenericProcessDelegate<CanonizationStepKind,CanonizationNodeKind,CanonizationPriorities> =
+            GenericProcessDelegate::new(
+                QueueSearchStrategy::BFS,
+                GenericProcessPriorities::new(CanonizationPriorities::default(),false)
+            );
+        let mut canon_manager : GenericProcessManager<CanonizationConfig> = GenericProcessManager::new(
+            CanonizationContext::new(gen_ctx.clone()),
+            param,
+            delegate,
+            vec![],
+            vec![Box::new(CanonizationPhasesLogger::new())],
+            None,
+            true
+        );
+        let (_,verdict) = canon_manager.start_process(CanonizationNodeKind::new(int,0));
+        assert_eq!(verdict.canonized_ints, vec![normal_form.clone()]);
+        let logger : &CanonizationPhasesLogger = canon_manager.get_logger(0).unwrap()
+            .as_any().downcast_ref::<CanonizationPhasesLogger>().unwrap();
+        assert_eq!(logger.normal_forms.len(), 1);
+        assert_eq!(logger.interactions.get(&logger.normal_forms[0]), Some(&normal_form));
+        // the first phase removes the duplicate and the second one changes nothing
+        assert_eq!(logger.phases.len(), 2);
+        assert_eq!(logger.phases[0].num_nodes, 2);
+        assert_eq!(logger.phases[0].num_transformations, 1);
+        assert_eq!(logger.phases[0].transformations.get("Deduplicate"), Some(&1));
+        assert_eq!(logger.phases[0].num_outputs, 1);
+        assert_eq!(logger.phases[1].num_nodes, 1);
+        assert_eq!(logger.phases[1].num_transformations, 0);
+        assert_eq!(logger.phases[1].num_outputs, 1);
+        // the initial interaction, the one with which the second phase begins and the normal form
+        assert_eq!(logger.graph_nodes.len(), 3);
+        assert_eq!(logger.graph_edges.len(), 2);
+    }
+
+}
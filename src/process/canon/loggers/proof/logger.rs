@@ -0,0 +1,179 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::proof::derivation::{InteractionDerivation, InteractionDerivationStep};
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::process::canon::conf::{CanonizationConfig, CanonizationStaticLocalVerdictAnalysisProof};
+use crate::process::canon::context::CanonizationContext;
+use crate::process::canon::filter::elim::CanonizationFilterEliminationKind;
+use crate::process::canon::filter::filter::CanonizationFilterCriterion;
+use crate::process::canon::node::CanonizationNodeKind;
+use crate::process::canon::param::phase::CanonizationParameterization;
+use crate::process::canon::priorities::CanonizationPriorities;
+use crate::process::canon::step::CanonizationStepKind;
+use crate::process::canon::verdict::global::CanonizationGlobalVerdict;
+use crate::process::canon::verdict::local::CanonizationLocalVerdict;
+
+
+/**
+ * Records, for each node, the node from which it was first reached and the transformation applied,
+ * so that the derivation from the initial interaction to each normal form can be rebuilt.
+ * The derivations are in the order in which the normal forms appear in the global verdict.
+ **/
+pub struct CanonizationProofLogger {
+    interactions : HashMap<u32,Interaction>,
+    // for each node but the initial one, its parent and the transformation applied
+    // (none when going from one phase to the next)
+    parents : HashMap<u32,(u32,Option<(InteractionTransformationKind,Position)>)>,
+    pub derivations : Vec<InteractionDerivation>
+}
+
+impl CanonizationProofLogger {
+
+    pub fn new() -> Self {
+        CanonizationProofLogger{
+            interactions : HashMap::new(),
+            parents : HashMap::new(),
+            derivations : vec![]
+        }
+    }
+
+    fn get_derivation(&self, node_id : u32) -> InteractionDerivation {
+        let mut steps = vec![];
+        let mut current_id = node_id;
+        while let Some((parent_id,transfo)) = self.parents.get(&current_id) {
+            if let Some((kind,position)) = transfo {
+                steps.push( InteractionDerivationStep::new(kind.clone(),
+                                                           position.clone(),
+                                                           self.interactions.get(parent_id).unwrap().clone(),
+                                                           self.interactions.get(&current_id).unwrap().clone()) );
+            }
+            current_id = *parent_id;
+        }
+        steps.reverse();
+        InteractionDerivation::new(self.interactions.get(&current_id).unwrap().clone(), steps)
+    }
+
+}
+
+impl AbstractProcessLogger<CanonizationConfig> for CanonizationProofLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.interactions = HashMap::new();
+        self.parents = HashMap::new();
+        self.derivations = vec![];
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<CanonizationPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<CanonizationFilterCriterion, CanonizationFilterEliminationKind>>],
+                            _goal: &Option<CanonizationGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &CanonizationParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &CanonizationContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &CanonizationFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &CanonizationContext,
+                    _param: &CanonizationParameterization,
+                    new_node_id: u32,
+                    new_node: &CanonizationNodeKind) {
+        self.interactions.insert(new_node_id, new_node.interaction.clone());
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &CanonizationContext,
+                    _param: &CanonizationParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &CanonizationStepKind,
+                    _target_node: &CanonizationNodeKind,
+                    _target_depth: u32) {
+        // with memoization, a node may be reached several times, in which case its first parent is kept
+        if self.parents.contains_key(&target_node_id) {
+            return;
+        }
+        let transfo = match step {
+            CanonizationStepKind::Transform(result) => {
+                Some((result.kind.clone(), result.position.clone()))
+            },
+            CanonizationStepKind::GoToNextPhase => {
+                None
+            }
+        };
+        self.parents.insert(target_node_id, (origin_node_id, transfo));
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &CanonizationContext,
+                               _param: &CanonizationParameterization,
+                               parent_node_id: u32,
+                               _verdict: &CanonizationLocalVerdict) {
+        let derivation = self.get_derivation(parent_node_id);
+        self.derivations.push(derivation);
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &CanonizationContext,
+                                      _param: &CanonizationParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &CanonizationLocalVerdict,
+                                      _proof: &CanonizationStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &CanonizationGlobalVerdict) {
+        // nothing
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &CanonizationContext,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &CanonizationContext,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+
+}
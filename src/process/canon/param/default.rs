@@ -18,6 +18,7 @@ limitations under the License.
 use crate::core::transformation::transfokind::InteractionTransformationKind;
 use crate::core::transformation::transfophase::InteractionTransformationPhase;
 
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum DefaultCanonizationProcess {
     Basic,
     BasicWithUnfoldActions,
@@ -28,7 +29,7 @@ pub enum DefaultCanonizationProcess {
 
 impl DefaultCanonizationProcess {
 
-    pub fn get_phases(&self) -> Vec<InteractionTransformationPhase> {
+    pub fn get_phases(&self, get_all : bool) -> Vec<InteractionTransformationPhase> {
         match *self {
             DefaultCanonizationProcess::Basic => {
                 vec![InteractionTransformationPhase::new(
@@ -40,7 +41,9 @@ impl DefaultCanonizationProcess {
                         InteractionTransformationKind::InvertAlt,
                         InteractionTransformationKind::Deduplicate,
                         InteractionTransformationKind::LoopUnNest
-                    ]
+                    ],
+                    get_all,
+                    false
                 )]
             },
             DefaultCanonizationProcess::BasicWithUnfoldActions => {
@@ -54,7 +57,9 @@ impl DefaultCanonizationProcess {
                         InteractionTransformationKind::Deduplicate,
                         InteractionTransformationKind::LoopUnNest,
                         InteractionTransformationKind::UnfoldActions
-                    ]
+                    ],
+                    get_all,
+                    false
                 )]
             },
             DefaultCanonizationProcess::BasicWithToSeq => {
@@ -69,7 +74,9 @@ impl DefaultCanonizationProcess {
                         InteractionTransformationKind::LoopUnNest,
                         InteractionTransformationKind::StrictToSeq,
                         InteractionTransformationKind::ParToSeq
-                    ]
+                    ],
+                    get_all,
+                    false
                 )]
             },
             DefaultCanonizationProcess::FivePhases => {
@@ -91,13 +98,13 @@ impl DefaultCanonizationProcess {
                     InteractionTransformationKind::Deduplicate,
                 ];
                 vec![
-                    InteractionTransformationPhase::new(Self::simpl_phase()),
-                    InteractionTransformationPhase::new(defactorize),
-                    InteractionTransformationPhase::new(Self::simpl_phase()),
-                    InteractionTransformationPhase::new(factorize_suff),
-                    InteractionTransformationPhase::new(Self::simpl_phase()),
-                    InteractionTransformationPhase::new(factorize_pref),
-                    InteractionTransformationPhase::new(Self::simpl_phase())
+                    InteractionTransformationPhase::new(Self::simpl_phase(),get_all,false),
+                    InteractionTransformationPhase::new(defactorize,get_all,false),
+                    InteractionTransformationPhase::new(Self::simpl_phase(),get_all,false),
+                    InteractionTransformationPhase::new(factorize_suff,get_all,false),
+                    InteractionTransformationPhase::new(Self::simpl_phase(),get_all,false),
+                    InteractionTransformationPhase::new(factorize_pref,get_all,false),
+                    InteractionTransformationPhase::new(Self::simpl_phase(),get_all,false)
                 ]
             }
         }
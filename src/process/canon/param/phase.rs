@@ -20,24 +20,22 @@ use crate::core::transformation::transfophase::InteractionTransformationPhase;
 use crate::process::canon::param::default::DefaultCanonizationProcess;
 
 pub struct CanonizationParameterization {
-    pub phases : Vec<InteractionTransformationPhase>,
-    pub get_all : bool
+    pub phases : Vec<InteractionTransformationPhase>
 }
 
 impl CanonizationParameterization {
-    pub fn new(phases: Vec<InteractionTransformationPhase>, get_all: bool) -> Self {
-        Self { phases, get_all }
+    pub fn new(phases: Vec<InteractionTransformationPhase>) -> Self {
+        Self { phases }
     }
 
     pub fn from_default(def : DefaultCanonizationProcess, get_all: bool) -> Self {
-        Self{ phases : def.get_phases(), get_all}
+        Self{ phases : def.get_phases(get_all)}
     }
 }
 
 impl AbstractProcessParameterization for CanonizationParameterization {
     fn get_param_as_strings(&self) -> Vec<String> {
         let mut strs = vec!["process = canonization".to_string()];
-        strs.push(format!("get_all_transformations = {:}", self.get_all));
         for (x,phase) in self.phases.iter().enumerate() {
             strs.push(format!("phase {:} = {:}", x+1, phase));
         }
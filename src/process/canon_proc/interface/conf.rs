@@ -1,37 +0,0 @@
-/*
-Copyright 2020 Erwan Mahe (github.com/erwanM974)
-
-Licensed under the Apache License, Version 2.0 (the "License");
-you may not use this file except in compliance with the License.
-You may obtain a copy of the License at
-
-    http://www.apache.org/licenses/LICENSE-2.0
-
-Unless required by applicable law or agreed to in writing, software
-distributed under the License is distributed on an "AS IS" BASIS,
-WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-See the License for the specific language governing permissions and
-limitations under the License.
-*/
-
-
-
-
-
-use crate::process::abstract_proc::generic::AbstractConfiguration;
-use crate::process::canon_proc::interface::filter::{CanonizationFilter, CanonizationFilterCriterion};
-use crate::process::canon_proc::interface::logger::CanonizationLogger;
-use crate::process::canon_proc::interface::node::CanonizationNodeKind;
-use crate::process::canon_proc::interface::priorities::CanonizationPriorities;
-use crate::process::canon_proc::interface::step::CanonizationStepKind;
-
-pub struct CanonizationConfig {}
-
-impl AbstractConfiguration for CanonizationConfig {
-    type NodeKind = CanonizationNodeKind;
-    type StepKind = CanonizationStepKind;
-    type Priorities = CanonizationPriorities;
-    type Filter = CanonizationFilter;
-    type FilterCriterion = CanonizationFilterCriterion;
-    type Logger = Box<dyn CanonizationLogger>;
-}
\ No newline at end of file
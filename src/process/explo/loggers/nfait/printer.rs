@@ -66,8 +66,9 @@ const SYNTAX_NEGATION_HTML : &str = "&not;";
 
 impl AbstractLanguagePrinter<usize> for ActionNFAITPrinter {
 
-    fn is_letter_string_repr_atomic(&self, letter: &usize) -> bool {
-        false
+    fn is_letter_string_repr_atomic(&self, _letter: &usize) -> bool {
+        // letters are either single actions or sets of actions between braces
+        true
     }
 
     fn get_letter_string_repr(&self, letter: &usize) -> String {
@@ -15,4 +15,8 @@ limitations under the License.
 */
 
 pub mod slice;
-pub mod mutate;
\ No newline at end of file
+pub mod mutate;
+pub mod random_walk;
+pub mod test_suite;
+pub mod near_miss;
+pub mod uniform_sampling;
\ No newline at end of file
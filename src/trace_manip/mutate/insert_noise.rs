@@ -19,7 +19,8 @@ use std::collections::{BTreeSet, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use rand::Rng;
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
@@ -51,7 +52,8 @@ pub fn generate_insert_noise_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_inserting_noise(gen_ctx,co_localizations,multi_trace,max_num_inserts,one_per_compo_max,only_at_end);
+    let mut rng = StdRng::from_entropy();
+    let mutant_mt = mutate_by_inserting_noise(gen_ctx,co_localizations,multi_trace,max_num_inserts,one_per_compo_max,only_at_end,&mut rng);
     write_multi_trace_into_file(path.as_path(),
                                 gen_ctx,
                                 co_localizations,
@@ -60,7 +62,7 @@ pub fn generate_insert_noise_mutant(gen_ctx : &GeneralContext,
 }
 
 
-fn generate_random_action_on_coloc(rng : &mut ThreadRng,
+fn generate_random_action_on_coloc(rng : &mut StdRng,
                                    gen_ctx : &GeneralContext,
                                    coloc_lfs : &BTreeSet<usize>) -> TraceAction {
     let lf_id : usize;
@@ -85,12 +87,13 @@ fn generate_random_action_on_coloc(rng : &mut ThreadRng,
 }
 
 
-fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
-                               co_localizations : &CoLocalizations,
-                               multi_trace : &MultiTrace,
-                                   max_num_inserts : u32,
-                                   one_per_compo_max : bool,
-                                   only_at_end : bool) -> MultiTrace {
+pub fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
+                                 co_localizations : &CoLocalizations,
+                                 multi_trace : &MultiTrace,
+                                 max_num_inserts : u32,
+                                 one_per_compo_max : bool,
+                                 only_at_end : bool,
+                                 rng : &mut StdRng) -> MultiTrace {
     // ***
     let mut rem_num_inserts : u32;
     if one_per_compo_max {
@@ -99,22 +102,21 @@ fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
         rem_num_inserts = max_num_inserts;
     }
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut seen_colocs : HashSet<usize> = hashset!{};
     let mut mutated_mt = multi_trace.clone();
     while rem_num_inserts > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         if one_per_compo_max && seen_colocs.contains(&coloc_id) {
             // nothing
         } else {
             let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
-            let to_insert = generate_random_action_on_coloc(&mut rng,gen_ctx,co_localizations.get_coloc_lfs_ids(coloc_id));
+            let to_insert = generate_random_action_on_coloc(rng,gen_ctx,co_localizations.get_coloc_lfs_ids(coloc_id));
             if only_at_end {
                 component.push(btreeset!{to_insert});
             } else {
-                mutate_vector_by_insert(&mut rng,component,btreeset!{to_insert});
+                mutate_vector_by_insert(rng,component,btreeset!{to_insert});
             }
             rem_num_inserts -= 1;
         }
@@ -124,7 +126,7 @@ fn mutate_by_inserting_noise(gen_ctx : &GeneralContext,
 
 
 
-fn mutate_vector_by_insert<T>(rng : &mut ThreadRng, vector : &mut Vec<T>, to_insert : T) {
+fn mutate_vector_by_insert<T>(rng : &mut StdRng, vector : &mut Vec<T>, to_insert : T) {
     let vec_length = vector.len();
     if vec_length > 0 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
@@ -141,13 +143,15 @@ fn mutate_vector_by_insert<T>(rng : &mut ThreadRng, vector : &mut Vec<T>, to_ins
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use super::{mutate_vector_by_insert};
 
     #[test]
     fn mutate_insert_test() {
         let orig_vec : Vec<u32> = vec![0,1,2];
         let mut myvec : Vec<u32> = orig_vec.clone();
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         mutate_vector_by_insert(&mut rng, &mut myvec, 4);
         println!("{:?}", myvec);
         assert_ne!(myvec,orig_vec);
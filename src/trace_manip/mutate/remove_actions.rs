@@ -16,7 +16,8 @@ limitations under the License.
 
 
 use std::path::PathBuf;
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 
@@ -43,7 +44,8 @@ pub fn generate_remove_actions_mutant(gen_ctx : &GeneralContext,
         }
     };
     // ***
-    let mutant_mt = mutate_by_removing_actions(gen_ctx,co_localizations,multi_trace,max_num_removes);
+    let mut rng = StdRng::from_entropy();
+    let mutant_mt = mutate_by_removing_actions(gen_ctx,co_localizations,multi_trace,max_num_removes,&mut rng);
     write_multi_trace_into_file(path.as_path(),
                                 gen_ctx,
                                 co_localizations,
@@ -54,22 +56,22 @@ pub fn generate_remove_actions_mutant(gen_ctx : &GeneralContext,
 
 
 
-fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
-                             co_localizations : &CoLocalizations,
-                             multi_trace : &MultiTrace,
-                             max_num_removes : u32) -> MultiTrace {
+pub fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
+                                  co_localizations : &CoLocalizations,
+                                  multi_trace : &MultiTrace,
+                                  max_num_removes : u32,
+                                  rng : &mut StdRng) -> MultiTrace {
     // ***
     let mut rem_num_removes = max_num_removes;
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut mutated_mt = multi_trace.clone();
     while rem_num_removes > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         // ***
         let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
-        mutate_vector_by_removal(&mut rng,component);
+        mutate_vector_by_removal(rng,component);
         rem_num_removes -= 1;
     }
     // ***
@@ -78,7 +80,7 @@ fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
 
 
 
-fn mutate_vector_by_removal<T>(rng : &mut ThreadRng, vector : &mut Vec<T>) {
+fn mutate_vector_by_removal<T>(rng : &mut StdRng, vector : &mut Vec<T>) {
     let vec_length = vector.len();
     if vec_length > 0 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
@@ -93,13 +95,15 @@ fn mutate_vector_by_removal<T>(rng : &mut ThreadRng, vector : &mut Vec<T>) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use super::{mutate_vector_by_removal};
 
     #[test]
     fn mutate_remove_test() {
         let orig_vec : Vec<u32> = vec![0,1,2];
         let mut myvec : Vec<u32> = orig_vec.clone();
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         mutate_vector_by_removal(&mut rng, &mut myvec);
         println!("{:?}", myvec);
         assert_ne!(myvec,orig_vec);
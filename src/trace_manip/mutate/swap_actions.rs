@@ -19,7 +19,8 @@ limitations under the License.
 
 use std::path::PathBuf;
 use std::{fs, ptr};
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
@@ -49,7 +50,8 @@ pub fn generate_swap_actions_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_swapping_actions(multi_trace,max_num_swaps);
+    let mut rng = StdRng::from_entropy();
+    let mutant_mt = mutate_by_swapping_actions(multi_trace,max_num_swaps,&mut rng);
     write_multi_trace_into_file(path.as_path(),
                                 gen_ctx,
                                 co_localizations,
@@ -58,19 +60,18 @@ pub fn generate_swap_actions_mutant(gen_ctx : &GeneralContext,
 }
 
 
-fn mutate_by_swapping_actions(multi_trace : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
+pub fn mutate_by_swapping_actions(multi_trace : &MultiTrace, max_num_swaps : u32, rng : &mut StdRng) -> MultiTrace {
     // ***
     let mut rem_num_swaps = max_num_swaps.min(multi_trace_max_swap(multi_trace));
     // ***
-    let mut rng = rand::thread_rng();
     let rng_coloc_indices = Uniform::from(0..multi_trace.len() );
     // ***
     let mut mutated_mt = multi_trace.clone();
     while rem_num_swaps > 0 {
-        let coloc_id : usize = rng_coloc_indices.sample(&mut rng);
+        let coloc_id : usize = rng_coloc_indices.sample(rng);
         let component : &mut Trace = mutated_mt.get_mut(coloc_id).unwrap();
         // ***
-        if mutate_vector_by_swapping(&mut rng, component) {
+        if mutate_vector_by_swapping(rng, component) {
             rem_num_swaps -= 1;
         }
     }
@@ -88,7 +89,7 @@ fn multi_trace_max_swap(multi_trace : &MultiTrace) -> u32 {
 }
 
 
-fn mutate_vector_by_swapping<T>(rng : &mut ThreadRng,vector : &mut Vec<T>) -> bool {
+fn mutate_vector_by_swapping<T>(rng : &mut StdRng,vector : &mut Vec<T>) -> bool {
     let vec_length = vector.len();
     if vec_length > 1 {
         let mut vec_indices : Vec<usize> = (0..vec_length).collect();
@@ -111,6 +112,8 @@ fn vector_swap<T>(vector : &mut Vec<T>, a: usize, b: usize) {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use super::{vector_swap, mutate_vector_by_swapping};
 
     #[test]
@@ -125,7 +128,7 @@ mod tests {
     fn mutate_swap_test() {
         let orig_vec : Vec<u32> = vec![0,1,2,3];
         let mut myvec : Vec<u32> = orig_vec.clone();
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         mutate_vector_by_swapping(&mut rng, &mut myvec);
         println!("{:?}", myvec);
         assert_ne!(myvec,orig_vec);
@@ -18,7 +18,8 @@ limitations under the License.
 
 use std::fs;
 use std::path::PathBuf;
-use rand::rngs::ThreadRng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use rand::seq::SliceRandom;
 use rand::distributions::{Distribution, Uniform};
 use crate::core::colocalizations::CoLocalizations;
@@ -49,7 +50,8 @@ pub fn generate_swap_components_mutant(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let mutant_mt = mutate_by_swapping_components(mu1,mu2,max_num_swaps);
+    let mut rng = StdRng::from_entropy();
+    let mutant_mt = mutate_by_swapping_components(mu1,mu2,max_num_swaps,&mut rng);
     write_multi_trace_into_file(path.as_path(),
                                 gen_ctx,
                                 co_localizations,
@@ -58,12 +60,11 @@ pub fn generate_swap_components_mutant(gen_ctx : &GeneralContext,
 }
 
 
-fn mutate_by_swapping_components(multi_trace_1 : &MultiTrace, multi_trace_2 : &MultiTrace, max_num_swaps : u32) -> MultiTrace {
+pub fn mutate_by_swapping_components(multi_trace_1 : &MultiTrace, multi_trace_2 : &MultiTrace, max_num_swaps : u32, rng : &mut StdRng) -> MultiTrace {
     let num_compos = multi_trace_1.len() as u32;
     // ***
-    let mut rng = rand::thread_rng();
     let mut compos_indices : Vec<usize> = (0..(num_compos as usize)).collect();
-    compos_indices.shuffle(&mut rng);
+    compos_indices.shuffle(rng);
     // ***
     let mut rem_num_swaps = max_num_swaps.max(num_compos - 1);
     // ***
@@ -77,7 +78,7 @@ fn mutate_by_swapping_components(multi_trace_1 : &MultiTrace, multi_trace_2 : &M
 }
 
 
-fn mutate_merge_vectors_by_exchange<T : Clone>(rng : &mut ThreadRng, vec_1 : &mut Vec<T>, vec_2 : &Vec<T>) {
+fn mutate_merge_vectors_by_exchange<T : Clone>(rng : &mut StdRng, vec_1 : &mut Vec<T>, vec_2 : &Vec<T>) {
     assert_eq!(vec_1.len(),vec_2.len());
     let vec_length = vec_1.len();
     if vec_length >= 1 {
@@ -98,6 +99,8 @@ fn vectors_exchange<T : Clone>(vec_1 : &mut Vec<T>, vec_2 : &Vec<T>, index : usi
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
     use super::{vectors_exchange,mutate_merge_vectors_by_exchange};
 
     #[test]
@@ -114,7 +117,7 @@ mod tests {
         let mut vec_1 : Vec<u32> = vec![0,1,2];
         let vec_2 : Vec<u32> = vec![4,5,6];
         // ***
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(0);
         mutate_merge_vectors_by_exchange(&mut rng, &mut vec_1, &vec_2);
         // ***
         let expected : HashSet<Vec<u32>> = hashset!{vec![4,1,2],vec![0,5,2],vec![0,1,6]};
@@ -0,0 +1,171 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::mutate::insert_noise::mutate_by_inserting_noise;
+use crate::trace_manip::mutate::remove_actions::mutate_by_removing_actions;
+use crate::trace_manip::mutate::swap_actions::mutate_by_swapping_actions;
+use crate::trace_manip::mutate::swap_component::mutate_by_swapping_components;
+use crate::trace_manip::near_miss::mutation::{get_first_divergence, NearMissMutationKind};
+
+
+pub struct NearMissMutant {
+    // index of the original multi-trace in the corpus
+    pub source_id : usize,
+    pub kind : NearMissMutationKind,
+    pub multi_trace : MultiTrace,
+    // component and index of the first difference with the original multi-trace
+    pub divergence : (usize,usize)
+}
+
+fn mutate(gen_ctx : &GeneralContext,
+          co_localizations : &CoLocalizations,
+          multi_trace : &MultiTrace,
+          others : &Vec<&MultiTrace>,
+          kind : &NearMissMutationKind,
+          rng : &mut StdRng) -> Option<MultiTrace> {
+    match kind {
+        NearMissMutationKind::InsertNoise => {
+            Some(mutate_by_inserting_noise(gen_ctx,co_localizations,multi_trace,1,false,false,rng))
+        },
+        NearMissMutationKind::RemoveActions => {
+            Some(mutate_by_removing_actions(gen_ctx,co_localizations,multi_trace,1,rng))
+        },
+        NearMissMutationKind::SwapActions => {
+            Some(mutate_by_swapping_actions(multi_trace,1,rng))
+        },
+        NearMissMutationKind::SwapComponents => {
+            // components can only be exchanged between multi-traces having several components
+            if co_localizations.num_colocs() < 2 {
+                return None;
+            }
+            let other = others.choose(rng)?;
+            Some(mutate_by_swapping_components(multi_trace,other,1,rng))
+        }
+    }
+}
+
+/**
+ * Applies each mutation operator "num_tries" times to each multi-trace of the corpus,
+ * which are all expected to be accepted by the interaction and to be defined on the same co-localizations.
+ * Distinct mutants are analyzed with the options returned by "get_options"
+ * and only those which yield a "Fail" verdict are kept.
+ * Also returns the number of distinct mutants which were discarded because of another verdict.
+ * The mutations are drawn from "rng" so that the same seed yields the same mutants.
+ **/
+pub fn generate_near_miss_mutants(gen_ctx : &GeneralContext,
+                                  interaction : &Interaction,
+                                  co_localizations : &CoLocalizations,
+                                  corpus : &Vec<MultiTrace>,
+                                  kinds : &Vec<NearMissMutationKind>,
+                                  num_tries : u32,
+                                  get_options : &dyn Fn() -> HibouAnalyzeOptions,
+                                  rng : &mut StdRng) -> (Vec<NearMissMutant>,u32) {
+    let mut seen : HashSet<MultiTrace> = corpus.iter().cloned().collect();
+    let mut kept = vec![];
+    let mut discarded = 0;
+    for (source_id,multi_trace) in corpus.iter().enumerate() {
+        let others : Vec<&MultiTrace> = corpus.iter().enumerate()
+            .filter(|(other_id,_)| *other_id != source_id)
+            .map(|(_,other)| other)
+            .collect();
+        for kind in kinds {
+            for _ in 0..num_tries {
+                let mutant = match mutate(gen_ctx,co_localizations,multi_trace,&others,kind,rng) {
+                    None => {
+                        continue;
+                    },
+                    Some(got) => {
+                        got
+                    }
+                };
+                if seen.contains(&mutant) {
+                    continue;
+                }
+                seen.insert(mutant.clone());
+                let (_,verdict) = analyze_multi_trace_without_loggers(gen_ctx,interaction,co_localizations,&mutant,get_options());
+                if verdict == AnalysisGlobalVerdict::Fail {
+                    let divergence = get_first_divergence(multi_trace,&mutant).unwrap();
+                    kept.push(NearMissMutant{source_id,kind:*kind,multi_trace:mutant,divergence});
+                } else {
+                    discarded += 1;
+                }
+            }
+        }
+    }
+    return (kept,discarded);
+}
+
+
+
+#[cfg(test)]
+mod tests {
+
+    use rand::SeedableRng;
+
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::io::input::hif::interface::interaction_from_text;
+    use super::*;
+
+    #[test]
+    fn near_miss_mutants_test() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        let m2 = gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"alt(seq(l1 -- m1 ->|,l2 -- m2 ->|),seq(l1 -- m2 ->|,l2 -- m1 ->|))").unwrap();
+        let colocs = CoLocalizations::get_discrete_partition(2);
+        let act = |lf_id : usize, ms_id : usize| btreeset!{TraceAction::new(lf_id,TraceActionKind::Emission,ms_id)};
+        let corpus = vec![vec![vec![act(l1,m1)],vec![act(l2,m2)]],
+                          vec![vec![act(l1,m2)],vec![act(l2,m1)]]];
+        let generate = |seed : u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            generate_near_miss_mutants(&gen_ctx,&int,&colocs,&corpus,&NearMissMutationKind::all(),5,&HibouAnalyzeOptions::default,&mut rng)
+        };
+        let (mutants,_) = generate(0);
+        assert!(!mutants.is_empty());
+        for mutant in &mutants {
+            let (_,verdict) = analyze_multi_trace_without_loggers(&gen_ctx,&int,&colocs,&mutant.multi_trace,HibouAnalyzeOptions::default());
+            assert_eq!(verdict, AnalysisGlobalVerdict::Fail);
+            let original = corpus.get(mutant.source_id).unwrap();
+            assert_eq!(get_first_divergence(original,&mutant.multi_trace), Some(mutant.divergence));
+            let (coloc_id,index) = mutant.divergence;
+            assert_ne!(original[coloc_id].get(index), mutant.multi_trace[coloc_id].get(index));
+        }
+        // exchanging the first component of a trace with that of the other one yields a rejected mutant
+        assert!(mutants.iter().any(|m| m.kind == NearMissMutationKind::SwapComponents));
+        // the same seed yields the same mutants
+        let (again,_) = generate(0);
+        let as_tuples = |got : &Vec<NearMissMutant>| -> Vec<(usize,NearMissMutationKind,MultiTrace,(usize,usize))> {
+            got.iter().map(|m| (m.source_id,m.kind,m.multi_trace.clone(),m.divergence)).collect()
+        };
+        assert_eq!(as_tuples(&mutants), as_tuples(&again));
+    }
+
+}
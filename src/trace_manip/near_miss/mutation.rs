@@ -0,0 +1,94 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NearMissMutationKind {
+    InsertNoise,
+    RemoveActions,
+    SwapActions,
+    SwapComponents
+}
+
+impl NearMissMutationKind {
+    pub fn all() -> Vec<NearMissMutationKind> {
+        vec![NearMissMutationKind::InsertNoise,
+             NearMissMutationKind::RemoveActions,
+             NearMissMutationKind::SwapActions,
+             NearMissMutationKind::SwapComponents]
+    }
+}
+
+impl fmt::Display for NearMissMutationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NearMissMutationKind::InsertNoise => {
+                write!(f,"insert_noise")
+            },
+            NearMissMutationKind::RemoveActions => {
+                write!(f,"remove_actions")
+            },
+            NearMissMutationKind::SwapActions => {
+                write!(f,"swap_actions")
+            },
+            NearMissMutationKind::SwapComponents => {
+                write!(f,"swap_components")
+            }
+        }
+    }
+}
+
+/**
+ * Returns the first component in which the mutant differs from the original multi-trace
+ * and the index of the first differing element in this component.
+ **/
+pub fn get_first_divergence(original : &MultiTrace,
+                            mutant : &MultiTrace) -> Option<(usize,usize)> {
+    for (coloc_id,(orig_trace,mut_trace)) in original.iter().zip(mutant.iter()).enumerate() {
+        if orig_trace != mut_trace {
+            let mut index = 0;
+            while index < orig_trace.len() && index < mut_trace.len() && orig_trace[index] == mut_trace[index] {
+                index += 1;
+            }
+            return Some((coloc_id,index));
+        }
+    }
+    return None;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use super::get_first_divergence;
+
+    #[test]
+    fn first_divergence_test() {
+        let act = |ms_id : usize| -> BTreeSet<TraceAction> {btreeset!{TraceAction::new(0,TraceActionKind::Emission,ms_id)}};
+        let original = vec![vec![act(0),act(1)],vec![act(0),act(1),act(2)]];
+        assert_eq!(get_first_divergence(&original,&original),None);
+        let swapped = vec![vec![act(0),act(1)],vec![act(0),act(2),act(1)]];
+        assert_eq!(get_first_divergence(&original,&swapped),Some((1,1)));
+        let removed = vec![vec![act(0)],vec![act(0),act(1),act(2)]];
+        assert_eq!(get_first_divergence(&original,&removed),Some((0,1)));
+    }
+}
@@ -0,0 +1,68 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::PathBuf;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::trace_manip::random_walk::walk::random_walk;
+use crate::trace_manip::random_walk::weights::RandomWalkWeights;
+
+
+/**
+ * Performs "num_walks" random walks from a generator seeded with "seed"
+ * and writes the resulting multi-traces into "dir_name".
+ * If "only_accepted" is set, walks which do not end on an interaction expressing the empty trace are discarded.
+ * Returns the number of written and discarded multi-traces.
+ **/
+pub fn generate_random_walks(gen_ctx : &GeneralContext,
+                             interaction : &Interaction,
+                             co_localizations : &CoLocalizations,
+                             weights : &RandomWalkWeights,
+                             num_walks : u32,
+                             max_length : u32,
+                             seed : u64,
+                             only_accepted : bool,
+                             dir_name : &str,
+                             file_name_prefix : &str) -> Result<(u32,u32),String> {
+    fs::create_dir_all(dir_name).unwrap();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut written = 0;
+    let mut discarded = 0;
+    for walk_id in 0..num_walks {
+        let result = random_walk(interaction,co_localizations,weights,max_length,&mut rng)?;
+        if only_accepted && !result.accepted {
+            discarded += 1;
+        } else {
+            let file_name = format!("{:}{:}.{:}", file_name_prefix, walk_id, HIBOU_TRACE_FILE_EXTENSION);
+            let path : PathBuf = [dir_name, &file_name].iter().collect();
+            write_multi_trace_into_file(path.as_path(),
+                                        gen_ctx,
+                                        co_localizations,
+                                        &result.multi_trace);
+            written += 1;
+        }
+    }
+    Ok((written,discarded))
+}
@@ -0,0 +1,204 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::{FrontierElement, global_frontier};
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace, trace_as_multi_trace};
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::trace_manip::random_walk::weights::RandomWalkWeights;
+
+
+pub struct RandomWalkResult {
+    pub multi_trace : MultiTrace,
+    // whether or not the interaction reached at the end of the walk expresses the empty trace
+    pub accepted : bool
+}
+
+
+fn frontier_element_weight(interaction : &Interaction,
+                           position : &Position,
+                           weights : &RandomWalkWeights) -> f32 {
+    match (interaction,position) {
+        (Interaction::Emission(_), Position::Epsilon(_)) => {
+            weights.emission
+        },
+        (Interaction::Reception(_), Position::Epsilon(_)) => {
+            weights.reception
+        },
+        (Interaction::Alt(i1,_), Position::Left(p1)) => {
+            weights.alt_left * frontier_element_weight(i1,p1,weights)
+        },
+        (Interaction::Alt(_,i2), Position::Right(p2)) => {
+            weights.alt_right * frontier_element_weight(i2,p2,weights)
+        },
+        (Interaction::Loop(_,i1), Position::Left(p1)) => {
+            weights.loop_continue * frontier_element_weight(i1,p1,weights)
+        },
+        (Interaction::Sync(_,i1,i2), Position::Both(p1,p2)) => {
+            // a synchronization is as likely as the likeliest of its sides
+            frontier_element_weight(i1,p1,weights).max(frontier_element_weight(i2,p2,weights))
+        },
+        (Interaction::Strict(i1,_), Position::Left(p1))
+        | (Interaction::Seq(i1,_), Position::Left(p1))
+        | (Interaction::CoReg(_,i1,_), Position::Left(p1))
+        | (Interaction::Par(i1,_), Position::Left(p1))
+        | (Interaction::Sync(_,i1,_), Position::Left(p1)) => {
+            frontier_element_weight(i1,p1,weights)
+        },
+        (Interaction::Strict(_,i2), Position::Right(p2))
+        | (Interaction::Seq(_,i2), Position::Right(p2))
+        | (Interaction::CoReg(_,_,i2), Position::Right(p2))
+        | (Interaction::Par(_,i2), Position::Right(p2))
+        | (Interaction::Sync(_,_,i2), Position::Right(p2)) => {
+            frontier_element_weight(i2,p2,weights)
+        },
+        _ => {
+            panic!("non-conform position {:?} in interaction", position);
+        }
+    }
+}
+
+
+/**
+ * Performs a single random walk over the operational semantics of the interaction.
+ * At each step, either a frontier element is executed or, if the current interaction expresses
+ * the empty trace, the walk is stopped, with probabilities proportional to the given weights.
+ * The walk is also stopped after "max_length" execution steps or if all weights are null.
+ * The operational semantics is not defined for the "and" operator, so that interactions
+ * containing it must have their gates merged beforehand.
+ **/
+pub fn random_walk(interaction : &Interaction,
+                   co_localizations : &CoLocalizations,
+                   weights : &RandomWalkWeights,
+                   max_length : u32,
+                   rng : &mut StdRng) -> Result<RandomWalkResult,String> {
+    if interaction.get_characteristics().has_ands {
+        return Err("random walks do not apply to interactions with 'and' operators".to_string());
+    }
+    let mut trace : Trace = vec![];
+    let mut current = interaction.clone();
+    let mut length = 0;
+    loop {
+        // "None" stands for stopping the walk
+        let mut choices : Vec<Option<FrontierElement>> = vec![];
+        let mut choices_weights : Vec<f32> = vec![];
+        if current.express_empty() {
+            choices.push(None);
+            choices_weights.push(weights.stop);
+        }
+        if length < max_length {
+            // non-delayed alternatives so that each frontier element belongs to a single branch
+            for frt_elt in global_frontier(&current,false) {
+                choices_weights.push(frontier_element_weight(&current,&frt_elt.position,weights));
+                choices.push(Some(frt_elt));
+            }
+        }
+        let chosen = match WeightedIndex::new(&choices_weights) {
+            Err(_) => {
+                // no choices or only null weights
+                None
+            },
+            Ok(distribution) => {
+                choices.swap_remove(distribution.sample(rng))
+            }
+        };
+        match chosen {
+            None => {
+                break;
+            },
+            Some(frt_elt) => {
+                trace.push(frt_elt.target_actions.clone());
+                current = execute_interaction(&current,
+                                              &frt_elt.position,
+                                              &frt_elt.target_lf_ids,
+                                              false).interaction;
+                length += 1;
+            }
+        }
+    }
+    let accepted = current.express_empty();
+    Ok(RandomWalkResult{multi_trace:trace_as_multi_trace(&trace,co_localizations),accepted})
+}
+
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::core::general_context::GeneralContext;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::io::input::hif::interface::interaction_from_text;
+    use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+    use crate::process::ana::param::anakind::AnalysisKind;
+    use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+    use super::*;
+
+    fn walk_from_seed(interaction : &Interaction,
+                      co_localizations : &CoLocalizations,
+                      seed : u64) -> Vec<RandomWalkResult> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..30).map(|_| random_walk(interaction,co_localizations,&RandomWalkWeights::default(),12,&mut rng).unwrap()).collect()
+    }
+
+    #[test]
+    fn seeded_random_walks_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_lf("l3".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        gen_ctx.add_msg("m2".to_string());
+        let int = interaction_from_text(&gen_ctx,"seq(alt(l1 -- m1 -> l2,l1 -- m2 ->|),loopS(par(l2 -- m1 -> l1,l3 -- m2 ->|)))").unwrap();
+        let co_localizations = CoLocalizations::get_discrete_partition(3);
+        // ***
+        let first = walk_from_seed(&int,&co_localizations,42);
+        let second = walk_from_seed(&int,&co_localizations,42);
+        assert_eq!(first.iter().map(|r| &r.multi_trace).collect::<Vec<&MultiTrace>>(),
+                   second.iter().map(|r| &r.multi_trace).collect::<Vec<&MultiTrace>>());
+        // ***
+        let mut num_accepted = 0;
+        for result in &first {
+            if result.accepted {
+                let mut ana_opts = HibouAnalyzeOptions::default();
+                ana_opts.ana_param.ana_kind = AnalysisKind::Accept;
+                ana_opts.goal = Some(AnalysisGlobalVerdict::Pass);
+                let (_,verdict) = analyze_multi_trace_without_loggers(&gen_ctx,&int,&co_localizations,&result.multi_trace,ana_opts);
+                assert_eq!(verdict,AnalysisGlobalVerdict::Pass);
+                num_accepted += 1;
+            }
+        }
+        assert!(num_accepted > 0);
+    }
+
+    #[test]
+    fn random_walk_with_and_test() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("l1".to_string());
+        gen_ctx.add_lf("l2".to_string());
+        gen_ctx.add_msg("m1".to_string());
+        let int = interaction_from_text(&gen_ctx,"and(l1 -- m1 ->|,l2 -- m1 ->|)").unwrap();
+        let co_localizations = CoLocalizations::get_discrete_partition(2);
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(random_walk(&int,&co_localizations,&RandomWalkWeights::default(),12,&mut rng).is_err());
+    }
+
+}
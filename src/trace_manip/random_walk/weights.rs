@@ -0,0 +1,85 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum RandomWalkWeightsError {
+    NegativeWeight(String)
+}
+
+/**
+ * Relative weights with which the frontier elements of an interaction are selected during a random walk.
+ * - "emission" and "reception" weight executing an action according to its kind
+ * - "loop" weights starting a new instance of a loop
+ * - "alt_left" and "alt_right" weight executing an action in the left or right branch of an alternative
+ * - "stop" weights terminating the walk when the interaction expresses the empty trace
+ * Weights along the path from the root of the interaction to the action are multiplied.
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub struct RandomWalkWeights {
+    pub emission : f32,
+    pub reception : f32,
+    pub loop_continue : f32,
+    pub alt_left : f32,
+    pub alt_right : f32,
+    pub stop : f32
+}
+
+impl RandomWalkWeights {
+
+    pub fn new(emission: f32,
+               reception: f32,
+               loop_continue: f32,
+               alt_left: f32,
+               alt_right: f32,
+               stop: f32) -> Result<RandomWalkWeights,RandomWalkWeightsError> {
+        for (name,weight) in [("emission",emission),
+                              ("reception",reception),
+                              ("loop",loop_continue),
+                              ("alt_left",alt_left),
+                              ("alt_right",alt_right),
+                              ("stop",stop)] {
+            if weight < 0.0 {
+                return Err(RandomWalkWeightsError::NegativeWeight(name.to_string()));
+            }
+        }
+        Ok(RandomWalkWeights{emission,reception,loop_continue,alt_left,alt_right,stop})
+    }
+
+    pub fn default() -> RandomWalkWeights {
+        RandomWalkWeights{emission:1.0,reception:1.0,loop_continue:1.0,alt_left:1.0,alt_right:1.0,stop:1.0}
+    }
+
+}
+
+
+impl std::fmt::Display for RandomWalkWeightsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RandomWalkWeightsError::NegativeWeight(name) => {
+                write!(f, "random walk weight '{}' must not be negative", name)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for RandomWalkWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[emission={},reception={},loop={},alt_left={},alt_right={},stop={}]",
+               self.emission, self.reception, self.loop_continue, self.alt_left, self.alt_right, self.stop)
+    }
+}
@@ -0,0 +1,298 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::core::error::HibouCoreError;
+use crate::core::execution::interpretation::interpretation::InteractionInterpretation;
+use crate::core::execution::interpretation::origin::{InteractionOrigins, relabel_interaction_actions};
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::trace::multitrace::Trace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::nfa_translation::multitrace_ana::nfa::get_nfa_and_states_from_interaction_terms;
+use crate::trace_manip::test_suite::criterion::{TestCandidate, TestCoverageCriterion, TestCoverageItem, TestSuiteProblem};
+
+
+fn get_interpretation_items(criterion : &TestCoverageCriterion,
+                            origins : &InteractionOrigins) -> Vec<TestCoverageItem> {
+    let mut items = vec![];
+    match criterion {
+        TestCoverageCriterion::Actions => {
+            for origin in &origins.actions {
+                items.push(TestCoverageItem::Action(origin.position.clone(),origin.ms_id));
+            }
+        },
+        TestCoverageCriterion::AltBranches => {
+            for alt_pos in &origins.alts {
+                items.push(TestCoverageItem::AltBranch(alt_pos.clone(),true));
+                items.push(TestCoverageItem::AltBranch(alt_pos.clone(),false));
+            }
+        },
+        TestCoverageCriterion::Loops => {
+            for loop_pos in &origins.loops {
+                for num_instances in 0..3 {
+                    items.push(TestCoverageItem::LoopInstances(loop_pos.clone(),num_instances));
+                }
+            }
+        },
+        TestCoverageCriterion::NfaTransitions => {
+            panic!("NFA transitions are not obtained from interpretations");
+        }
+    }
+    return items;
+}
+
+// the items are ordered as in "get_interpretation_items"
+fn get_covered_items(criterion : &TestCoverageCriterion,
+                     interpretation : &InteractionInterpretation) -> BTreeSet<usize> {
+    let mut covered = BTreeSet::new();
+    match criterion {
+        TestCoverageCriterion::Actions => {
+            for (act_id,(_,num_executions)) in interpretation.actions.iter().enumerate() {
+                if *num_executions > 0 {
+                    covered.insert(act_id);
+                }
+            }
+        },
+        TestCoverageCriterion::AltBranches => {
+            for (alt_id,(_,num_left,num_right)) in interpretation.alts.iter().enumerate() {
+                if *num_left > 0 {
+                    covered.insert(2*alt_id);
+                }
+                if *num_right > 0 {
+                    covered.insert(2*alt_id + 1);
+                }
+            }
+        },
+        TestCoverageCriterion::Loops => {
+            for (loop_id,(_,num_instances)) in interpretation.loops.iter().enumerate() {
+                if *num_instances < 3 {
+                    covered.insert(3*loop_id + (*num_instances as usize));
+                }
+            }
+        },
+        TestCoverageCriterion::NfaTransitions => {
+            panic!("NFA transitions are not obtained from interpretations");
+        }
+    }
+    return covered;
+}
+
+// the part of the interpretation which is relevant for the criterion, with counts bounded so that it takes finitely many values
+fn get_interpretation_signature(criterion : &TestCoverageCriterion,
+                                interpretation : &InteractionInterpretation) -> Vec<u32> {
+    match criterion {
+        TestCoverageCriterion::Actions => {
+            interpretation.actions.iter().map(|(_,num)| (*num).min(1)).collect()
+        },
+        TestCoverageCriterion::AltBranches => {
+            interpretation.alts.iter().flat_map(|(_,num_left,num_right)| vec![(*num_left).min(1),(*num_right).min(1)]).collect()
+        },
+        TestCoverageCriterion::Loops => {
+            interpretation.loops.iter().map(|(_,num)| (*num).min(3)).collect()
+        },
+        TestCoverageCriterion::NfaTransitions => {
+            panic!("NFA transitions are not obtained from interpretations");
+        }
+    }
+}
+
+fn get_path_trace(nodes : &Vec<(Option<usize>,BTreeSet<TraceAction>)>,
+                  node_id : usize) -> Trace {
+    let mut trace = vec![];
+    let mut current = node_id;
+    while let (Some(parent_id),ref actions) = nodes.get(current).unwrap() {
+        trace.push(actions.clone());
+        current = *parent_id;
+    }
+    trace.reverse();
+    return trace;
+}
+
+/**
+ * Explores the semantics of the interaction breadth first, without delaying the choice of alternatives,
+ * so as to collect, for each distinct set of covered criterion items, a shortest accepted trace covering it.
+ * Two paths reaching the same term with the same relevant part of their interpretation are not distinguished.
+ * Traces are limited to "max_length" actions and at most "max_states_num" states are explored.
+ **/
+pub fn get_interpretation_test_candidates(interaction : &Interaction,
+                                          criterion : TestCoverageCriterion,
+                                          max_length : u32,
+                                          max_states_num : usize) -> Result<TestSuiteProblem,HibouCoreError> {
+    let origins = relabel_interaction_actions(interaction)?;
+    let items = get_interpretation_items(&criterion,&origins);
+    let mut candidates = vec![];
+    let mut candidates_items : HashSet<BTreeSet<usize>> = HashSet::new();
+    let mut is_complete = true;
+    // parent node and actions executed to reach each node
+    let mut nodes : Vec<(Option<usize>,BTreeSet<TraceAction>)> = vec![(None,BTreeSet::new())];
+    let initial_interpretation = InteractionInterpretation::new_from_origins(&origins);
+    let mut visited : HashSet<(Interaction,Vec<u32>)> = HashSet::new();
+    visited.insert((origins.relabelled.clone(),get_interpretation_signature(&criterion,&initial_interpretation)));
+    let mut queue : VecDeque<(usize,Interaction,InteractionInterpretation,u32)> = VecDeque::new();
+    queue.push_back((0,origins.relabelled.clone(),initial_interpretation,0));
+    while let Some((node_id,relabelled,interpretation,length)) = queue.pop_front() {
+        if relabelled.express_empty() {
+            let covered_items = get_covered_items(&criterion,&interpretation);
+            if !covered_items.is_empty() && !candidates_items.contains(&covered_items) {
+                candidates_items.insert(covered_items.clone());
+                candidates.push(TestCandidate{trace:get_path_trace(&nodes,node_id),covered_items});
+            }
+        }
+        if length >= max_length {
+            continue;
+        }
+        for frt_elt in global_frontier(&relabelled,false) {
+            let mut new_interpretation = interpretation.clone();
+            new_interpretation.record_execution(&origins,&relabelled,&frt_elt.position);
+            let new_relabelled = execute_interaction(&relabelled,&frt_elt.position,&frt_elt.target_lf_ids,false).interaction;
+            let key = (new_relabelled.clone(),get_interpretation_signature(&criterion,&new_interpretation));
+            if visited.contains(&key) {
+                continue;
+            }
+            if visited.len() >= max_states_num {
+                is_complete = false;
+                continue;
+            }
+            visited.insert(key);
+            // the message ids of the relabelled interaction are those of the origins of the actions
+            let actions : BTreeSet<TraceAction> = frt_elt.target_actions.iter()
+                .map(|act| TraceAction::new(act.lf_id,act.act_kind.clone(),origins.actions.get(act.ms_id).unwrap().ms_id))
+                .collect();
+            nodes.push((Some(node_id),actions));
+            queue.push_back((nodes.len() - 1,new_relabelled,new_interpretation,length + 1));
+        }
+    }
+    return Ok(TestSuiteProblem{criterion,items,candidates,is_complete});
+}
+
+
+// shortest paths as (previous state, letter) from the initial state, computed breadth first
+fn get_forward_paths(transitions : &Vec<Vec<(usize,usize)>>,
+                     initial : usize) -> Vec<Option<(usize,usize)>> {
+    let mut previous : Vec<Option<(usize,usize)>> = vec![None;transitions.len()];
+    let mut reached : Vec<bool> = vec![false;transitions.len()];
+    reached[initial] = true;
+    let mut queue = VecDeque::from(vec![initial]);
+    while let Some(state) = queue.pop_front() {
+        for (letter,target) in transitions.get(state).unwrap() {
+            if !reached[*target] {
+                reached[*target] = true;
+                previous[*target] = Some((state,*letter));
+                queue.push_back(*target);
+            }
+        }
+    }
+    return previous;
+}
+
+// shortest paths as (letter, next state) towards a final state, computed breadth first on the reversed transitions
+fn get_backward_paths(transitions : &Vec<Vec<(usize,usize)>>,
+                      finals : &BTreeSet<usize>) -> (Vec<Option<(usize,usize)>>,Vec<bool>) {
+    let mut reversed : Vec<Vec<(usize,usize)>> = vec![vec![];transitions.len()];
+    for (origin,outgoing) in transitions.iter().enumerate() {
+        for (letter,target) in outgoing {
+            reversed[*target].push((*letter,origin));
+        }
+    }
+    let mut next : Vec<Option<(usize,usize)>> = vec![None;transitions.len()];
+    let mut reached : Vec<bool> = vec![false;transitions.len()];
+    let mut queue = VecDeque::new();
+    for final_state in finals {
+        reached[*final_state] = true;
+        queue.push_back(*final_state);
+    }
+    while let Some(state) = queue.pop_front() {
+        for (letter,origin) in reversed.get(state).unwrap() {
+            if !reached[*origin] {
+                reached[*origin] = true;
+                next[*origin] = Some((*letter,state));
+                queue.push_back(*origin);
+            }
+        }
+    }
+    return (next,reached);
+}
+
+/**
+ * Builds the NFA whose states are the interaction terms reachable from the interaction
+ * and, for each of its transitions that can be followed by an accepted suffix,
+ * the accepted word made of a shortest path to its origin, the transition and a shortest path from its target to a final state.
+ **/
+pub fn get_nfa_test_candidates(interaction : &Interaction,
+                               alphabet : &Vec<BTreeSet<TraceAction>>,
+                               max_states_num : usize) -> TestSuiteProblem {
+    let (nfa,_,is_complete) = get_nfa_and_states_from_interaction_terms(interaction,alphabet,max_states_num);
+    // sorted transitions so that the items do not depend on the iteration order of hash maps
+    let mut transitions : Vec<Vec<(usize,usize)>> = vec![];
+    let mut items = vec![];
+    let mut items_ids : HashMap<(usize,usize,usize),usize> = HashMap::new();
+    for (origin,outgoing) in nfa.transitions.iter().enumerate() {
+        let mut sorted = vec![];
+        for (letter,targets) in outgoing {
+            for target in targets {
+                sorted.push((*letter,*target));
+            }
+        }
+        sorted.sort();
+        for (letter,target) in &sorted {
+            items_ids.insert((origin,*letter,*target),items.len());
+            items.push(TestCoverageItem::NfaTransition(origin,alphabet.get(*letter).unwrap().clone(),*target));
+        }
+        transitions.push(sorted);
+    }
+    let finals : BTreeSet<usize> = nfa.finals.iter().cloned().collect();
+    let previous = get_forward_paths(&transitions,0);
+    let (next,co_reachable) = get_backward_paths(&transitions,&finals);
+    // ***
+    let mut candidates = vec![];
+    let mut candidates_words : HashSet<Vec<usize>> = HashSet::new();
+    for (origin,outgoing) in transitions.iter().enumerate() {
+        for (letter,target) in outgoing {
+            if !co_reachable[*target] {
+                continue;
+            }
+            // states along the path and letters
+            let mut path : Vec<(usize,usize,usize)> = vec![];
+            let mut current = origin;
+            while let Some((prev_state,prev_letter)) = previous[current] {
+                path.push((prev_state,prev_letter,current));
+                current = prev_state;
+            }
+            path.reverse();
+            path.push((origin,*letter,*target));
+            let mut current = *target;
+            while let Some((next_letter,next_state)) = next[current] {
+                path.push((current,next_letter,next_state));
+                current = next_state;
+            }
+            let word : Vec<usize> = path.iter().map(|(_,l,_)| *l).collect();
+            if candidates_words.contains(&word) {
+                continue;
+            }
+            candidates_words.insert(word);
+            let covered_items : BTreeSet<usize> = path.iter()
+                .map(|transition| *items_ids.get(transition).unwrap())
+                .collect();
+            let trace : Trace = path.iter().map(|(_,l,_)| alphabet.get(*l).unwrap().clone()).collect();
+            candidates.push(TestCandidate{trace,covered_items});
+        }
+    }
+    return TestSuiteProblem{criterion:TestCoverageCriterion::NfaTransitions,items,candidates,is_complete};
+}
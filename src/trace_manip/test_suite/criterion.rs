@@ -0,0 +1,84 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::core::execution::trace::multitrace::Trace;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::position::position::Position;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TestCoverageCriterion {
+    // every action of the interaction is executed
+    Actions,
+    // both branches of every alternative are taken
+    AltBranches,
+    // every loop is instantiated zero, one and two times
+    Loops,
+    // every transition of the NFA whose states are the reachable interaction terms is taken
+    NfaTransitions
+}
+
+impl fmt::Display for TestCoverageCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TestCoverageCriterion::Actions => {
+                write!(f,"actions")
+            },
+            TestCoverageCriterion::AltBranches => {
+                write!(f,"alt_branches")
+            },
+            TestCoverageCriterion::Loops => {
+                write!(f,"loops")
+            },
+            TestCoverageCriterion::NfaTransitions => {
+                write!(f,"nfa_transitions")
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TestCoverageItem {
+    // position of the action in the interaction and its message id
+    Action(Position,usize),
+    // position of the alternative and whether or not it is its left branch
+    AltBranch(Position,bool),
+    // position of the loop and its number of instances
+    LoopInstances(Position,u32),
+    // origin state, actions and target state
+    NfaTransition(usize,BTreeSet<TraceAction>,usize)
+}
+
+
+// an accepted trace and the indices of the criterion items it covers
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TestCandidate {
+    pub trace : Trace,
+    pub covered_items : BTreeSet<usize>
+}
+
+pub struct TestSuiteProblem {
+    pub criterion : TestCoverageCriterion,
+    pub items : Vec<TestCoverageItem>,
+    pub candidates : Vec<TestCandidate>,
+    // false if the search for candidates was stopped before exploring all the reachable states
+    pub is_complete : bool
+}
@@ -0,0 +1,86 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+
+use crate::trace_manip::test_suite::criterion::TestSuiteProblem;
+
+
+/**
+ * Greedily selects candidates until no more item can be covered:
+ * each time, the candidate covering the most uncovered items is chosen, the shortest one in case of ties.
+ * This yields a small, though not necessarily minimum, test suite.
+ * Returns the indices of the selected candidates.
+ **/
+pub fn select_test_suite(problem : &TestSuiteProblem) -> Vec<usize> {
+    let mut covered : BTreeSet<usize> = BTreeSet::new();
+    let mut selected = vec![];
+    // the number of new items covered by a candidate can only decrease as items get covered
+    // so that the gains stored in the heap are upper bounds which are refreshed lazily
+    let mut heap : BinaryHeap<(usize,Reverse<usize>,Reverse<usize>)> = problem.candidates.iter().enumerate()
+        .map(|(cand_id,candidate)| (candidate.covered_items.len(),Reverse(candidate.trace.len()),Reverse(cand_id)))
+        .collect();
+    while let Some((_,Reverse(trace_len),Reverse(cand_id))) = heap.pop() {
+        let candidate = problem.candidates.get(cand_id).unwrap();
+        let gain = candidate.covered_items.difference(&covered).count();
+        if gain == 0 {
+            continue;
+        }
+        let is_best = match heap.peek() {
+            None => {
+                true
+            },
+            Some(next) => {
+                (gain,Reverse(trace_len),Reverse(cand_id)) >= *next
+            }
+        };
+        if is_best {
+            covered.extend(candidate.covered_items.iter().cloned());
+            selected.push(cand_id);
+        } else {
+            heap.push((gain,Reverse(trace_len),Reverse(cand_id)));
+        }
+    }
+    return selected;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use crate::trace_manip::test_suite::criterion::{TestCandidate, TestCoverageCriterion, TestSuiteProblem};
+    use super::select_test_suite;
+
+    fn candidate(length : usize, covered_items : Vec<usize>) -> TestCandidate {
+        TestCandidate{trace:vec![BTreeSet::new();length],covered_items:covered_items.into_iter().collect()}
+    }
+
+    #[test]
+    fn greedy_selection_test() {
+        let problem = TestSuiteProblem{criterion:TestCoverageCriterion::Actions,
+            items:vec![],
+            candidates:vec![candidate(3,vec![0,1]),
+                            candidate(5,vec![0,1,2]),
+                            candidate(2,vec![3]),
+                            candidate(1,vec![3]),
+                            candidate(4,vec![1,2])],
+            is_complete:true};
+        // the largest candidate first then the shortest one among those covering the remaining item
+        assert_eq!(select_test_suite(&problem),vec![1,3]);
+    }
+}
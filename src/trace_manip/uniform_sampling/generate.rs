@@ -0,0 +1,72 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{trace_as_multi_trace, Trace};
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::nfa_translation::count::counter::AcceptedTracesCounter;
+use crate::nfa_translation::count::sample::sample_accepted_word;
+
+
+/**
+ * Draws "num_traces" global traces of length "length" uniformly at random among those accepted by the DFA of the counter,
+ * from a generator seeded with "seed", and writes them into "dir_name" as multi-traces w.r.t. "co_localizations".
+ * Traces are drawn independently so that the same trace may be drawn several times.
+ * Returns the number of written multi-traces, which is zero if no trace of that length is accepted.
+ **/
+pub fn generate_uniform_traces(gen_ctx : &GeneralContext,
+                               counter : &mut AcceptedTracesCounter,
+                               alphabet : &Vec<BTreeSet<TraceAction>>,
+                               co_localizations : &CoLocalizations,
+                               length : usize,
+                               num_traces : u32,
+                               seed : u64,
+                               dir_name : &str,
+                               file_name_prefix : &str) -> u32 {
+    fs::create_dir_all(dir_name).unwrap();
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut written = 0;
+    for trace_id in 0..num_traces {
+        match sample_accepted_word(counter,length,&mut rng) {
+            None => {
+                break;
+            },
+            Some(word) => {
+                let trace : Trace = word.iter().map(|letter| alphabet.get(*letter).unwrap().clone()).collect();
+                let file_name = format!("{:}{:}.{:}", file_name_prefix, trace_id, HIBOU_TRACE_FILE_EXTENSION);
+                let path : PathBuf = [dir_name, &file_name].iter().collect();
+                write_multi_trace_into_file(path.as_path(),
+                                            gen_ctx,
+                                            co_localizations,
+                                            &trace_as_multi_trace(&trace,co_localizations));
+                written += 1;
+            }
+        }
+    }
+    written
+}
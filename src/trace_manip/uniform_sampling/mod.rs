@@ -16,7 +16,4 @@ limitations under the License.
 
 
 
-pub mod phases;
-
-
-
+pub mod generate;
@@ -0,0 +1,163 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::experiments::ana_strategies::{AnalysisStrategyMetrics, benchmark_analysis_strategy};
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::core::general_context::GeneralContext;
+
+
+fn get_ana_options(gen_ctx : &GeneralContext,
+                   hcf_file_path : Option<&str>,
+                   strategy : QueueSearchStrategy,
+                   heuristic_guidance : bool) -> HibouAnalyzeOptions {
+    let mut ana_opts = match hcf_file_path {
+        None => {
+            HibouAnalyzeOptions::default()
+        },
+        Some( hcf_path ) => {
+            parse_hcf_file_for_ana(gen_ctx,hcf_path).unwrap()
+        }
+    };
+    ana_opts.strategy = strategy;
+    ana_opts.ana_param.heuristic_guidance = heuristic_guidance;
+    ana_opts
+}
+
+pub fn cli_ana_benchmark(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let hcf_file_path = matches.value_of("hcf");
+                    if let Some(hcf_path) = hcf_file_path {
+                        if let Err(e) = parse_hcf_file_for_ana(&gen_ctx,hcf_path) {
+                            return (vec![e.to_string()],1);
+                        }
+                    }
+                    let num_tries : u32 = match matches.value_of("num_tries") {
+                        None => {
+                            3
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    // ***
+                    let traces_folder = matches.value_of("traces").unwrap();
+                    let mut htf_file_paths : Vec<PathBuf> = vec![];
+                    match fs::read_dir(traces_folder) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( entries ) => {
+                            for entry in entries {
+                                let path = entry.unwrap().path();
+                                if path.extension().map_or(false, |ext| ext == HIBOU_TRACE_FILE_EXTENSION) {
+                                    htf_file_paths.push(path);
+                                }
+                            }
+                        }
+                    }
+                    htf_file_paths.sort();
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "BENCHMARKING GREEDY DFS AGAINST HCS".to_string());
+                    ret_print.push( format!("for the analysis of the {:} traces from folder '{}'", htf_file_paths.len(), traces_folder) );
+                    ret_print.push( format!("against interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("with median times over {:} tries", num_tries) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let mut csv_results = "trace,length,".to_string();
+                    AnalysisStrategyMetrics::add_csv_title_line("hcs",&mut csv_results);
+                    AnalysisStrategyMetrics::add_csv_title_line("greedydfs",&mut csv_results);
+                    csv_results.push_str("\n");
+                    let mut total_hcs = (0_u64,0_u128);
+                    let mut total_greedy = (0_u64,0_u128);
+                    for htf_file_path in &htf_file_paths {
+                        let htf_file_str = htf_file_path.to_str().unwrap();
+                        let (co_localizations,multi_trace) = match parse_htf_file(&gen_ctx,htf_file_str) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got ) => {
+                                got
+                            }
+                        };
+                        let hcs = benchmark_analysis_strategy(&gen_ctx,&int,&co_localizations,&multi_trace,
+                                                              &|| get_ana_options(&gen_ctx,hcf_file_path,QueueSearchStrategy::HCS,false),
+                                                              num_tries);
+                        let greedy = benchmark_analysis_strategy(&gen_ctx,&int,&co_localizations,&multi_trace,
+                                                                 &|| get_ana_options(&gen_ctx,hcf_file_path,QueueSearchStrategy::DFS,true),
+                                                                 num_tries);
+                        ret_print.push( format!("{} : HCS {} in {}μs with {} nodes, GreedyDFS {} in {}μs with {} nodes",
+                                                htf_file_str,
+                                                hcs.verdict, hcs.median_time, hcs.node_count,
+                                                greedy.verdict, greedy.median_time, greedy.node_count) );
+                        if hcs.verdict != greedy.verdict {
+                            ret_print.push( "WARNING : the verdicts differ (this may be due to filters or goals)".to_string() );
+                        }
+                        total_hcs = (total_hcs.0 + hcs.node_count as u64, total_hcs.1 + hcs.median_time);
+                        total_greedy = (total_greedy.0 + greedy.node_count as u64, total_greedy.1 + greedy.median_time);
+                        // ***
+                        csv_results.push_str(&format!("{},{},", htf_file_str, multi_trace_length(&multi_trace)));
+                        hcs.add_csv_line(&mut csv_results);
+                        greedy.add_csv_line(&mut csv_results);
+                        csv_results.push_str("\n");
+                    }
+                    // ***
+                    let output_file_name = if matches.is_present("output") {
+                        format!("{}.csv", matches.value_of("output").unwrap())
+                    } else {
+                        let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                        format!("{}_strategies.csv", file_name)
+                    };
+                    let mut file = File::create(output_file_name.clone()).unwrap();
+                    file.write(csv_results.as_bytes());
+                    // ***
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("total HCS       : {} nodes in {}μs", total_hcs.0, total_hcs.1) );
+                    ret_print.push( format!("total GreedyDFS : {} nodes in {}μs", total_greedy.0, total_greedy.1) );
+                    ret_print.push( format!("collected metrics in file '{}'", output_file_name) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
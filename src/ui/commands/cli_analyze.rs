@@ -32,6 +32,10 @@ use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
 use crate::process::ana::node::node::AnalysisNodeKind;
 use crate::process::ana::priorities::AnalysisPriorities;
 use crate::process::ana::step::AnalysisStepKind;
+use crate::io::output::to_json::stats::{process_options_as_json, process_statistics_as_json};
+use crate::io::output::to_json::util::{json_object, json_string};
+use crate::loggers::stats::logger::GenericProcessStatsLogger;
+use crate::ui::util::output::{analysis_verdict_exit_code, HibouOutputFormat};
 
 
 pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -80,7 +84,16 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             }
                             ret_print.push( "".to_string());
                             // ***
+                            let output_format = HibouOutputFormat::from_matches(matches);
+                            let mut loggers = ana_opts.loggers;
+                            let stats_logger_id = loggers.len();
+                            if let HibouOutputFormat::Json = output_format {
+                                loggers.push(Box::new(GenericProcessStatsLogger::<AnalysisConfig>::new()));
+                            }
+                            // ***
                             let ana_ctx = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,multi_trace_length);
+                            // the loggers only know of the queue, which is depth-first with the GreedyDFS strategy
+                            let strategy_name = if ana_opts.ana_param.heuristic_guidance {"GreedyDFS".to_string()} else {ana_opts.strategy.to_string()};
                             let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
 
                             let init_flags : MultiTraceAnalysisFlags;
@@ -100,7 +113,7 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                                                                                                                           ana_opts.ana_param,
                                                                                                                                 delegate,
                                                                                                                           ana_opts.filters,
-                                                                                                                          ana_opts.loggers,
+                                                                                                                          loggers,
                                                                                                                           ana_opts.goal,
                                                                                                                           ana_opts.use_memoization);
 
@@ -110,10 +123,23 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             let now = Instant::now();
                             let (node_count,verdict) = analysis_manager.start_process(init_node);
                             let elapsed_time = now.elapsed();
+                            let exit_code = analysis_verdict_exit_code(&verdict);
+                            if let HibouOutputFormat::Json = output_format {
+                                let stats : &GenericProcessStatsLogger<AnalysisConfig> = analysis_manager.get_logger(stats_logger_id).unwrap()
+                                    .as_any().downcast_ref::<GenericProcessStatsLogger<AnalysisConfig>>().unwrap();
+                                let json = json_object(vec![("command",json_string("analyze")),
+                                                            ("interaction",json_string(hif_file_path)),
+                                                            ("trace",json_string(htf_file_path)),
+                                                            ("trace_length",multi_trace_length.to_string()),
+                                                            ("verdict",json_string(&verdict.to_string())),
+                                                            ("options",process_options_as_json(&strategy_name,stats)),
+                                                            ("statistics",process_statistics_as_json(stats,elapsed_time.as_secs_f64()))]);
+                                return (vec![json],exit_code);
+                            }
                             ret_print.push( format!("verdict    : '{}'", verdict.to_string() ) );
                             ret_print.push( format!("node count : {:?}", node_count ) );
                             ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
-                            return (ret_print,0);
+                            return (ret_print,exit_code);
                         }
                     }
                 }
@@ -16,6 +16,7 @@ limitations under the License.
 
 
 
+use std::path::Path;
 use std::time::Instant;
 use clap::ArgMatches;
 use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
@@ -24,14 +25,29 @@ use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrate
 use crate::io::input::hcf::canon::interface::parse_hcf_file_for_canonize;
 use crate::io::input::hcf::canon::options::HibouCanonizeOptions;
 use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hrf::interface::parse_hrf_file;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::process::canon::conf::CanonizationConfig;
 use crate::process::canon::context::CanonizationContext;
+use crate::process::canon::loggers::phases::drawer::draw_canonization_phases;
+use crate::process::canon::loggers::phases::logger::{CanonizationPhasesLogger, CanonizationPhaseStatistics};
+use crate::process::canon::loggers::proof::logger::CanonizationProofLogger;
 use crate::process::canon::node::CanonizationNodeKind;
 use crate::process::canon::param::default::DefaultCanonizationProcess;
 use crate::process::canon::param::phase::CanonizationParameterization;
 use crate::process::canon::priorities::CanonizationPriorities;
 use crate::process::canon::step::CanonizationStepKind;
+use crate::core::transformation::proof::certificate::InteractionEquivalenceCertificate;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::core::transformation::transfophase::InteractionTransformationPhase;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::io::output::to_hfiles::proof::to_hpf::write_certificate_into_file;
+use crate::io::output::to_json::stats::{process_options_as_json, process_statistics_as_json};
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::loggers::stats::logger::GenericProcessStatsLogger;
+use crate::nfa_translation::canonize::normalize_interaction;
+use crate::ui::util::output::HibouOutputFormat;
 
 
 pub fn cli_canonize(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -47,10 +63,21 @@ pub fn cli_canonize(matches : &ArgMatches) -> (Vec<String>,u32) {
                     return (vec![e.to_string()],1);
                 },
                 Ok( int) => {
+                    let mut rules : Vec<InteractionRewriteRule> = vec![];
+                    if let Some(hrf_file_path) = matches.value_of("rules") {
+                        match parse_hrf_file(hrf_file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got_rules ) => {
+                                rules = got_rules;
+                            }
+                        }
+                    }
                     let canon_opts : HibouCanonizeOptions;
                     if matches.is_present("hcf") {
                         let hcf_file_path = matches.value_of("hcf").unwrap();
-                        match parse_hcf_file_for_canonize(&gen_ctx,hcf_file_path) {
+                        match parse_hcf_file_for_canonize(&gen_ctx,hcf_file_path,&rules) {
                             Err(e) => {
                                 return (vec![e.to_string()],1);
                             },
@@ -68,25 +95,60 @@ pub fn cli_canonize(matches : &ArgMatches) -> (Vec<String>,u32) {
                     ret_print.push( format!("from file '{}'",hif_file_path) );
                     ret_print.push( "".to_string());
                     // ***
-
-                    let canon_ctx = CanonizationContext::new(gen_ctx);
+                    let output_format = HibouOutputFormat::from_matches(matches);
+                    let mut loggers = canon_opts.loggers;
+                    let stats_logger_id = loggers.len();
+                    if let HibouOutputFormat::Json = output_format {
+                        loggers.push(Box::new(GenericProcessStatsLogger::<CanonizationConfig>::new()));
+                    }
+                    let proof_logger_id = loggers.len();
+                    if matches.is_present("proof") {
+                        loggers.push(Box::new(CanonizationProofLogger::new()));
+                    }
+                    let all_forms = matches.is_present("all_forms");
+                    let phases_logger_id = loggers.len();
+                    if all_forms {
+                        loggers.push(Box::new(CanonizationPhasesLogger::new()));
+                    }
+                    // ***
+                    let mut canon_param = get_canonization_parameterization(canon_opts.phases,canon_opts.search_all,&rules);
+                    // the "basic" phase is applied by the normalizer unless all the transformations are to be considered
+                    // or the process is to be logged (e.g. for the proof, the phases or the statistics)
+                    if !canon_opts.search_all
+                        && loggers.is_empty()
+                        && canon_param.phases == DefaultCanonizationProcess::Basic.get_phases(false) {
+                        let now = Instant::now();
+                        let normalized = normalize_interaction(&int,DefaultCanonizationProcess::Basic);
+                        let elapsed_time = now.elapsed();
+                        ret_print.push( "normalized without running a process".to_string() );
+                        ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                        ret_print.push( "".to_string());
+                        ret_print.push( "normal form :".to_string() );
+                        ret_print.push( interaction_as_hif_encoding(&gen_ctx,&normalized) );
+                        ret_print.push( "".to_string());
+                        return (ret_print,0);
+                    }
+                    // ***
+                    let canon_ctx = CanonizationContext::new(gen_ctx.clone());
                     let delegate : GenericProcessDelegate<CanonizationStepKind,CanonizationNodeKind,CanonizationPriorities> =
                         GenericProcessDelegate::new(
                             canon_opts.strategy,
                             canon_opts.priorities
                         );
 
-                    let canon_param = CanonizationParameterization::from_default(
-                        DefaultCanonizationProcess::FivePhases,
-                        canon_opts.search_all
-                    );
+                    if all_forms {
+                        // all the interactions with which each phase may end are explored
+                        for phase in canon_param.phases.iter_mut() {
+                            phase.get_all = true;
+                        }
+                    }
 
                     let mut canon_manager : GenericProcessManager<CanonizationConfig> = GenericProcessManager::new(
                         canon_ctx,
                         canon_param,
                         delegate,
                         canon_opts.filters,
-                        canon_opts.loggers,
+                        loggers,
                         None,
                         true
                     );
@@ -94,10 +156,73 @@ pub fn cli_canonize(matches : &ArgMatches) -> (Vec<String>,u32) {
                     let init_node = CanonizationNodeKind::new(int,0);
                     // ***
                     let now = Instant::now();
-                    let (node_count,_) = canon_manager.start_process(init_node);
+                    let (node_count,verdict) = canon_manager.start_process(init_node);
                     let elapsed_time = now.elapsed();
+                    if let Some(hpf_file_path) = matches.value_of("proof") {
+                        let proof_logger : &CanonizationProofLogger = canon_manager.get_logger(proof_logger_id).unwrap()
+                            .as_any().downcast_ref::<CanonizationProofLogger>().unwrap();
+                        // the proof log of the first normal form
+                        if let Some(derivation) = proof_logger.derivations.first() {
+                            let certificate = InteractionEquivalenceCertificate::new(vec![derivation.clone()]);
+                            if let Err(e) = write_certificate_into_file(Path::new(hpf_file_path),&gen_ctx,&certificate) {
+                                return (vec![e.to_string()],1);
+                            }
+                        }
+                    }
+                    let mut phases_stats : Vec<CanonizationPhaseStatistics> = vec![];
+                    if all_forms {
+                        let phases_logger : &CanonizationPhasesLogger = canon_manager.get_logger(phases_logger_id).unwrap()
+                            .as_any().downcast_ref::<CanonizationPhasesLogger>().unwrap();
+                        let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                        draw_canonization_phases(&gen_ctx,
+                                                 phases_logger,
+                                                 &"temp".to_string(),
+                                                 &format!("{}_phases", file_name));
+                        phases_stats = phases_logger.phases.clone();
+                    }
+                    if let HibouOutputFormat::Json = output_format {
+                        let stats : &GenericProcessStatsLogger<CanonizationConfig> = canon_manager.get_logger(stats_logger_id).unwrap()
+                            .as_any().downcast_ref::<GenericProcessStatsLogger<CanonizationConfig>>().unwrap();
+                        let normal_forms_json : Vec<String> = verdict.canonized_ints.iter()
+                            .map(|i| json_string(&interaction_as_hif_encoding(&gen_ctx,i)))
+                            .collect();
+                        let phases_json : Vec<String> = phases_stats.iter()
+                            .map(phase_statistics_as_json)
+                            .collect();
+                        // the global verdict of a canonization process is not pertinent
+                        let json = json_object(vec![("command",json_string("canonize")),
+                                                    ("interaction",json_string(hif_file_path)),
+                                                    ("verdict","null".to_string()),
+                                                    ("options",process_options_as_json(&stats.strategy,stats)),
+                                                    ("statistics",process_statistics_as_json(stats,elapsed_time.as_secs_f64())),
+                                                    ("normal_forms",json_array(normal_forms_json)),
+                                                    ("phases",json_array(phases_json))]);
+                        return (vec![json],0);
+                    }
                     ret_print.push( format!("node count : {:?}", node_count ) );
                     ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                    if all_forms {
+                        ret_print.push( "".to_string());
+                        for (x,phase_stats) in phases_stats.iter().enumerate() {
+                            ret_print.push( format!("phase {} : {} interactions, {} transformations, {} outputs",
+                                                    x+1,
+                                                    phase_stats.num_nodes,
+                                                    phase_stats.num_transformations,
+                                                    phase_stats.num_outputs) );
+                            for (kind,count) in &phase_stats.transformations {
+                                ret_print.push( format!("    {} : {}", kind, count) );
+                            }
+                        }
+                        ret_print.push( "".to_string());
+                        ret_print.push( format!("{} normal forms :", verdict.canonized_ints.len()) );
+                        for canonized in &verdict.canonized_ints {
+                            ret_print.push( interaction_as_hif_encoding(&gen_ctx,canonized) );
+                        }
+                        ret_print.push( "".to_string());
+                    }
+                    if let Some(hpf_file_path) = matches.value_of("proof") {
+                        ret_print.push( format!("proof log written in '{}'", hpf_file_path) );
+                    }
                     // ***
                     return (ret_print,0);
                 }
@@ -106,3 +231,36 @@ pub fn cli_canonize(matches : &ArgMatches) -> (Vec<String>,u32) {
     }
 }
 
+fn phase_statistics_as_json(phase_stats : &CanonizationPhaseStatistics) -> String {
+    let transfos_json : Vec<String> = phase_stats.transformations.iter()
+        .map(|(kind,count)| json_object(vec![("kind",json_string(kind)),("count",count.to_string())]))
+        .collect();
+    return json_object(vec![("num_nodes",phase_stats.num_nodes.to_string()),
+                            ("num_transformations",phase_stats.num_transformations.to_string()),
+                            ("transformations",json_array(transfos_json)),
+                            ("num_outputs",phase_stats.num_outputs.to_string())]);
+}
+
+/**
+The phases declared in the configuration file if any,
+otherwise those of the default process, to which the rewrite rules are added.
+**/
+pub fn get_canonization_parameterization(phases : Option<Vec<InteractionTransformationPhase>>,
+                                         search_all : bool,
+                                         rules : &Vec<InteractionRewriteRule>) -> CanonizationParameterization {
+    match phases {
+        Some(phases) => {
+            return CanonizationParameterization::new(phases);
+        },
+        None => {
+            let mut param = CanonizationParameterization::from_default(
+                DefaultCanonizationProcess::FivePhases,
+                search_all
+            );
+            for phase in param.phases.iter_mut() {
+                phase.transfos.extend(rules.iter().map(|r| InteractionTransformationKind::Rule(r.clone())));
+            }
+            return param;
+        }
+    }
+}
@@ -0,0 +1,164 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::transformation::proof::certificate::InteractionEquivalenceCertificate;
+use crate::core::transformation::proof::derivation::InteractionDerivation;
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::io::input::hcf::canon::interface::parse_hcf_file_for_canonize;
+use crate::io::input::hcf::canon::options::HibouCanonizeOptions;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hrf::interface::parse_hrf_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::io::output::to_hfiles::proof::to_hpf::write_certificate_into_file;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::inconc::InconcReason;
+use crate::process::canon::conf::CanonizationConfig;
+use crate::process::canon::context::CanonizationContext;
+use crate::process::canon::loggers::proof::logger::CanonizationProofLogger;
+use crate::process::canon::node::CanonizationNodeKind;
+use crate::process::canon::priorities::CanonizationPriorities;
+use crate::process::canon::step::CanonizationStepKind;
+use crate::ui::commands::cli_canonize::get_canonization_parameterization;
+use crate::ui::util::output::analysis_verdict_exit_code;
+
+
+/**
+Canonizes the interaction and returns the derivations leading to each of its normal forms.
+The configuration file is parsed anew for each interaction given that the loggers and filters it declares are consumed by the process.
+**/
+fn get_normal_forms_derivations(gen_ctx : &GeneralContext,
+                                interaction : Interaction,
+                                hcf_file_path : Option<&str>,
+                                rules : &Vec<InteractionRewriteRule>) -> Result<Vec<InteractionDerivation>,String> {
+    let canon_opts = match hcf_file_path {
+        None => {
+            HibouCanonizeOptions::default()
+        },
+        Some( hcf_file_path ) => {
+            match parse_hcf_file_for_canonize(gen_ctx,hcf_file_path,rules) {
+                Err(e) => {
+                    return Err(e.to_string());
+                },
+                Ok( got_canon_opt ) => {
+                    got_canon_opt
+                }
+            }
+        }
+    };
+    let mut loggers = canon_opts.loggers;
+    let proof_logger_id = loggers.len();
+    loggers.push(Box::new(CanonizationProofLogger::new()));
+    let canon_ctx = CanonizationContext::new(gen_ctx.clone());
+    let delegate : GenericProcessDelegate<CanonizationStepKind,CanonizationNodeKind,CanonizationPriorities> =
+        GenericProcessDelegate::new(
+            canon_opts.strategy,
+            canon_opts.priorities
+        );
+    let canon_param = get_canonization_parameterization(canon_opts.phases,canon_opts.search_all,rules);
+    let mut canon_manager : GenericProcessManager<CanonizationConfig> = GenericProcessManager::new(
+        canon_ctx,
+        canon_param,
+        delegate,
+        canon_opts.filters,
+        loggers,
+        None,
+        true
+    );
+    canon_manager.start_process(CanonizationNodeKind::new(interaction,0));
+    let proof_logger : &CanonizationProofLogger = canon_manager.get_logger(proof_logger_id).unwrap()
+        .as_any().downcast_ref::<CanonizationProofLogger>().unwrap();
+    return Ok( proof_logger.derivations.clone() );
+}
+
+pub fn cli_certify_equiv(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut rules : Vec<InteractionRewriteRule> = vec![];
+    if let Some(hrf_file_path) = matches.value_of("rules") {
+        match parse_hrf_file(hrf_file_path) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( got_rules ) => {
+                rules = got_rules;
+            }
+        }
+    }
+    let mut derivations_per_interaction = vec![];
+    for hif_arg in ["hif1","hif2"] {
+        let hif_file_path = matches.value_of(hif_arg).unwrap();
+        let int = match parse_hif_file(&gen_ctx,hif_file_path) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( got ) => {
+                got
+            }
+        };
+        match get_normal_forms_derivations(&gen_ctx,int,matches.value_of("hcf"),&rules) {
+            Err(e) => {
+                return (vec![e],1);
+            },
+            Ok( derivations ) => {
+                derivations_per_interaction.push(derivations);
+            }
+        }
+    }
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "CERTIFYING the EQUIVALENCE of INTERACTIONS".to_string());
+    ret_print.push( format!("from files '{}' and '{}'",matches.value_of("hif1").unwrap(),matches.value_of("hif2").unwrap()) );
+    ret_print.push( "".to_string());
+    // with search_all, any normal form common to both interactions yields a certificate
+    let derivs2 = derivations_per_interaction.pop().unwrap();
+    let derivs1 = derivations_per_interaction.pop().unwrap();
+    for deriv1 in &derivs1 {
+        if let Some(deriv2) = derivs2.iter().find(|d| d.get_final() == deriv1.get_final()) {
+            let certificate = InteractionEquivalenceCertificate::new(vec![deriv1.clone(),deriv2.clone()]);
+            let hpf_file_path = matches.value_of("output").unwrap_or("certificate.hpf");
+            if let Err(e) = write_certificate_into_file(Path::new(hpf_file_path),&gen_ctx,&certificate) {
+                return (vec![e.to_string()],1);
+            }
+            ret_print.push( "common normal form :".to_string());
+            ret_print.push( interaction_as_hif_encoding(&gen_ctx,deriv1.get_final()) );
+            ret_print.push( format!("certificate written in '{}'", hpf_file_path) );
+            ret_print.push( format!("verdict : {}", AnalysisGlobalVerdict::Pass) );
+            return (ret_print,analysis_verdict_exit_code(&AnalysisGlobalVerdict::Pass));
+        }
+    }
+    // distinct normal forms do not prove that the interactions are not equivalent
+    let verdict = AnalysisGlobalVerdict::Inconc(InconcReason::IncompleteCanonization);
+    ret_print.push( "no certificate found".to_string());
+    ret_print.push( format!("verdict : {}", verdict) );
+    return (ret_print,analysis_verdict_exit_code(&verdict));
+}
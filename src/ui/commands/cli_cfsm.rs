@@ -0,0 +1,123 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_cfsm::{CfsmOutputFormat, machines_to_file};
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::cfsm::CommunicatingMachine;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::nfa_translation::realizability::channels::AsyncChannels;
+use crate::nfa_translation::realizability::local::{get_lifelines_local_interactions, get_local_dfa};
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+
+
+pub fn cli_cfsm(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let format = match matches.value_of("export") {
+                        None | Some("text") => {
+                            CfsmOutputFormat::Text
+                        },
+                        Some("dot") => {
+                            CfsmOutputFormat::Dot
+                        },
+                        Some("json") => {
+                            CfsmOutputFormat::Json
+                        },
+                        Some(other) => {
+                            return (vec![format!("unknown export format '{:}'", other)],1);
+                        }
+                    };
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let output_file_path = match matches.value_of("output") {
+                        None => {
+                            let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                            format!("{}_cfsm.{}", file_name, format.get_file_extension())
+                        },
+                        Some( got ) => {
+                            got.to_string()
+                        }
+                    };
+                    let channels = match AsyncChannels::from_interaction(&gen_ctx,&int) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    let mut machines = vec![];
+                    let mut are_nfas_complete = true;
+                    for (lf_id,local_int) in get_lifelines_local_interactions(&gen_ctx,&int).into_iter().enumerate() {
+                        match get_interaction_nfa_from_arg(&gen_ctx,&local_int,&alphabet,matches.value_of("nfa"),max_states) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( (local_nfa,is_complete) ) => {
+                                are_nfas_complete = are_nfas_complete && is_complete;
+                                machines.push(CommunicatingMachine::new(lf_id,get_local_dfa(local_nfa),&alphabet,&channels));
+                            }
+                        }
+                    }
+                    machines_to_file(Path::new(&output_file_path),&gen_ctx,&machines,&format);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "EXPORTING COMMUNICATING AUTOMATA".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("on file : {}",output_file_path) );
+                    ret_print.push( "".to_string());
+                    for machine in &machines {
+                        ret_print.push( format!("lifeline '{:}' : {:} states, {:} transitions",
+                                                gen_ctx.get_lf_name(machine.lf_id).unwrap(),
+                                                machine.num_states,
+                                                machine.transitions.len()) );
+                    }
+                    ret_print.push( "".to_string());
+                    if !are_nfas_complete {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states", max_states) );
+                        ret_print.push( "".to_string());
+                    }
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
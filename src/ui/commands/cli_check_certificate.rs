@@ -0,0 +1,78 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use clap::ArgMatches;
+
+use crate::core::transformation::rewrite::rule::InteractionRewriteRule;
+use crate::io::input::hpf::interface::parse_hpf_file;
+use crate::io::input::hrf::interface::parse_hrf_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::ui::util::output::analysis_verdict_exit_code;
+
+
+pub fn cli_check_certificate(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut rules : Vec<InteractionRewriteRule> = vec![];
+    if let Some(hrf_file_path) = matches.value_of("rules") {
+        match parse_hrf_file(hrf_file_path) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( got_rules ) => {
+                rules = got_rules;
+            }
+        }
+    }
+    let hpf_file_path = matches.value_of("hpf").unwrap();
+    let certificate = match parse_hpf_file(&gen_ctx,hpf_file_path,&rules) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "CHECKING CERTIFICATE".to_string());
+    ret_print.push( format!("from file '{}'",hpf_file_path) );
+    ret_print.push( format!("with {:} derivation(s)", certificate.derivations.len()) );
+    ret_print.push( "".to_string());
+    match certificate.check() {
+        Ok( normal_form ) => {
+            ret_print.push( "normal form :".to_string());
+            ret_print.push( interaction_as_hif_encoding(&gen_ctx,normal_form) );
+            ret_print.push( format!("verdict : {}", AnalysisGlobalVerdict::Pass) );
+            return (ret_print,analysis_verdict_exit_code(&AnalysisGlobalVerdict::Pass));
+        },
+        Err(e) => {
+            ret_print.push( e.to_string() );
+            ret_print.push( format!("verdict : {}", AnalysisGlobalVerdict::Fail) );
+            return (ret_print,analysis_verdict_exit_code(&AnalysisGlobalVerdict::Fail));
+        }
+    }
+}
@@ -0,0 +1,185 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+use num_bigint::BigUint;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::count::counter::AcceptedTracesCounter;
+use crate::nfa_translation::count::growth::TraceLanguageGrowth;
+use crate::nfa_translation::count::multi::{count_accepted_multi_traces, get_letters_coloc_ids};
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::ui::util::colocs::parse_co_localizations_arg;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+use crate::ui::util::output::HibouOutputFormat;
+
+
+pub const DEFAULT_MAX_COUNTED_MULTI_TRACES : usize = 1000000;
+
+pub fn cli_count_traces(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let max_length : usize = match matches.value_of("max_length") {
+                        None => {
+                            10
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let max_multi_traces : usize = match matches.value_of("max_multi_traces") {
+                        None => {
+                            DEFAULT_MAX_COUNTED_MULTI_TRACES
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let now = Instant::now();
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    // ***
+                    let (nfa,is_nfa_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int,&alphabet,matches.value_of("nfa"),max_states) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let mut counter = AcceptedTracesCounter::new(&nfa);
+                    let global_counts : Vec<BigUint> = (0..=max_length).map(|n| counter.count_accepted_traces(n)).collect();
+                    let growth = counter.get_language_growth();
+                    // ***
+                    let multi_counts : Option<(Vec<BigUint>,bool)> = if matches.is_present("partition") {
+                        let co_localizations = match parse_co_localizations_arg(&gen_ctx,matches.value_of("partition")) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got ) => {
+                                got
+                            }
+                        };
+                        Some(count_accepted_multi_traces(&counter,
+                                                         &get_letters_coloc_ids(&alphabet,&co_localizations),
+                                                         co_localizations.num_colocs(),
+                                                         max_length,
+                                                         max_multi_traces))
+                    } else {
+                        None
+                    };
+                    let elapsed_time = now.elapsed();
+                    // ***
+                    if let HibouOutputFormat::Json = HibouOutputFormat::from_matches(matches) {
+                        // counts are written as strings as they may exceed the precision of JSON numbers
+                        let as_json_array = |counts : &Vec<BigUint>| json_array(counts.iter().map(|x| json_string(&x.to_string())).collect());
+                        let (growth_kind,growth_value) = match &growth {
+                            TraceLanguageGrowth::Empty => {
+                                ("empty","null".to_string())
+                            },
+                            TraceLanguageGrowth::Finite(longest) => {
+                                ("finite",longest.to_string())
+                            },
+                            TraceLanguageGrowth::Polynomial(degree) => {
+                                ("polynomial",degree.to_string())
+                            },
+                            TraceLanguageGrowth::Exponential(rate) => {
+                                ("exponential",rate.to_string())
+                            }
+                        };
+                        let partition_json = match matches.value_of("partition") {
+                            None => {
+                                "null".to_string()
+                            },
+                            Some( as_str ) => {
+                                json_string(as_str)
+                            }
+                        };
+                        let mut fields = vec![("command",json_string("count_traces")),
+                                              ("interaction",json_string(hif_file_path)),
+                                              ("verdict","null".to_string()),
+                                              ("options",json_object(vec![("max_length",max_length.to_string()),
+                                                                          ("partition",partition_json),
+                                                                          ("nfa",json_string(matches.value_of("nfa").unwrap_or("exploration"))),
+                                                                          ("max_states",max_states.to_string()),
+                                                                          ("max_multi_traces",max_multi_traces.to_string())])),
+                                              ("statistics",json_object(vec![("dfa_states",counter.dfa.transitions.len().to_string()),
+                                                                             ("is_nfa_complete",is_nfa_complete.to_string()),
+                                                                             ("elapsed",format!("{:?}", elapsed_time.as_secs_f64()))])),
+                                              ("growth",json_object(vec![("kind",json_string(growth_kind)),
+                                                                         ("value",growth_value)])),
+                                              ("traces",as_json_array(&global_counts))];
+                        if let Some((counts,is_complete)) = &multi_counts {
+                            fields.push(("multi_traces",as_json_array(counts)));
+                            fields.push(("are_multi_traces_complete",is_complete.to_string()));
+                        }
+                        return (vec![json_object(fields)],0);
+                    }
+                    let mut ret_print = vec![];
+                    ret_print.push( "COUNTING ACCEPTED TRACES".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("via a DFA with {:} states", counter.dfa.transitions.len()) );
+                    if !is_nfa_complete {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states, counts are lower bounds", max_states) );
+                    }
+                    ret_print.push( "".to_string());
+                    for (length,count) in global_counts.iter().enumerate() {
+                        match &multi_counts {
+                            Some((counts,_)) if length < counts.len() => {
+                                ret_print.push( format!("n = {:} : {:} traces, {:} multi-traces", length, count, counts[length]) );
+                            },
+                            _ => {
+                                ret_print.push( format!("n = {:} : {:} traces", length, count) );
+                            }
+                        }
+                    }
+                    if let Some((counts,false)) = &multi_counts {
+                        ret_print.push( format!("WARNING : multi-traces were counted up to length {:} only as there were more than {:} of them",
+                                                counts.len() - 1, max_multi_traces) );
+                    }
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("accepted language : {:}", growth) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
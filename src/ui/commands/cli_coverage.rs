@@ -0,0 +1,215 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::interpretation::coverage::InteractionCoverage;
+use crate::core::execution::interpretation::interpretation::InteractionInterpretation;
+use crate::core::execution::interpretation::origin::relabel_interaction_actions;
+use crate::core::execution::interpretation::replay::replay_witness;
+use crate::core::execution::interpretation::witness::ExecutionWitness;
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
+use crate::io::output::draw_interactions::interface::draw_highlighted_interaction_as_sd;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+use crate::io::output::to_json::coverage::write_coverage_into_json_file;
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::loggers::wlog::collector::AnalysisWitnessCollector;
+use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+// analyzes the multi-trace and returns the first acceptance witness that is found if any
+fn get_acceptance_witness(gen_ctx : &GeneralContext,
+                          interaction : &Interaction,
+                          co_localizations : CoLocalizations,
+                          multi_trace : MultiTrace,
+                          ana_opts : HibouAnalyzeOptions) -> Option<ExecutionWitness> {
+    let multi_trace_length = multi_trace_length(&multi_trace);
+    let ana_ctx = AnalysisContext::new(gen_ctx.clone(),co_localizations,multi_trace,multi_trace_length);
+    let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
+    let init_flags : MultiTraceAnalysisFlags;
+    match ana_opts.ana_param.ana_kind.get_sim_config() {
+        None => {
+            init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
+                                                           0,
+                                                           0);
+        },
+        Some( sim_config ) => {
+            init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
+                                                           sim_config.get_reset_rem_loop(multi_trace_length,interaction),
+                                                           sim_config.get_reset_rem_act(multi_trace_length,interaction));
+        }
+    }
+    // the search stops as soon as the multi-trace is found to be accepted
+    let mut analysis_manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
+                                                                                              ana_opts.ana_param,
+                                                                                              delegate,
+                                                                                              ana_opts.filters,
+                                                                                              vec![Box::new(AnalysisWitnessCollector::new())],
+                                                                                              Some(AnalysisGlobalVerdict::Pass),
+                                                                                              ana_opts.use_memoization);
+    let init_node = AnalysisNodeKind::new(interaction.clone(),init_flags,0);
+    analysis_manager.start_process(init_node);
+    // ***
+    let collector : &AnalysisWitnessCollector = analysis_manager.get_logger(0).unwrap()
+        .as_any().downcast_ref::<AnalysisWitnessCollector>().unwrap();
+    return collector.accepting_witnesses.first().cloned();
+}
+
+pub fn cli_coverage(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let origins = match relabel_interaction_actions(&int) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_origins ) => {
+                            got_origins
+                        }
+                    };
+                    let mut coverage = InteractionCoverage::new_from_interpretation_template(&InteractionInterpretation::new_from_origins(&origins));
+                    // ***
+                    let traces_folder = matches.value_of("traces").unwrap();
+                    let mut htf_file_paths : Vec<PathBuf> = vec![];
+                    match fs::read_dir(traces_folder) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( entries ) => {
+                            for entry in entries {
+                                let path = entry.unwrap().path();
+                                if path.extension().map_or(false, |ext| ext == HIBOU_TRACE_FILE_EXTENSION) {
+                                    htf_file_paths.push(path);
+                                }
+                            }
+                        }
+                    }
+                    htf_file_paths.sort();
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "MEASURING COVERAGE".to_string());
+                    ret_print.push( format!("of interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("by the {:} traces from folder '{}'", htf_file_paths.len(), traces_folder) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let mut traces : Vec<(String,bool)> = vec![];
+                    for htf_file_path in &htf_file_paths {
+                        let htf_file_str = htf_file_path.to_str().unwrap();
+                        let (co_localizations,multi_trace) = match parse_htf_file(&gen_ctx,htf_file_str) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got ) => {
+                                got
+                            }
+                        };
+                        let ana_opts : HibouAnalyzeOptions;
+                        if matches.is_present("hcf") {
+                            let hcf_file_path = matches.value_of("hcf").unwrap();
+                            match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( got_ana_opt) => {
+                                    ana_opts = got_ana_opt;
+                                }
+                            }
+                        } else {
+                            ana_opts = HibouAnalyzeOptions::default();
+                        }
+                        // ***
+                        let mut accepted = false;
+                        if let Some(witness) = get_acceptance_witness(&gen_ctx,&int,co_localizations,multi_trace,ana_opts) {
+                            match replay_witness(&int,&witness) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( replay ) => {
+                                    coverage.add_interpretation(&replay.interpretation);
+                                    accepted = true;
+                                }
+                            }
+                        }
+                        if !accepted {
+                            ret_print.push( format!("WARNING : trace '{}' is not accepted and does not contribute to the coverage", htf_file_str) );
+                        }
+                        traces.push( (htf_file_str.to_string(),accepted) );
+                    }
+                    // ***
+                    let output_file_name : String;
+                    if matches.is_present("output") {
+                        let extracted = matches.value_of("output").unwrap();
+                        output_file_name = extracted.to_string();
+                    } else {
+                        let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                        output_file_name = format!("{}_coverage", file_name);
+                    }
+                    let json_file_name = format!("{}.json", output_file_name);
+                    if let Err(e) = write_coverage_into_json_file(Path::new(&json_file_name),&gen_ctx,&origins,&coverage,&traces) {
+                        return (vec![e.to_string()],1);
+                    }
+                    let highlighting = SequenceDiagramHighlighting::new_from_coverage(&coverage);
+                    draw_highlighted_interaction_as_sd(&gen_ctx, &int, &highlighting, &"".to_string(), &output_file_name);
+                    // ***
+                    ret_print.push( format!("accepted traces : {:}/{:}", coverage.interpretations_num, traces.len()) );
+                    ret_print.push( format!("covered actions : {:}/{:}", coverage.get_covered_actions_num(), coverage.actions.len()) );
+                    ret_print.push( format!("covered alt branches : {:}/{:}", coverage.get_covered_alt_branches_num(), 2*coverage.alts.len()) );
+                    for loop_cov in &coverage.loops {
+                        ret_print.push( format!("loop at position {:} : instantiated 0 times in {:}, once in {:} and many times in {:} trace(s)",
+                                                position_as_hwf_encoding(&loop_cov.position),
+                                                loop_cov.zero,
+                                                loop_cov.one,
+                                                loop_cov.many) );
+                    }
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("coverage report written on files : {}.png and {}", output_file_name, json_file_name) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::time::Instant;
 use clap::ArgMatches;
 use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
 use graph_process_manager_core::manager::manager::GenericProcessManager;
+use crate::core::language::unroll::unrollable::LoopUnrollable;
 use crate::io::input::hcf::explo::interface::parse_hcf_file_for_explore;
 use crate::io::input::hcf::explo::options::HibouExploreOptions;
 
@@ -32,6 +33,10 @@ use crate::process::explo::context::{ExplorationContext, ExplorationParameteriza
 use crate::process::explo::node::ExplorationNodeKind;
 use crate::process::explo::priorities::ExplorationPriorities;
 use crate::process::explo::step::ExplorationStepKind;
+use crate::io::output::to_json::stats::{process_options_as_json, process_statistics_as_json};
+use crate::io::output::to_json::util::{json_object, json_string};
+use crate::loggers::stats::logger::GenericProcessStatsLogger;
+use crate::ui::util::output::HibouOutputFormat;
 
 
 pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -66,8 +71,29 @@ pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
                     ret_print.push( "".to_string());
                     ret_print.push( "EXPLORING SEMANTICS".to_string());
                     ret_print.push( format!("of interaction from file '{}'",hsf_file_path) );
+                    // the syntactic counterpart of a bound on the number of loop instanciations
+                    let int = match matches.value_of("unroll") {
+                        None => {
+                            int
+                        },
+                        Some( as_str ) => {
+                            let num_iterations = as_str.trim().parse::<u32>().unwrap();
+                            ret_print.push( format!("with loops unrolled at most {} time(s)",num_iterations) );
+                            if int.unrolling_over_approximates(num_iterations) {
+                                ret_print.push( "WARNING : the unrolled interaction over-approximates the bounded language given that the iterations of \"loopH\" may overlap".to_string() );
+                            }
+                            int.unroll_loops(num_iterations)
+                        }
+                    };
                     ret_print.push( "".to_string());
                     // ***
+                    let output_format = HibouOutputFormat::from_matches(matches);
+                    let mut loggers = explo_opts.loggers;
+                    let stats_logger_id = loggers.len();
+                    if let HibouOutputFormat::Json = output_format {
+                        loggers.push(Box::new(GenericProcessStatsLogger::<ExplorationConfig>::new()));
+                    }
+                    // ***
                     let explo_ctx = ExplorationContext::new(gen_ctx);
                     let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
 
@@ -75,7 +101,7 @@ pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
                                                                                                                         ExplorationParameterization{},
                                                                                                                   delegate,
                                                                                                                         explo_opts.filters,
-                                                                                                                        explo_opts.loggers,
+                                                                                                                        loggers,
                                                                                                                   None,
                                                                                                                         explo_opts.use_memoization);
 
@@ -83,8 +109,18 @@ pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
                     let init_node = ExplorationNodeKind::new(int,0);
                     // ***
                     let now = Instant::now();
-                    let (node_count,_) = exploration_manager.start_process(init_node);
+                    let (node_count,verdict) = exploration_manager.start_process(init_node);
                     let elapsed_time = now.elapsed();
+                    if let HibouOutputFormat::Json = output_format {
+                        let stats : &GenericProcessStatsLogger<ExplorationConfig> = exploration_manager.get_logger(stats_logger_id).unwrap()
+                            .as_any().downcast_ref::<GenericProcessStatsLogger<ExplorationConfig>>().unwrap();
+                        let json = json_object(vec![("command",json_string("explore")),
+                                                    ("interaction",json_string(hif_file_path)),
+                                                    ("verdict",json_string(&verdict.to_string())),
+                                                    ("options",process_options_as_json(&stats.strategy,stats)),
+                                                    ("statistics",process_statistics_as_json(stats,elapsed_time.as_secs_f64()))]);
+                        return (vec![json],0);
+                    }
                     ret_print.push( format!("node count : {:?}", node_count ) );
                     ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
                     return (ret_print,0);
@@ -0,0 +1,136 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_rust_monitor::monitor_as_rust_module;
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::monitor::checks::get_checked_traces;
+use crate::nfa_translation::monitor::table::MonitorTable;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::process::explo::loggers::nfait::printer::ActionNFAITPrinter;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+
+
+pub fn cli_gen_monitor(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let num_traces : usize = match matches.value_of("num_traces") {
+                        None => {
+                            20
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let max_length : usize = match matches.value_of("max_length") {
+                        None => {
+                            10
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let seed : u64 = match matches.value_of("seed") {
+                        None => {
+                            0
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u64>().unwrap()
+                        }
+                    };
+                    let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let output_file_path = match matches.value_of("output") {
+                        None => {
+                            format!("{}_monitor.rs", file_name)
+                        },
+                        Some( got ) => {
+                            got.to_string()
+                        }
+                    };
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    let (nfa,is_nfa_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int,&alphabet,matches.value_of("nfa"),max_states) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let table = MonitorTable::from_nfa(&nfa);
+                    let checked_traces = get_checked_traces(&gen_ctx,&int,&alphabet,&table,num_traces,max_length,seed);
+                    let disagreements : Vec<&Vec<usize>> = checked_traces.iter()
+                        .filter(|(word,verdict)| table.get_verdict(word) != *verdict)
+                        .map(|(word,_)| word)
+                        .collect();
+                    let module = monitor_as_rust_module(&gen_ctx,&alphabet,&table,&checked_traces,hif_file_path);
+                    let mut file = File::create(&output_file_path).unwrap();
+                    file.write(module.as_bytes());
+                    // ***
+                    let num_transitions : usize = table.transitions.iter().map(|x| x.len()).sum();
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING RUNTIME MONITOR".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("on file : {}",output_file_path) );
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("{:} states, {:} transitions, {:} events", table.num_states, num_transitions, alphabet.len()) );
+                    ret_print.push( format!("{:} traces checked against the analysis of the interaction", checked_traces.len()) );
+                    ret_print.push( "".to_string());
+                    if !is_nfa_complete {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states", max_states) );
+                    }
+                    if disagreements.is_empty() {
+                        return (ret_print,0);
+                    }
+                    let printer = ActionNFAITPrinter::new(alphabet,gen_ctx);
+                    for word in disagreements {
+                        let word_as_strings : Vec<String> = word.iter().map(|x| printer.get_letter_string_repr(x)).collect();
+                        ret_print.push( format!("the monitor disagrees with the analysis on '{:}'", word_as_strings.join(".")) );
+                    }
+                    ret_print.push( "".to_string());
+                    return (ret_print,1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::output::to_json::near_miss::write_near_miss_manifest_into_json_file;
+use crate::process::ana::manager::analyze_multi_trace_without_loggers;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::near_miss::generate::generate_near_miss_mutants;
+use crate::trace_manip::near_miss::mutation::NearMissMutationKind;
+
+
+fn get_ana_options(gen_ctx : &GeneralContext,
+                   hcf_file_path : Option<&str>) -> HibouAnalyzeOptions {
+    match hcf_file_path {
+        None => {
+            HibouAnalyzeOptions::default()
+        },
+        Some( hcf_path ) => {
+            parse_hcf_file_for_ana(gen_ctx,hcf_path).unwrap()
+        }
+    }
+}
+
+pub fn cli_gen_negatives(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let hcf_file_path = matches.value_of("hcf");
+                    if let Some(hcf_path) = hcf_file_path {
+                        if let Err(e) = parse_hcf_file_for_ana(&gen_ctx,hcf_path) {
+                            return (vec![e.to_string()],1);
+                        }
+                    }
+                    let num_tries : u32 = match matches.value_of("num_tries") {
+                        None => {
+                            5
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let kinds : Vec<NearMissMutationKind> = match matches.value_of("mutations") {
+                        None => {
+                            NearMissMutationKind::all()
+                        },
+                        Some( as_str ) => {
+                            let mut kinds = vec![];
+                            for kind_str in as_str.split(',') {
+                                match NearMissMutationKind::all().into_iter().find(|k| k.to_string() == kind_str.trim()) {
+                                    None => {
+                                        return (vec![format!("unknown mutation '{:}'", kind_str)],1);
+                                    },
+                                    Some(kind) => {
+                                        kinds.push(kind);
+                                    }
+                                }
+                            }
+                            kinds
+                        }
+                    };
+                    let seed : u64 = match matches.value_of("seed") {
+                        None => {
+                            0
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u64>().unwrap()
+                        }
+                    };
+                    // ***
+                    let traces_folder = matches.value_of("traces").unwrap();
+                    let mut htf_file_paths : Vec<PathBuf> = vec![];
+                    match fs::read_dir(traces_folder) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( entries ) => {
+                            for entry in entries {
+                                let path = entry.unwrap().path();
+                                if path.extension().map_or(false, |ext| ext == HIBOU_TRACE_FILE_EXTENSION) {
+                                    htf_file_paths.push(path);
+                                }
+                            }
+                        }
+                    }
+                    htf_file_paths.sort();
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING NEAR-MISS NEGATIVE (MULTI-)TRACES".to_string());
+                    ret_print.push( format!("from the traces of folder '{}'",traces_folder) );
+                    ret_print.push( format!("for interaction from file '{}'",hif_file_path) );
+                    ret_print.push( "".to_string());
+                    // ***
+                    let mut co_localizations = None;
+                    let mut sources : Vec<String> = vec![];
+                    let mut corpus : Vec<MultiTrace> = vec![];
+                    for htf_file_path in &htf_file_paths {
+                        let htf_file_str = htf_file_path.to_str().unwrap();
+                        let (colocs,multi_trace) = match parse_htf_file(&gen_ctx,htf_file_str) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( got ) => {
+                                got
+                            }
+                        };
+                        match &co_localizations {
+                            None => {
+                                co_localizations = Some(colocs.clone());
+                            },
+                            Some(first_colocs) => {
+                                if *first_colocs != colocs {
+                                    return (vec![format!("trace file '{}' is not defined on the same co-localizations as the previous ones", htf_file_str)],1);
+                                }
+                            }
+                        }
+                        let (_,verdict) = analyze_multi_trace_without_loggers(&gen_ctx,&int,&colocs,&multi_trace,get_ana_options(&gen_ctx,hcf_file_path));
+                        match verdict {
+                            AnalysisGlobalVerdict::Pass | AnalysisGlobalVerdict::WeakPass => {
+                                sources.push(htf_file_str.to_string());
+                                corpus.push(multi_trace);
+                            },
+                            _ => {
+                                ret_print.push( format!("ignoring '{}' which is not accepted ({})", htf_file_str, verdict) );
+                            }
+                        }
+                    }
+                    let co_localizations = match co_localizations {
+                        None => {
+                            return (vec![format!("no trace file in folder '{}'", traces_folder)],1);
+                        },
+                        Some(got) => {
+                            got
+                        }
+                    };
+                    // ***
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let (mutants,discarded) = generate_near_miss_mutants(&gen_ctx,
+                                                                         &int,
+                                                                         &co_localizations,
+                                                                         &corpus,
+                                                                         &kinds,
+                                                                         num_tries,
+                                                                         &|| get_ana_options(&gen_ctx,hcf_file_path),
+                                                                         &mut rng);
+                    // ***
+                    let int_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let output_folder : String = if matches.is_present("folder") {
+                        matches.value_of("folder").unwrap().to_string()
+                    } else {
+                        format!("{:}_negatives", int_name)
+                    };
+                    fs::create_dir_all(&output_folder).unwrap();
+                    let mut named_mutants = vec![];
+                    for (mutant_id,mutant) in mutants.into_iter().enumerate() {
+                        let source_name = Path::new(sources.get(mutant.source_id).unwrap()).file_stem().unwrap().to_str().unwrap();
+                        let file_name = format!("{:}_{:}{:}.{:}", source_name, mutant.kind, mutant_id, HIBOU_TRACE_FILE_EXTENSION);
+                        let path : PathBuf = [&output_folder, &file_name].iter().collect();
+                        write_multi_trace_into_file(path.as_path(),
+                                                    &gen_ctx,
+                                                    &co_localizations,
+                                                    &mutant.multi_trace);
+                        named_mutants.push((file_name,mutant));
+                    }
+                    let manifest_path : PathBuf = [&output_folder, "manifest.json"].iter().collect();
+                    write_near_miss_manifest_into_json_file(manifest_path.as_path(),
+                                                            &gen_ctx,
+                                                            hif_file_path,
+                                                            &co_localizations,
+                                                            &sources,
+                                                            &named_mutants,
+                                                            discarded);
+                    // ***
+                    for kind in &kinds {
+                        let num = named_mutants.iter().filter(|(_,m)| m.kind == *kind).count();
+                        ret_print.push( format!("{:} : {:} rejected mutants", kind, num) );
+                    }
+                    ret_print.push( format!("discarded {:} mutants which were not rejected", discarded) );
+                    ret_print.push( format!("wrote the mutants and their manifest in folder '{:}'", output_folder) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use crate::core::execution::trace::multitrace::trace_as_multi_trace;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::io::output::to_json::test_suite::write_test_suite_manifest_into_json_file;
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::trace_manip::test_suite::candidates::{get_interpretation_test_candidates, get_nfa_test_candidates};
+use crate::trace_manip::test_suite::criterion::TestCoverageCriterion;
+use crate::trace_manip::test_suite::select::select_test_suite;
+use crate::ui::util::colocs::parse_co_localizations_arg;
+
+
+pub fn cli_gen_tests(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations = match parse_co_localizations_arg(&gen_ctx,matches.value_of("partition")) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let criterion = match matches.value_of("criterion") {
+                        None | Some("actions") => {
+                            TestCoverageCriterion::Actions
+                        },
+                        Some("alts") => {
+                            TestCoverageCriterion::AltBranches
+                        },
+                        Some("loops") => {
+                            TestCoverageCriterion::Loops
+                        },
+                        Some("nfa") => {
+                            TestCoverageCriterion::NfaTransitions
+                        },
+                        Some(other) => {
+                            return (vec![format!("unknown test generation criterion '{:}'", other)],1);
+                        }
+                    };
+                    let max_length : u32 = match matches.value_of("max_length") {
+                        None => {
+                            20
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    // ***
+                    let problem = match criterion {
+                        TestCoverageCriterion::NfaTransitions => {
+                            get_nfa_test_candidates(&int,&get_alphabet_from_gen_ctx(&gen_ctx),max_states)
+                        },
+                        _ => {
+                            match get_interpretation_test_candidates(&int,criterion,max_length,max_states) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( got ) => {
+                                    got
+                                }
+                            }
+                        }
+                    };
+                    let selected = select_test_suite(&problem);
+                    // ***
+                    let int_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let output_folder : String = if matches.is_present("folder") {
+                        matches.value_of("folder").unwrap().to_string()
+                    } else {
+                        format!("{:}_tests", int_name)
+                    };
+                    fs::create_dir_all(&output_folder).unwrap();
+                    let mut tests = vec![];
+                    for (test_id,cand_id) in selected.iter().enumerate() {
+                        let candidate = problem.candidates.get(*cand_id).unwrap();
+                        let file_name = format!("{:}_{:}{:}.{:}", int_name, criterion, test_id, HIBOU_TRACE_FILE_EXTENSION);
+                        let path : PathBuf = [&output_folder, &file_name].iter().collect();
+                        write_multi_trace_into_file(path.as_path(),
+                                                    &gen_ctx,
+                                                    &co_localizations,
+                                                    &trace_as_multi_trace(&candidate.trace,&co_localizations));
+                        tests.push((file_name,*cand_id));
+                    }
+                    let manifest_path : PathBuf = [&output_folder, "manifest.json"].iter().collect();
+                    write_test_suite_manifest_into_json_file(manifest_path.as_path(),
+                                                             &gen_ctx,
+                                                             hif_file_path,
+                                                             &problem,
+                                                             &tests);
+                    // ***
+                    let mut covered_items : Vec<usize> = selected.iter()
+                        .flat_map(|cand_id| problem.candidates.get(*cand_id).unwrap().covered_items.iter().cloned())
+                        .collect();
+                    covered_items.sort();
+                    covered_items.dedup();
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING A TEST SUITE".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("with coverage criterion '{}'",criterion) );
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("{:} accepted traces cover {:} of the {:} criterion items",
+                                            selected.len(), covered_items.len(), problem.items.len()) );
+                    if !problem.is_complete {
+                        ret_print.push( format!("WARNING : the search was stopped after exploring {:} states", max_states) );
+                    }
+                    ret_print.push( format!("wrote the traces and their manifest in folder '{:}'", output_folder) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -15,16 +15,20 @@ limitations under the License.
 */
 
 
+use std::time::Instant;
+
 use autour_core::nfa::nfa::AutNFA;
 use autour_core::traits::translate::AutTranslatable;
 use clap::ArgMatches;
-use crate::core::language::syntax::metrics::InteractionMetrics;
+use crate::core::language::syntax::metrics::{InteractionMetrics, SymbolKind};
 use crate::io::input::hif::interface::parse_hif_file;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
 use crate::nfa_translation::canonize::canonize_interaction;
 use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
 use crate::process::canon::param::default::DefaultCanonizationProcess;
+use crate::io::output::to_json::util::{json_object, json_string};
+use crate::ui::util::output::{HIBOU_EXIT_CODE_ERROR, HibouOutputFormat};
 
 
 pub fn cli_get_metrics(matches : &ArgMatches) -> (Vec<String>, u32) {
@@ -48,17 +52,27 @@ pub fn cli_get_metrics(matches : &ArgMatches) -> (Vec<String>, u32) {
                         numbers[mid]
                     }
 
-                    let int = canonize_interaction(&gen_ctx,&int,DefaultCanonizationProcess::BasicWithUnfoldActions);
+                    let output_format = HibouOutputFormat::from_matches(matches);
+                    let now = Instant::now();
+
+                    let int = canonize_interaction(&gen_ctx,&int,DefaultCanonizationProcess::BasicWithUnfoldActions,false);
 
                     let mut ret_print = vec![];
                     ret_print.push( "".to_string());
                     ret_print.push( format!("for interaction from file '{}'",hif_file_path) );
 
-                    match matches.value_of("kind").unwrap() {
+                    let metrics_kind = matches.value_of("kind").unwrap();
+                    let mut metrics_json : Vec<(&str,String)> = vec![];
+                    match metrics_kind {
                         "INT" => {
                             ret_print.push( "GETTING INTERACTION METRICS".to_string());
                             let imetrics = InteractionMetrics::extract_from_interaction(&int);
                             ret_print.append(&mut imetrics.string_summary());
+                            metrics_json.push(("depth",imetrics.depth.to_string()));
+                            metrics_json.push(("loop_depth",imetrics.max_nested_loop_depth.to_string()));
+                            metrics_json.push(("lifelines",imetrics.lifelines.len().to_string()));
+                            metrics_json.push(("actions",imetrics.symbols.get(&SymbolKind::Action).unwrap().to_string()));
+                            metrics_json.push(("symbols",imetrics.get_num_symbols().to_string()));
                         },
                         "operatNFA" => {
                             let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
@@ -71,7 +85,9 @@ pub fn cli_get_metrics(matches : &ArgMatches) -> (Vec<String>, u32) {
                                                                                           alphabet.clone());
                                 let in_micros = duration.as_micros();
                                 nfa = got_nfa;
-                                println!("via exploration translated interaction into nfa of {:} states in {:}μs", nfa.transitions.len(), in_micros);
+                                if let HibouOutputFormat::Text = output_format {
+                                    println!("via exploration translated interaction into nfa of {:} states in {:}μs", nfa.transitions.len(), in_micros);
+                                }
                                 durations.push(in_micros);
                             }
 
@@ -86,6 +102,9 @@ pub fn cli_get_metrics(matches : &ArgMatches) -> (Vec<String>, u32) {
                             ret_print.push( format!("number of states              : {:}", num_states));
                             ret_print.push( format!("number of edges               : {:}", num_edges));
                             ret_print.push( format!("synthesis time (median of 5)  : {:}", median_duration));
+                            metrics_json.push(("states",num_states.to_string()));
+                            metrics_json.push(("edges",num_edges.to_string()));
+                            metrics_json.push(("median_synthesis_micros",median_duration.to_string()));
                         },
                         "minDFA" => {
                             let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
@@ -102,13 +121,26 @@ pub fn cli_get_metrics(matches : &ArgMatches) -> (Vec<String>, u32) {
                             ret_print.push( "GETTING DFA METRICS".to_string());
                             ret_print.push( format!("number of states              : {:}", num_states));
                             ret_print.push( format!("number of edges               : {:}", num_edges));
+                            metrics_json.push(("states",num_states.to_string()));
+                            metrics_json.push(("edges",num_edges.to_string()));
                         },
                         x => {
                             ret_print.push( format!("unkown metrics : {:}", x));
                             ret_print.push( "".to_string());
                             ret_print.push( "requires either of INT, operatNFA or minDFA".to_string());
+                            return (ret_print,HIBOU_EXIT_CODE_ERROR);
                         }
                     }
+                    let elapsed_time = now.elapsed();
+                    if let HibouOutputFormat::Json = output_format {
+                        let json = json_object(vec![("command",json_string("get_metrics")),
+                                                    ("interaction",json_string(hif_file_path)),
+                                                    ("verdict","null".to_string()),
+                                                    ("options",json_object(vec![("kind",json_string(metrics_kind))])),
+                                                    ("statistics",json_object(vec![("elapsed",format!("{:?}", elapsed_time.as_secs_f64()))])),
+                                                    ("metrics",json_object(metrics_json))]);
+                        return (vec![json],0);
+                    }
                     ret_print.push( "".to_string());
                     return (ret_print,0);
                 }
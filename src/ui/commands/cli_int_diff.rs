@@ -0,0 +1,107 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use clap::ArgMatches;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::diff::diff::{diff_interactions, InteractionDiffItem};
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::draw_interactions::interface::draw_highlighted_interactions_side_by_side_as_sd;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+use crate::nfa_translation::canonize::canonize_interaction;
+use crate::process::canon::param::default::DefaultCanonizationProcess;
+
+
+pub fn cli_int_diff(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut ints = vec![];
+    for hif_arg in ["hif1","hif2"] {
+        match parse_hif_file(&gen_ctx,matches.value_of(hif_arg).unwrap()) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( got ) => {
+                if matches.is_present("canonize") {
+                    ints.push(canonize_interaction(&gen_ctx,&got,DefaultCanonizationProcess::FivePhases,false));
+                } else {
+                    ints.push(got);
+                }
+            }
+        }
+    }
+    let output_name = matches.value_of("output").unwrap_or("diff").to_string();
+    // ***
+    let diff = diff_interactions(&ints[0],&ints[1]);
+    // ***
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "STRUCTURAL DIFF of INTERACTIONS".to_string());
+    ret_print.push( format!("from files '{}' and '{}'",matches.value_of("hif1").unwrap(),matches.value_of("hif2").unwrap()) );
+    if matches.is_present("canonize") {
+        ret_print.push( "after canonization".to_string() );
+    }
+    ret_print.push( "".to_string());
+    for item in &diff {
+        match item {
+            InteractionDiffItem::Removed(pos,sub_int) => {
+                ret_print.push( format!("- removed at {:} : {:}",
+                                        position_as_hwf_encoding(pos),
+                                        interaction_as_single_line(&gen_ctx,sub_int)) );
+            },
+            InteractionDiffItem::Inserted(pos,sub_int) => {
+                ret_print.push( format!("+ inserted at {:} : {:}",
+                                        position_as_hwf_encoding(pos),
+                                        interaction_as_single_line(&gen_ctx,sub_int)) );
+            },
+            InteractionDiffItem::Moved(pos1,pos2,sub_int) => {
+                ret_print.push( format!("~ moved from {:} to {:} : {:}",
+                                        position_as_hwf_encoding(pos1),
+                                        position_as_hwf_encoding(pos2),
+                                        interaction_as_single_line(&gen_ctx,sub_int)) );
+            }
+        }
+    }
+    if diff.is_empty() {
+        ret_print.push( "both interactions are identical".to_string() );
+    } else {
+        ret_print.push( format!("{:} difference(s) found", diff.len()) );
+    }
+    draw_highlighted_interactions_side_by_side_as_sd(&gen_ctx,
+                                                     (&ints[0],&SequenceDiagramHighlighting::new_from_diff(&diff,true)),
+                                                     (&ints[1],&SequenceDiagramHighlighting::new_from_diff(&diff,false)),
+                                                     &".".to_string(),
+                                                     &output_name);
+    ret_print.push( format!("drawn into '{:}.png'", output_name) );
+    ret_print.push( "".to_string());
+    return (ret_print,0);
+}
+
+fn interaction_as_single_line(gen_ctx : &GeneralContext, interaction : &Interaction) -> String {
+    return interaction_as_hif_encoding(gen_ctx,interaction).lines().map(|line| line.trim()).collect();
+}
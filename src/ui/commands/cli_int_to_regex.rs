@@ -0,0 +1,167 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use autour_core::bre::bre::ExpBRE;
+use autour_core::traits::repr::{AbstractLanguagePrinter, ExpBREPrintable};
+use autour_core::traits::translate::AutTranslatable;
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::compare::get_shortest_word_in_difference;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::nfa_translation::regex::elimination::get_regex_from_nfa;
+use crate::nfa_translation::regex::simplify::{get_regex_size, simplify_regex};
+use crate::nfa_translation::regex::structural::get_regex_from_interaction_structure;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::explo::loggers::nfait::printer::ActionNFAITPrinter;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+use crate::ui::util::output::{analysis_verdict_exit_code, HibouOutputFormat};
+
+
+pub fn cli_int_to_regex(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let now = Instant::now();
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    let letters : HashSet<usize> = (0..alphabet.len()).collect();
+                    let (nfa,is_nfa_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int,&alphabet,matches.value_of("nfa"),max_states) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    // ***
+                    let structural = match matches.value_of("source") {
+                        None | Some("auto") => {
+                            get_regex_from_interaction_structure(&int,&alphabet)
+                        },
+                        Some("structure") => {
+                            match get_regex_from_interaction_structure(&int,&alphabet) {
+                                None => {
+                                    return (vec!["the interaction is not only made of actions, strict sequencing, alternatives and loopS".to_string()],1);
+                                },
+                                Some( got ) => {
+                                    Some(got)
+                                }
+                            }
+                        },
+                        Some("nfa") => {
+                            None
+                        },
+                        Some(other) => {
+                            return (vec![format!("unknown regular expression source '{:}'", other)],1);
+                        }
+                    };
+                    let (source,raw_regex) = match structural {
+                        None => {
+                            ("nfa",get_regex_from_nfa(&nfa))
+                        },
+                        Some( got ) => {
+                            ("structure",got)
+                        }
+                    };
+                    let raw_size = get_regex_size(&raw_regex);
+                    let regex = simplify_regex(raw_regex);
+                    let regex_size = get_regex_size(&regex);
+                    // ***
+                    let regex_nfa = ExpBRE::from_raw(letters,regex.clone()).unwrap().to_nfa();
+                    let counterexample = match get_shortest_word_in_difference(&nfa,&regex_nfa) {
+                        None => {
+                            get_shortest_word_in_difference(&regex_nfa,&nfa)
+                        },
+                        Some( word ) => {
+                            Some(word)
+                        }
+                    };
+                    let elapsed_time = now.elapsed();
+                    let printer = ActionNFAITPrinter::new(alphabet,gen_ctx);
+                    let regex_as_string = regex.regexp_to_string(false,&printer);
+                    let ret_code = if counterexample.is_none() {0} else {analysis_verdict_exit_code(&AnalysisGlobalVerdict::Fail)};
+                    // ***
+                    if let HibouOutputFormat::Json = HibouOutputFormat::from_matches(matches) {
+                        let mut fields = vec![("command",json_string("int_to_regex")),
+                                              ("interaction",json_string(hif_file_path)),
+                                              ("verdict","null".to_string()),
+                                              ("options",json_object(vec![("source",json_string(matches.value_of("source").unwrap_or("auto"))),
+                                                                          ("nfa",json_string(matches.value_of("nfa").unwrap_or("exploration"))),
+                                                                          ("max_states",max_states.to_string())])),
+                                              ("statistics",json_object(vec![("raw_size",raw_size.to_string()),
+                                                                             ("size",regex_size.to_string()),
+                                                                             ("is_nfa_complete",is_nfa_complete.to_string()),
+                                                                             ("elapsed",format!("{:?}", elapsed_time.as_secs_f64()))])),
+                                              ("built_from",json_string(source)),
+                                              ("regex",json_string(&regex_as_string)),
+                                              ("is_equivalent",counterexample.is_none().to_string())];
+                        if let Some(word) = &counterexample {
+                            let word_as_strings : Vec<String> = word.iter().map(|x| json_string(&printer.get_letter_string_repr(x))).collect();
+                            fields.push(("counterexample",json_array(word_as_strings)));
+                        }
+                        return (vec![json_object(fields)],ret_code);
+                    }
+                    let mut ret_print = vec![];
+                    ret_print.push( "INTERACTION AS A REGULAR EXPRESSION".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("built from the {:}, simplified from size {:} to {:}", if source == "nfa" {"NFA"} else {"structure of the interaction"}, raw_size, regex_size) );
+                    ret_print.push( "".to_string());
+                    ret_print.push( regex_as_string );
+                    ret_print.push( "".to_string());
+                    if !is_nfa_complete {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states", max_states) );
+                    }
+                    match &counterexample {
+                        None => {
+                            ret_print.push( "the regular expression denotes the same language as the NFA of the interaction".to_string() );
+                        },
+                        Some( word ) => {
+                            let word_as_strings : Vec<String> = word.iter().map(|x| printer.get_letter_string_repr(x)).collect();
+                            ret_print.push( format!("the regular expression and the NFA of the interaction differ on '{:}'", word_as_strings.join(".")) );
+                        }
+                    }
+                    ret_print.push( "".to_string());
+                    return (ret_print,ret_code);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::compare::get_shortest_word_in_difference;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::inconc::InconcReason;
+use crate::process::explo::loggers::nfait::printer::ActionNFAITPrinter;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+use crate::ui::util::output::{analysis_verdict_exit_code, HibouOutputFormat};
+
+
+pub fn cli_equiv(matches : &ArgMatches) -> (Vec<String>,u32) {
+    compare_interactions_languages(matches,true)
+}
+
+pub fn cli_includes(matches : &ArgMatches) -> (Vec<String>,u32) {
+    compare_interactions_languages(matches,false)
+}
+
+/**
+ * Decides whether the trace language of the first interaction includes (or is equal to) that of the second one.
+ * If not, a shortest distinguishing trace is given as counterexample.
+ **/
+fn compare_interactions_languages(matches : &ArgMatches, is_equiv : bool) -> (Vec<String>,u32) {
+    let command_name = if is_equiv {"equiv"} else {"includes"};
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let hif1_file_path = matches.value_of("hif1").unwrap();
+    let int1 = match parse_hif_file(&gen_ctx,hif1_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let hif2_file_path = matches.value_of("hif2").unwrap();
+    let int2 = match parse_hif_file(&gen_ctx,hif2_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let max_states : usize = match matches.value_of("max_states") {
+        None => {
+            DEFAULT_NFA_MAX_STATES_NUM
+        },
+        Some( as_str ) => {
+            as_str.trim().parse::<usize>().unwrap()
+        }
+    };
+    let now = Instant::now();
+    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+    let (nfa1,is_nfa1_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int1,&alphabet,matches.value_of("nfa"),max_states) {
+        Err(e) => {
+            return (vec![e],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let (nfa2,is_nfa2_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int2,&alphabet,matches.value_of("nfa"),max_states) {
+        Err(e) => {
+            return (vec![e],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    // ***
+    // a trace of the second interaction that the first one does not accept contradicts the inclusion
+    let missing_from_first = get_shortest_word_in_difference(&nfa2,&nfa1);
+    let missing_from_second = if is_equiv {
+        get_shortest_word_in_difference(&nfa1,&nfa2)
+    } else {
+        None
+    };
+    // the shortest of both counterexamples, with the index of the only interaction accepting it
+    let counterexample = match (missing_from_first,missing_from_second) {
+        (None,None) => {
+            None
+        },
+        (Some(word),None) => {
+            Some((word,2))
+        },
+        (None,Some(word)) => {
+            Some((word,1))
+        },
+        (Some(word2),Some(word1)) => {
+            if word1.len() < word2.len() {
+                Some((word1,1))
+            } else {
+                Some((word2,2))
+            }
+        }
+    };
+    let elapsed_time = now.elapsed();
+    // with a truncated NFA, counterexamples may be spurious and languages may differ beyond the bound
+    let verdict = if !(is_nfa1_complete && is_nfa2_complete) {
+        AnalysisGlobalVerdict::Inconc(InconcReason::FilteredNodes)
+    } else if counterexample.is_none() {
+        AnalysisGlobalVerdict::Pass
+    } else {
+        AnalysisGlobalVerdict::Fail
+    };
+    let exit_code = analysis_verdict_exit_code(&verdict);
+    let printer = ActionNFAITPrinter::new(alphabet,gen_ctx);
+    // ***
+    if let HibouOutputFormat::Json = HibouOutputFormat::from_matches(matches) {
+        let mut fields = vec![("command",json_string(command_name)),
+                              ("interaction",json_string(hif1_file_path)),
+                              ("other_interaction",json_string(hif2_file_path)),
+                              ("verdict",json_string(&verdict.to_string())),
+                              ("options",json_object(vec![("nfa",json_string(matches.value_of("nfa").unwrap_or("exploration"))),
+                                                          ("max_states",max_states.to_string())])),
+                              ("statistics",json_object(vec![("nfa_states",json_array(vec![nfa1.transitions.len().to_string(),nfa2.transitions.len().to_string()])),
+                                                             ("is_nfa_complete",(is_nfa1_complete && is_nfa2_complete).to_string()),
+                                                             ("elapsed",format!("{:?}", elapsed_time.as_secs_f64()))]))];
+        match &counterexample {
+            None => {
+                fields.push(("counterexample","null".to_string()));
+                fields.push(("accepted_by","null".to_string()));
+            },
+            Some((word,accepted_by)) => {
+                let word_as_strings : Vec<String> = word.iter().map(|x| json_string(&printer.get_letter_string_repr(x))).collect();
+                fields.push(("counterexample",json_array(word_as_strings)));
+                fields.push(("accepted_by",accepted_by.to_string()));
+            }
+        }
+        return (vec![json_object(fields)],exit_code);
+    }
+    let mut ret_print = vec![];
+    if is_equiv {
+        ret_print.push( "TRACE LANGUAGE EQUIVALENCE".to_string());
+    } else {
+        ret_print.push( "TRACE LANGUAGE INCLUSION".to_string());
+    }
+    ret_print.push( format!("of interaction from file '{}'",hif1_file_path) );
+    ret_print.push( format!("and interaction from file '{}'",hif2_file_path) );
+    ret_print.push( "".to_string());
+    if !(is_nfa1_complete && is_nfa2_complete) {
+        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states", max_states) );
+    }
+    match &counterexample {
+        None => {
+            if is_equiv {
+                ret_print.push( "both interactions accept the same traces".to_string() );
+            } else {
+                ret_print.push( "all the traces accepted by the second interaction are accepted by the first one".to_string() );
+            }
+        },
+        Some((word,accepted_by)) => {
+            let word_as_strings : Vec<String> = word.iter().map(|x| printer.get_letter_string_repr(x)).collect();
+            let trace_as_string = if word.is_empty() {"the empty trace".to_string()} else {format!("'{:}'", word_as_strings.join("."))};
+            ret_print.push( format!("{:} is accepted by interaction {:} only", trace_as_string, accepted_by) );
+        }
+    }
+    ret_print.push( format!("verdict : {}", verdict) );
+    ret_print.push( "".to_string());
+    return (ret_print,exit_code);
+}
@@ -15,43 +15,83 @@ limitations under the License.
 */
 
 
-use std::collections::HashSet;
-use std::fs::write;
 use std::path::Path;
 use std::time::Instant;
-use clap::App;
+
 use clap::ArgMatches;
 
+use crate::io::file_extensions::HIBOU_INTERACTION_FILE_EXTENSION;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+use crate::merge_gates::merge_check::check_models_for_merge;
+use crate::merge_gates::process::{DEFAULT_MERGE_MAX_NODES, merge_interactions};
 
-pub fn cli_merge(matches : &ArgMatches) -> (Vec<String>,u32) {
 
+pub fn cli_merge(matches : &ArgMatches) -> (Vec<String>,u32) {
     let hsf_file_path = matches.value_of("hsf").unwrap();
-    match parse_hsf_file(hsf_file_path,&ProcessKind::None) {
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
         Err(e) => {
-            ret_print.push( e.to_string() );
-            print_retval(ret_print);
-            return -1;
+            return (vec![e.to_string()],1);
         },
-        Ok( (gen_ctx,my_int,hoptions) ) => {
-            let file_name = Path::new(hsf_file_path).file_stem().unwrap().to_str().unwrap();
-            //
-            let opt_returns : bool = matches.is_present("returns");
-            let opt_complete : bool = matches.is_present("complete");
-            let opt_graphic : bool = matches.is_present("graphic");
-            // ***
-            // ***
-            if my_int.has_coregions() {
-                ret_print.push( "Interaction term has coregions -> Not Implemented".to_string() );
-                print_retval(ret_print);
-                return -1;
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut ints = vec![];
+    for hif_arg in ["hif1","hif2"] {
+        match parse_hif_file(&gen_ctx,matches.value_of(hif_arg).unwrap()) {
+            Err(e) => {
+                return (vec![e.to_string()],1);
+            },
+            Ok( got ) => {
+                ints.push(got);
             }
-            merge_process_interaction_term(&my_int,&gen_ctx,opt_returns,opt_complete,opt_graphic,&file_name);
-            // ***
-            ret_print.push( "".to_string());
-            ret_print.push( "MERGING process for INTERACTION".to_string());
-            ret_print.push( format!("from file '{}'",hsf_file_path) );
-            ret_print.push( "".to_string());
-            ret_print.push( "".to_string());
         }
     }
-}
\ No newline at end of file
+    if let Err(e) = check_models_for_merge(&ints[0],&ints[1]) {
+        return (vec![e],1);
+    }
+    let max_nodes : u32 = match matches.value_of("max_nodes") {
+        None => {
+            DEFAULT_MERGE_MAX_NODES
+        },
+        Some( as_str ) => {
+            as_str.trim().parse::<u32>().unwrap()
+        }
+    };
+    let output_name = matches.value_of("output").unwrap_or("merged");
+    // ***
+    let now = Instant::now();
+    let result = merge_interactions(&ints[0],&ints[1],max_nodes);
+    let elapsed_time = now.elapsed();
+    // ***
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "MERGING INTERACTIONS through GATES".to_string());
+    ret_print.push( format!("from files '{}' and '{}'",matches.value_of("hif1").unwrap(),matches.value_of("hif2").unwrap()) );
+    ret_print.push( "".to_string());
+    ret_print.push( format!("node count : {:?}", result.node_count ) );
+    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+    if !result.is_complete {
+        ret_print.push( format!("WARNING : the exploration was stopped after {:} nodes", max_nodes) );
+    }
+    if result.merged.is_empty() {
+        ret_print.push( "no composition found".to_string() );
+        return (ret_print,1);
+    }
+    ret_print.push( format!("{:} distinct composition(s) found", result.merged.len()) );
+    if matches.is_present("all") {
+        for (x,merged) in result.merged.iter().enumerate() {
+            let file_name = format!("{:}_{:}.{:}", output_name, x+1, HIBOU_INTERACTION_FILE_EXTENSION);
+            interaction_to_hif(Path::new(&file_name),&gen_ctx,merged);
+            ret_print.push( format!("wrote to file '{}'", file_name) );
+        }
+    } else {
+        let file_name = format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION);
+        interaction_to_hif(Path::new(&file_name),&gen_ctx,result.merged.first().unwrap());
+        ret_print.push( format!("wrote to file '{}'", file_name) );
+    }
+    ret_print.push( "".to_string());
+    return (ret_print,0);
+}
@@ -0,0 +1,108 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::core::transformation::minimize::{DEFAULT_MINIMIZE_MAX_NODES, minimize_interaction};
+use crate::io::file_extensions::HIBOU_INTERACTION_FILE_EXTENSION;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+use crate::nfa_translation::compare::interactions_accept_same_traces;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::ui::util::output::analysis_verdict_exit_code;
+
+
+pub fn cli_minimize(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let hif_file_path = matches.value_of("hif").unwrap();
+    let int = match parse_hif_file(&gen_ctx,hif_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let max_nodes : u32 = match matches.value_of("max_nodes") {
+        None => {
+            DEFAULT_MINIMIZE_MAX_NODES
+        },
+        Some( as_str ) => {
+            as_str.trim().parse::<u32>().unwrap()
+        }
+    };
+    let output_name = match matches.value_of("output") {
+        None => {
+            let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+            format!("{:}_min", file_name)
+        },
+        Some( got ) => {
+            got.to_string()
+        }
+    };
+    // ***
+    let now = Instant::now();
+    let result = minimize_interaction(&int,max_nodes);
+    let elapsed_time = now.elapsed();
+    // ***
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "MINIMIZING INTERACTION".to_string());
+    ret_print.push( format!("from file '{}'",hif_file_path) );
+    ret_print.push( "".to_string());
+    ret_print.push( format!("node count : {:?}", result.node_count ) );
+    ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+    if !result.is_complete {
+        ret_print.push( format!("WARNING : the search was stopped after {:} nodes", max_nodes) );
+    }
+    let reduction = result.initial_size - result.minimal_size;
+    ret_print.push( format!("size reduced from {:} to {:} symbols ({:.1}%)",
+                            result.initial_size,
+                            result.minimal_size,
+                            100.0 * (reduction as f64) / (result.initial_size as f64)) );
+    // the transformations preserve the traces, which is checked on the NFAs of both interactions
+    let verdict = if interactions_accept_same_traces(&gen_ctx,&int,&result.minimal) {
+        AnalysisGlobalVerdict::Pass
+    } else {
+        AnalysisGlobalVerdict::Fail
+    };
+    match verdict {
+        AnalysisGlobalVerdict::Pass => {
+            let file_name = format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION);
+            interaction_to_hif(Path::new(&file_name),&gen_ctx,&result.minimal);
+            ret_print.push( "both interactions accept the same traces".to_string() );
+            ret_print.push( format!("wrote to file '{}'", file_name) );
+        },
+        _ => {
+            ret_print.push( "ERROR : the minimized interaction does not accept the same traces, it is not written".to_string() );
+        }
+    }
+    ret_print.push( "".to_string());
+    return (ret_print,analysis_verdict_exit_code(&verdict));
+}
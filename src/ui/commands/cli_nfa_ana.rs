@@ -15,10 +15,10 @@ limitations under the License.
 */
 
 
-use std::collections::BTreeSet;
 use std::path::Path;
 use std::time::Instant;
 
+use autour_core::nfa::nfa::AutNFA;
 use autour_core::traits::repr::AutGraphvizDrawable;
 use autour_process::autana::conf::NfaWordAnalysisConfig;
 use autour_process::autana::context::NfaWordAnalysisContext;
@@ -43,17 +43,68 @@ use graphviz_dot_builder::item::node::node::GraphVizNode;
 use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape};
 use graphviz_dot_builder::traits::{DotBuildable, DotPrintable, GraphVizOutputFormat};
 
-use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::core::execution::trace::multitrace::{multi_trace_length, Trace};
+use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::hif::interface::parse_hif_file;
 use crate::io::input::htf::interface::parse_htf_file;
 use crate::io::output::draw_interactions::interface::{draw_interaction, InteractionGraphicalRepresentation};
 use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
-use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+use crate::nfa_translation::multitrace_ana::analysis::analyze_multi_trace_with_nfa;
+use crate::nfa_translation::multitrace_ana::kind::NfaMultiTraceAnalysisKind;
+use crate::nfa_translation::multitrace_ana::nfa::{DEFAULT_NFA_MAX_STATES_NUM, get_nfa_from_interaction_terms};
 use crate::process::explo::loggers::nfait::printer::ActionNFAITPrinter;
+use crate::io::output::to_json::util::{json_object, json_string};
+use crate::ui::util::output::{analysis_verdict_exit_code, HibouOutputFormat};
 
 
 
+// draws the analysis of a global trace against the NFA
+fn draw_global_trace_nfa_analysis(nfa : AutNFA<usize>,
+                                  printer : ActionNFAITPrinter,
+                                  trace : &Trace,
+                                  file_name : &str) {
+    let drawer = NfaWordAnalysisProcessDrawer::new("temp".to_string());
+    let graphic_logger : GenericGraphVizLogger<NfaWordAnalysisConfig<ActionNFAITPrinter>> = GenericGraphVizLogger::new(
+        Box::new(drawer),
+        GraphVizOutputFormat::svg,
+        GraphVizProcessLoggerLayout::Vertical,
+        true,
+        ".".to_string(),
+        format!("ana_{}",file_name));
+    let loggers: Vec<std::boxed::Box<(dyn AbstractProcessLogger<NfaWordAnalysisConfig<ActionNFAITPrinter>> + 'static)>> = vec![Box::new(graphic_logger)];
+
+    let param = NfaWordAnalysisParameterization::new(NfaWordAnalysisResetOn::Initials,
+                                                     NfaWordAnalysisPolicy::StopAtDeviation);
+    let init_node = param.make_init_node(&nfa);
+
+    let outside_letter = printer.index_to_action_map.len();
+
+    let word : Vec<usize> = trace.iter().map(
+        |x|
+            match printer.index_to_action_map.iter().position(|y| y == x) {
+                Some(y) => {y},
+                None => {outside_letter}
+            }
+    ).collect();
+    let process_ctx : NfaWordAnalysisContext<ActionNFAITPrinter> = NfaWordAnalysisContext::new(nfa,printer,word);
+    let priorities : GenericProcessPriorities<NfaWordAnalysisPriorities> = GenericProcessPriorities::new(NfaWordAnalysisPriorities{},false);
+    let delegate : GenericProcessDelegate<NfaWordAnalysisStepKind,NfaWordAnalysisNodeKind,NfaWordAnalysisPriorities> = GenericProcessDelegate::new(QueueSearchStrategy::BFS,
+                                                                                                                                                   priorities);
+
+    let mut manager : GenericProcessManager<NfaWordAnalysisConfig<ActionNFAITPrinter>> = GenericProcessManager::new(process_ctx,
+                                                                                                                param,
+                                                                                                                delegate,
+                                                                                                                vec![],
+                                                                                                                loggers,
+                                                                                                                None,
+                                                                                                                false);
+
+    manager.start_process(init_node);
+}
+
+
 pub fn cli_nfa_ana(matches : &ArgMatches) -> (Vec<String>,u32) {
     let hsf_file_path = matches.value_of("hsf").unwrap();
     match parse_hsf_file(hsf_file_path) {
@@ -74,19 +125,30 @@ pub fn cli_nfa_ana(matches : &ArgMatches) -> (Vec<String>,u32) {
                         Err(e) => {
                             return (vec![e.to_string()],1);
                         },
-                        Ok( (co_localizations,mut multi_trace) ) => {
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let ana_opts : HibouAnalyzeOptions;
+                            if matches.is_present("hcf") {
+                                let hcf_file_path = matches.value_of("hcf").unwrap();
+                                match parse_hcf_file_for_ana(&gen_ctx,hcf_file_path) {
+                                    Err(e) => {
+                                        return (vec![e.to_string()],1);
+                                    },
+                                    Ok( got_ana_opt) => {
+                                        ana_opts = got_ana_opt;
+                                    }
+                                }
+                            } else {
+                                ana_opts = HibouAnalyzeOptions::default();
+                            }
+                            let ana_kind = NfaMultiTraceAnalysisKind::from_analysis_kind(&ana_opts.ana_param.ana_kind);
                             // ***
                             let multi_trace_length = multi_trace_length(&multi_trace);
                             // ***
-                            if co_localizations.locs_lf_ids.len() != 1 {
-                                return (vec!["trace should be a global trace".to_string()],1);
-                            }
-                            let trace = multi_trace.remove(0);
-
-                            let (nfa,elapsed_get_nfa) = get_nfa_from_interaction_exploration(
-                                &gen_ctx,
-                                &int,
-                                get_alphabet_from_gen_ctx(&gen_ctx));
+                            let now = Instant::now();
+                            let (nfa,is_nfa_complete) = get_nfa_from_interaction_terms(&int,
+                                                                                       &get_alphabet_from_gen_ctx(&gen_ctx),
+                                                                                       DEFAULT_NFA_MAX_STATES_NUM);
+                            let elapsed_get_nfa = now.elapsed();
 
                             let printer = ActionNFAITPrinter::new(get_alphabet_from_gen_ctx(&gen_ctx),gen_ctx);
 
@@ -134,67 +196,71 @@ pub fn cli_nfa_ana(matches : &ArgMatches) -> (Vec<String>,u32) {
                                                 &GraphVizOutputFormat::svg);
                             }
 
-                            let loggers: Vec<std::boxed::Box<(dyn AbstractProcessLogger<NfaWordAnalysisConfig<ActionNFAITPrinter>> + 'static)>> = if matches.is_present("draw_analysis") {
-                                let drawer = NfaWordAnalysisProcessDrawer::new("temp".to_string());
-                                let graphic_logger : GenericGraphVizLogger<NfaWordAnalysisConfig<ActionNFAITPrinter>> = GenericGraphVizLogger::new(
-                                    Box::new(drawer),
-                                    GraphVizOutputFormat::svg,
-                                    GraphVizProcessLoggerLayout::Vertical,
-                                    true,
-                                    ".".to_string(),
-                                    format!("ana_{}",file_name));
-                                vec![Box::new(graphic_logger)]
-                            } else {
-                                vec![]
-                            };
-
-                            let param = NfaWordAnalysisParameterization::new(NfaWordAnalysisResetOn::Initials,
-                                                                             NfaWordAnalysisPolicy::StopAtDeviation);
-                            let init_node = param.make_init_node(&nfa);
-
-                            let outside_letter = printer.index_to_action_map.len();
-
-                            let word : Vec<usize> = trace.iter().map(
-                                |x|
-                                    match printer.index_to_action_map.iter().position(|y| y == x) {
-                                        Some(y) => {y},
-                                        None => {outside_letter}
-                                    }
-                            ).collect();
-                            let process_ctx : NfaWordAnalysisContext<ActionNFAITPrinter> = NfaWordAnalysisContext::new(nfa,printer,word);
-                            let priorities : GenericProcessPriorities<NfaWordAnalysisPriorities> = GenericProcessPriorities::new(NfaWordAnalysisPriorities{},false);
-                            let delegate : GenericProcessDelegate<NfaWordAnalysisStepKind,NfaWordAnalysisNodeKind,NfaWordAnalysisPriorities> = GenericProcessDelegate::new(QueueSearchStrategy::BFS,
-                                                                                                                                                                           priorities);
-
-                            let mut manager : GenericProcessManager<NfaWordAnalysisConfig<ActionNFAITPrinter>> = GenericProcessManager::new(process_ctx,
-                                                                                                                                        param,
-                                                                                                                                        delegate,
-                                                                                                                                        vec![],
-                                                                                                                                        loggers,
-                                                                                                                                        None,
-                                                                                                                                        false);
-
                             let now = Instant::now();
-                            let (_, verdict) = manager.start_process(init_node);
+                            let ana_result = analyze_multi_trace_with_nfa(&nfa,
+                                                                          &printer.index_to_action_map,
+                                                                          &co_localizations,
+                                                                          &multi_trace,
+                                                                          &ana_kind);
                             let elapsed_nfa_ana = now.elapsed();
+                            let nfa_states_num = nfa.transitions.len();
+                            let exit_code = analysis_verdict_exit_code(&ana_result.verdict);
 
                             let mut ret_print = vec![];
                             // ***
                             ret_print.push( "".to_string());
-                            ret_print.push( "Trace analysis using NFA monitor".to_string());
-                            ret_print.push( format!("from interaction from file '{}'",hsf_file_path) );
-                            ret_print.push( format!("and trace from file '{}'",htf_file_path) );
+                            ret_print.push( "Multi-trace analysis using NFA".to_string());
+                            ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                            ret_print.push( format!("and multi-trace from file '{}'",htf_file_path) );
+                            ret_print.push( format!("with {:?} components", co_localizations.num_colocs()) );
+                            if !is_nfa_complete {
+                                ret_print.push( format!("WARNING : more than {:?} distinct interaction terms are reachable, the NFA only approximates the interaction", DEFAULT_NFA_MAX_STATES_NUM) );
+                            }
                             ret_print.push( format!("of length '{:?}'", multi_trace_length) );
                             ret_print.push( "".to_string());
                             ret_print.push( format!("time to transform int to nfa : '{:?}'",elapsed_get_nfa.as_secs_f64()) );
+                            ret_print.push( format!("time of analysis using nfa : '{:?}'",elapsed_nfa_ana.as_secs_f64()) );
+                            ret_print.push( format!("analysis kind : '{:}'", ana_kind) );
+                            ret_print.push( format!("explored configurations : {:?}", ana_result.configurations_num) );
+                            if let Some(coloc_id) = ana_result.rejecting_component {
+                                ret_print.push( format!("component {:?} is not compatible with the projection of the nfa on its lifelines", coloc_id) );
+                            }
+                            ret_print.push( format!("verdict : '{:}'",ana_result.verdict) );
+                            // ***
                             if matches.is_present("draw_analysis") {
-                                ret_print.push( format!("time of analysis using nfa : '{:?}' (slowed by graphic representation)",elapsed_nfa_ana.as_secs_f64()) );
-                            } else {
-                                ret_print.push( format!("time of analysis using nfa : '{:?}'",elapsed_nfa_ana.as_secs_f64()) );
+                                if co_localizations.locs_lf_ids.len() == 1 {
+                                    let trace = multi_trace.get(0).unwrap();
+                                    draw_global_trace_nfa_analysis(nfa,printer,trace,file_name);
+                                } else {
+                                    ret_print.push( "the graphical representation of the analysis is only available for global traces".to_string() );
+                                }
                             }
-                            ret_print.push( format!("verdict : '{:}'",verdict) );
                             // ***
-                            return (ret_print,0);
+                            if let HibouOutputFormat::Json = HibouOutputFormat::from_matches(matches) {
+                                let rejecting_json = match ana_result.rejecting_component {
+                                    None => {"null".to_string()},
+                                    Some(coloc_id) => {coloc_id.to_string()}
+                                };
+                                let options_json = json_object(vec![("kind",json_string(&ana_kind.to_string())),
+                                                                    ("max_nfa_states",DEFAULT_NFA_MAX_STATES_NUM.to_string())]);
+                                let elapsed = elapsed_get_nfa.as_secs_f64() + elapsed_nfa_ana.as_secs_f64();
+                                let stats_json = json_object(vec![("nodes",ana_result.configurations_num.to_string()),
+                                                                  ("nfa_states",nfa_states_num.to_string()),
+                                                                  ("nfa_complete",is_nfa_complete.to_string()),
+                                                                  ("nfa_construction_elapsed",format!("{:?}", elapsed_get_nfa.as_secs_f64())),
+                                                                  ("analysis_elapsed",format!("{:?}", elapsed_nfa_ana.as_secs_f64())),
+                                                                  ("elapsed",format!("{:?}", elapsed))]);
+                                let json = json_object(vec![("command",json_string("nfa_analyze")),
+                                                            ("interaction",json_string(hif_file_path)),
+                                                            ("trace",json_string(htf_file_path)),
+                                                            ("trace_length",multi_trace_length.to_string()),
+                                                            ("verdict",json_string(&ana_result.verdict.to_string())),
+                                                            ("rejecting_component",rejecting_json),
+                                                            ("options",options_json),
+                                                            ("statistics",stats_json)]);
+                                return (vec![json],exit_code);
+                            }
+                            return (ret_print,exit_code);
                         }
                     }
                 }
@@ -0,0 +1,137 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::language::morphism::projection::project_interaction;
+use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_SIGNATURE_FILE_EXTENSION};
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+use crate::io::output::to_hfiles::signature::to_hsf::signature_to_hsf;
+use crate::nfa_translation::canonize::canonize_interaction;
+use crate::process::canon::param::default::DefaultCanonizationProcess;
+
+
+// comma-separated names given on the command line
+fn get_names_from_arg(matches : &ArgMatches, arg_name : &str) -> Vec<String> {
+    match matches.value_of(arg_name) {
+        None => {
+            return vec![];
+        },
+        Some( as_str ) => {
+            return as_str.split(",").map(|name| name.trim().to_string()).filter(|name| !name.is_empty()).collect();
+        }
+    }
+}
+
+pub fn cli_project(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let hif_file_path = matches.value_of("hif").unwrap();
+    let int = match parse_hif_file(&gen_ctx,hif_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let mut lfs_to_remove = BTreeSet::new();
+    for lf_name in get_names_from_arg(matches,"remove") {
+        match gen_ctx.get_lf_id(&lf_name) {
+            None => {
+                return (vec![format!("unknown lifeline '{}'", lf_name)],1);
+            },
+            Some( lf_id ) => {
+                lfs_to_remove.insert(lf_id);
+            }
+        }
+    }
+    let mut messages : HashMap<usize,Option<String>> = HashMap::new();
+    for ms_name in get_names_from_arg(matches,"hide") {
+        match gen_ctx.get_ms_id(&ms_name) {
+            None => {
+                return (vec![format!("unknown message '{}'", ms_name)],1);
+            },
+            Some( ms_id ) => {
+                messages.insert(ms_id,None);
+            }
+        }
+    }
+    for renaming in get_names_from_arg(matches,"rename") {
+        let (ms_name,new_name) = match renaming.split_once("=") {
+            None => {
+                return (vec![format!("renaming '{}' is not of the form 'old=new'", renaming)],1);
+            },
+            Some( (old,new) ) => {
+                (old.trim(),new.trim())
+            }
+        };
+        match gen_ctx.get_ms_id(ms_name) {
+            None => {
+                return (vec![format!("unknown message '{}'", ms_name)],1);
+            },
+            Some( ms_id ) => {
+                if messages.contains_key(&ms_id) {
+                    return (vec![format!("message '{}' is both hidden and renamed", ms_name)],1);
+                }
+                messages.insert(ms_id,Some(new_name.to_string()));
+            }
+        }
+    }
+    let output_name = match matches.value_of("output") {
+        None => {
+            let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+            format!("{:}_proj", file_name)
+        },
+        Some( got ) => {
+            got.to_string()
+        }
+    };
+    // ***
+    let (new_ctx,projected) = project_interaction(&gen_ctx,&int,&lfs_to_remove,&messages);
+    // the actions which were removed leave empty interactions
+    let projected = canonize_interaction(&new_ctx,&projected,DefaultCanonizationProcess::Basic,false);
+    let hsf_name = format!("{:}.{:}", output_name, HIBOU_SIGNATURE_FILE_EXTENSION);
+    let hif_name = format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION);
+    signature_to_hsf(Path::new(&hsf_name),&new_ctx);
+    interaction_to_hif(Path::new(&hif_name),&new_ctx,&projected);
+    // ***
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "PROJECTING INTERACTION".to_string());
+    ret_print.push( format!("from file '{}'",hif_file_path) );
+    ret_print.push( "".to_string());
+    ret_print.push( format!("{:} lifeline(s) removed, {:} remaining", lfs_to_remove.len(), new_ctx.get_lf_num()) );
+    ret_print.push( format!("{:} message(s) hidden, {:} renamed",
+                            messages.values().filter(|new_name| new_name.is_none()).count(),
+                            messages.values().filter(|new_name| new_name.is_some()).count()) );
+    ret_print.push( format!("wrote to files '{}' and '{}'", hsf_name, hif_name) );
+    ret_print.push( "".to_string());
+    return (ret_print,0);
+}
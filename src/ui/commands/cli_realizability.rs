@@ -0,0 +1,175 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Instant;
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_json::util::{json_array, json_object, json_string};
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::nfa_translation::realizability::channels::AsyncChannels;
+use crate::nfa_translation::realizability::check::check_realizability;
+use crate::nfa_translation::realizability::composition::DEFAULT_MAX_BUFFER_SIZE;
+use crate::nfa_translation::realizability::local::{get_lifelines_local_interactions, get_local_dfa};
+use crate::process::explo::loggers::nfait::printer::ActionNFAITPrinter;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+use crate::ui::util::output::{analysis_verdict_exit_code, HibouOutputFormat};
+
+
+pub fn cli_realizability(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let max_buffer_size : u32 = match matches.value_of("max_buffer_size") {
+                        None => {
+                            DEFAULT_MAX_BUFFER_SIZE
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let channels = match AsyncChannels::from_interaction(&gen_ctx,&int) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let now = Instant::now();
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    let (int_nfa,is_nfa_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int,&alphabet,matches.value_of("nfa"),max_states) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let mut local_dfas : Vec<AutDFA<usize>> = vec![];
+                    let mut are_local_nfas_complete = true;
+                    for local_int in get_lifelines_local_interactions(&gen_ctx,&int) {
+                        match get_interaction_nfa_from_arg(&gen_ctx,&local_int,&alphabet,matches.value_of("nfa"),max_states) {
+                            Err(e) => {
+                                return (vec![e],1);
+                            },
+                            Ok( (local_nfa,is_local_nfa_complete) ) => {
+                                are_local_nfas_complete = are_local_nfas_complete && is_local_nfa_complete;
+                                local_dfas.push(get_local_dfa(local_nfa));
+                            }
+                        }
+                    }
+                    let report = check_realizability(&int_nfa,&local_dfas,&alphabet,&channels,max_buffer_size,max_states);
+                    let elapsed_time = now.elapsed();
+                    let verdict = report.get_verdict();
+                    let exit_code = analysis_verdict_exit_code(&verdict);
+                    let printer = ActionNFAITPrinter::new(alphabet,gen_ctx.clone());
+                    let counterexamples = vec![("implied_scenario",&report.implied_scenario),
+                                               ("deadlock",&report.deadlock),
+                                               ("missing_trace",&report.missing_trace)];
+                    // ***
+                    if let HibouOutputFormat::Json = HibouOutputFormat::from_matches(matches) {
+                        let local_states : Vec<String> = local_dfas.iter().map(|x| x.transitions.len().to_string()).collect();
+                        let mut fields = vec![("command",json_string("realizability")),
+                                              ("interaction",json_string(hif_file_path)),
+                                              ("verdict",json_string(&verdict.to_string())),
+                                              ("options",json_object(vec![("nfa",json_string(matches.value_of("nfa").unwrap_or("exploration"))),
+                                                                          ("max_states",max_states.to_string()),
+                                                                          ("max_buffer_size",max_buffer_size.to_string())])),
+                                              ("statistics",json_object(vec![("local_states",json_array(local_states)),
+                                                                             ("configurations",report.num_configurations.to_string()),
+                                                                             ("is_nfa_complete",(is_nfa_complete && are_local_nfas_complete).to_string()),
+                                                                             ("is_composition_complete",report.is_complete.to_string()),
+                                                                             ("elapsed",format!("{:?}", elapsed_time.as_secs_f64()))]))];
+                        for (name,counterexample) in counterexamples {
+                            match counterexample {
+                                None => {
+                                    fields.push((name,"null".to_string()));
+                                },
+                                Some( word ) => {
+                                    let word_as_strings : Vec<String> = word.iter().map(|x| json_string(&printer.get_letter_string_repr(x))).collect();
+                                    fields.push((name,json_array(word_as_strings)));
+                                }
+                            }
+                        }
+                        return (vec![json_object(fields)],exit_code);
+                    }
+                    let mut ret_print = vec![];
+                    ret_print.push( "REALIZABILITY BY LIFELINE PROJECTIONS".to_string());
+                    ret_print.push( format!("of interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("with buffers of at most {:} messages", max_buffer_size) );
+                    ret_print.push( "".to_string());
+                    for (lf_id,dfa) in local_dfas.iter().enumerate() {
+                        ret_print.push( format!("local automaton of '{:}' : {:} states", gen_ctx.get_lf_name(lf_id).unwrap(), dfa.transitions.len()) );
+                    }
+                    ret_print.push( format!("composition : {:} configurations", report.num_configurations) );
+                    ret_print.push( "".to_string());
+                    if !(is_nfa_complete && are_local_nfas_complete) {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states", max_states) );
+                    }
+                    if !report.is_complete {
+                        ret_print.push( "WARNING : the composition was only partially built (bounded buffers or too many configurations)".to_string() );
+                    }
+                    for (name,counterexample) in counterexamples {
+                        if let Some(word) = counterexample {
+                            let word_as_strings : Vec<String> = word.iter().map(|x| printer.get_letter_string_repr(x)).collect();
+                            let trace_as_string = if word.is_empty() {"the empty trace".to_string()} else {format!("'{:}'", word_as_strings.join("."))};
+                            let description = match name {
+                                "implied_scenario" => {
+                                    "implied scenario (accepted by the composition and not by the interaction)"
+                                },
+                                "deadlock" => {
+                                    "deadlock (the composition can neither terminate nor continue)"
+                                },
+                                _ => {
+                                    "missing trace (accepted by the interaction and not by the composition)"
+                                }
+                            };
+                            ret_print.push( format!("{:} : {:}", description, trace_as_string) );
+                        }
+                    }
+                    ret_print.push( format!("verdict : {}", verdict) );
+                    ret_print.push( "".to_string());
+                    return (ret_print,exit_code);
+                }
+            }
+        }
+    }
+}
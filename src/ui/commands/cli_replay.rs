@@ -0,0 +1,112 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::execution::interpretation::replay::replay_witness;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hwf::interface::parse_hwf_file;
+use crate::io::output::draw_interactions::highlighting::SequenceDiagramHighlighting;
+use crate::io::output::draw_interactions::interface::draw_highlighted_interaction_as_sd;
+use crate::io::output::to_hfiles::witness::witness::position_as_hwf_encoding;
+
+
+pub fn cli_replay(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let hwf_file_path = matches.value_of("hwf").unwrap();
+                    match parse_hwf_file(&gen_ctx,hwf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( witness ) => {
+                            let mut ret_print = vec![];
+                            ret_print.push( "REPLAYING WITNESS".to_string());
+                            ret_print.push( format!("from file '{}'",hwf_file_path) );
+                            ret_print.push( format!("of length '{:?}'", witness.steps.len()) );
+                            ret_print.push( "ON INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( "".to_string());
+                            // ***
+                            match replay_witness(&int,&witness) {
+                                Err(e) => {
+                                    ret_print.push( format!("invalid witness : {:}", e) );
+                                    return (ret_print,1);
+                                },
+                                Ok( replay ) => {
+                                    ret_print.push( "valid witness".to_string() );
+                                    if replay.is_accepting() {
+                                        ret_print.push( "the reached interaction accepts the empty behavior".to_string() );
+                                    } else {
+                                        ret_print.push( "WARNING : the reached interaction does not accept the empty behavior".to_string() );
+                                    }
+                                    ret_print.push( "".to_string());
+                                    for (alt_pos,num_left,num_right) in &replay.interpretation.alts {
+                                        let choice = match (*num_left > 0, *num_right > 0) {
+                                            (false,false) => "none",
+                                            (true,false) => "left",
+                                            (false,true) => "right",
+                                            (true,true) => "both"
+                                        };
+                                        ret_print.push( format!("alt at position {:} : {:} branch ({:} left / {:} right executions)",
+                                                                position_as_hwf_encoding(alt_pos),
+                                                                choice,
+                                                                num_left,
+                                                                num_right) );
+                                    }
+                                    for (loop_pos,num_instances) in &replay.interpretation.loops {
+                                        ret_print.push( format!("loop at position {:} : {:} instance(s)",
+                                                                position_as_hwf_encoding(loop_pos),
+                                                                num_instances) );
+                                    }
+                                    // ***
+                                    let output_file_name : String;
+                                    if matches.is_present("output") {
+                                        let extracted = matches.value_of("output").unwrap();
+                                        output_file_name = extracted.to_string();
+                                    } else {
+                                        let file_name = Path::new(hwf_file_path).file_stem().unwrap().to_str().unwrap();
+                                        output_file_name = format!("{}_replay", file_name);
+                                    }
+                                    let highlighting = SequenceDiagramHighlighting::new_from_interpretation(&replay.interpretation);
+                                    draw_highlighted_interaction_as_sd(&gen_ctx, &int, &highlighting, &"".to_string(), &output_file_name);
+                                    ret_print.push( "".to_string());
+                                    ret_print.push( format!("annotated diagram drawn on file : {}",output_file_name) );
+                                    ret_print.push( "".to_string());
+                                    return (ret_print,0);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
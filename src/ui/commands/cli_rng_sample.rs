@@ -0,0 +1,126 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::count::counter::AcceptedTracesCounter;
+use crate::nfa_translation::multitrace_ana::nfa::DEFAULT_NFA_MAX_STATES_NUM;
+use crate::trace_manip::uniform_sampling::generate::generate_uniform_traces;
+use crate::ui::util::colocs::parse_co_localizations_arg;
+use crate::ui::util::nfa::get_interaction_nfa_from_arg;
+
+
+pub fn cli_rng_sample(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations = match parse_co_localizations_arg(&gen_ctx,matches.value_of("partition")) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let length : usize = matches.value_of("length").unwrap().trim().parse::<usize>().unwrap();
+                    let num_traces : u32 = match matches.value_of("num_traces") {
+                        None => {
+                            10
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let seed : u64 = match matches.value_of("seed") {
+                        None => {
+                            0
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u64>().unwrap()
+                        }
+                    };
+                    let max_states : usize = match matches.value_of("max_states") {
+                        None => {
+                            DEFAULT_NFA_MAX_STATES_NUM
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<usize>().unwrap()
+                        }
+                    };
+                    let int_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let output_folder : String = if matches.is_present("folder") {
+                        matches.value_of("folder").unwrap().to_string()
+                    } else {
+                        format!("{:}_samples", int_name)
+                    };
+                    // ***
+                    let alphabet = get_alphabet_from_gen_ctx(&gen_ctx);
+                    let (nfa,is_nfa_complete) = match get_interaction_nfa_from_arg(&gen_ctx,&int,&alphabet,matches.value_of("nfa"),max_states) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let mut counter = AcceptedTracesCounter::new(&nfa);
+                    let accepted_num = counter.count_accepted_traces(length);
+                    let written = generate_uniform_traces(&gen_ctx,
+                                                          &mut counter,
+                                                          &alphabet,
+                                                          &co_localizations,
+                                                          length,
+                                                          num_traces,
+                                                          seed,
+                                                          &output_folder,
+                                                          int_name);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "SAMPLING ACCEPTED TRACES UNIFORMLY".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("length : {:}, num_traces : {:}, seed : {:}", length, num_traces, seed) );
+                    if !is_nfa_complete {
+                        ret_print.push( format!("WARNING : the NFA construction was stopped after {:} states, only part of the accepted traces can be drawn", max_states) );
+                    }
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("drawn among {:} accepted traces of length {:}", accepted_num, length) );
+                    ret_print.push( format!("wrote {:} multi-traces in folder '{:}'", written, output_folder) );
+                    ret_print.push( "".to_string());
+                    if written == 0 && num_traces > 0 {
+                        return (ret_print,1);
+                    }
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::trace_manip::random_walk::generate::generate_random_walks;
+use crate::trace_manip::random_walk::weights::RandomWalkWeights;
+use crate::ui::util::colocs::parse_co_localizations_arg;
+
+
+fn get_weight(matches : &ArgMatches, name : &str) -> f32 {
+    match matches.value_of(name) {
+        None => {
+            1.0
+        },
+        Some( as_str ) => {
+            as_str.trim().parse::<f32>().unwrap()
+        }
+    }
+}
+
+pub fn cli_rng_walk(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations = match parse_co_localizations_arg(&gen_ctx,matches.value_of("partition")) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let weights = match RandomWalkWeights::new(get_weight(matches,"emission"),
+                                                               get_weight(matches,"reception"),
+                                                               get_weight(matches,"loop"),
+                                                               get_weight(matches,"alt_left"),
+                                                               get_weight(matches,"alt_right"),
+                                                               get_weight(matches,"stop")) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let num_walks : u32 = match matches.value_of("num_walks") {
+                        None => {
+                            10
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let max_length : u32 = match matches.value_of("max_length") {
+                        None => {
+                            50
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u32>().unwrap()
+                        }
+                    };
+                    let seed : u64 = match matches.value_of("seed") {
+                        None => {
+                            0
+                        },
+                        Some( as_str ) => {
+                            as_str.trim().parse::<u64>().unwrap()
+                        }
+                    };
+                    let only_accepted = matches.is_present("accepted");
+                    let int_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                    let output_folder : String = if matches.is_present("folder") {
+                        matches.value_of("folder").unwrap().to_string()
+                    } else {
+                        format!("{:}_walks", int_name)
+                    };
+                    // ***
+                    let (written,discarded) = match generate_random_walks(&gen_ctx,
+                                                                    &int,
+                                                                    &co_localizations,
+                                                                    &weights,
+                                                                    num_walks,
+                                                                    max_length,
+                                                                    seed,
+                                                                    only_accepted,
+                                                                    &output_folder,
+                                                                    int_name) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "GENERATING MULTI-TRACES BY RANDOM WALKS".to_string());
+                    ret_print.push( format!("from interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("num_walks : {:}, max_length : {:}, seed : {:}", num_walks, max_length, seed) );
+                    ret_print.push( format!("weights : {:}", weights) );
+                    ret_print.push( "".to_string());
+                    ret_print.push( format!("wrote {:} multi-traces in folder '{:}'", written, output_folder) );
+                    if only_accepted {
+                        ret_print.push( format!("discarded {:} walks that did not end on an accepting interaction", discarded) );
+                    }
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::language::unroll::unrollable::LoopUnrollable;
+use crate::io::file_extensions::HIBOU_INTERACTION_FILE_EXTENSION;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+
+
+pub fn cli_unroll(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    let gen_ctx = match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let hif_file_path = matches.value_of("hif").unwrap();
+    let int = match parse_hif_file(&gen_ctx,hif_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( got ) => {
+            got
+        }
+    };
+    let num_iterations : u32 = match matches.value_of("iterations") {
+        None => {
+            1
+        },
+        Some( as_str ) => {
+            as_str.trim().parse::<u32>().unwrap()
+        }
+    };
+    let output_name = match matches.value_of("output") {
+        None => {
+            let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+            format!("{:}_unrolled", file_name)
+        },
+        Some( got ) => {
+            got.to_string()
+        }
+    };
+    // ***
+    let unrolled = int.unroll_loops(num_iterations);
+    let file_name = format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION);
+    interaction_to_hif(Path::new(&file_name),&gen_ctx,&unrolled);
+    // ***
+    let mut ret_print = vec![];
+    ret_print.push( "".to_string());
+    ret_print.push( "UNROLLING LOOPS of INTERACTION".to_string());
+    ret_print.push( format!("from file '{}'",hif_file_path) );
+    ret_print.push( format!("with at most {:} iteration(s) per loop", num_iterations) );
+    ret_print.push( "".to_string());
+    ret_print.push( format!("{:} loop(s) unrolled", int.total_loop_num()) );
+    if int.unrolling_over_approximates(num_iterations) {
+        ret_print.push( "WARNING : the unrolled interaction over-approximates the bounded language given that the iterations of \"loopH\" may overlap".to_string() );
+    }
+    ret_print.push( format!("wrote to file '{}'", file_name) );
+    ret_print.push( "".to_string());
+    return (ret_print,0);
+}
@@ -18,9 +18,15 @@ pub mod cli_analyze;
 pub mod cli_nfa_ana;
 pub mod cli_nfa_experiment;
 pub mod cli_canonize;
+pub mod cli_certify_equiv;
+pub mod cli_check_certificate;
+pub mod cli_merge;
+pub mod cli_minimize;
+pub mod cli_unroll;
+pub mod cli_int_diff;
+pub mod cli_project;
 pub mod cli_draw;
 pub mod cli_explore;
-pub mod cli_puml_ap;
 pub mod cli_puml_sd;
 pub mod cli_slice;
 pub mod cli_mutate_insert_noise;
@@ -31,6 +37,19 @@ pub mod cli_glosem;
 pub mod cli_nfa_experiment2;
 pub mod cli_get_metrics;
 pub mod cli_rng_gen_interactions;
+pub mod cli_replay;
+pub mod cli_coverage;
+pub mod cli_ana_benchmark;
+pub mod cli_rng_walk;
+pub mod cli_gen_tests;
+pub mod cli_gen_negatives;
+pub mod cli_count_traces;
+pub mod cli_rng_sample;
+pub mod cli_int_to_regex;
+pub mod cli_lang_compare;
+pub mod cli_realizability;
+pub mod cli_cfsm;
+pub mod cli_gen_monitor;
 //pub mod cli_term_repr;
 
 
@@ -19,6 +19,24 @@ use clap::App;
 
 use crate::ui::commands::cli_analyze::cli_analyze;
 use crate::ui::commands::cli_canonize::cli_canonize;
+use crate::ui::commands::cli_certify_equiv::cli_certify_equiv;
+use crate::ui::commands::cli_check_certificate::cli_check_certificate;
+use crate::ui::commands::cli_merge::cli_merge;
+use crate::ui::commands::cli_minimize::cli_minimize;
+use crate::ui::commands::cli_unroll::cli_unroll;
+use crate::ui::commands::cli_int_diff::cli_int_diff;
+use crate::ui::commands::cli_project::cli_project;
+use crate::ui::commands::cli_coverage::cli_coverage;
+use crate::ui::commands::cli_ana_benchmark::cli_ana_benchmark;
+use crate::ui::commands::cli_rng_walk::cli_rng_walk;
+use crate::ui::commands::cli_gen_tests::cli_gen_tests;
+use crate::ui::commands::cli_gen_negatives::cli_gen_negatives;
+use crate::ui::commands::cli_count_traces::cli_count_traces;
+use crate::ui::commands::cli_rng_sample::cli_rng_sample;
+use crate::ui::commands::cli_int_to_regex::cli_int_to_regex;
+use crate::ui::commands::cli_lang_compare::{cli_equiv, cli_includes};
+use crate::ui::commands::cli_realizability::cli_realizability;
+use crate::ui::commands::cli_gen_monitor::cli_gen_monitor;
 use crate::ui::commands::cli_draw::cli_draw;
 use crate::ui::commands::cli_explore::cli_explore;
 use crate::ui::commands::cli_get_metrics::cli_get_metrics;
@@ -30,10 +48,12 @@ use crate::ui::commands::cli_mutate_swap_components::cli_mutate_swap_components;
 use crate::ui::commands::cli_nfa_ana::cli_nfa_ana;
 use crate::ui::commands::cli_nfa_experiment2::cli_nfa_experiment2;
 use crate::ui::commands::cli_nfa_experiment::cli_nfa_experiment;
-use crate::ui::commands::cli_puml_ap::cli_puml_ap;
+use crate::ui::commands::cli_cfsm::cli_cfsm;
 use crate::ui::commands::cli_puml_sd::cli_puml_sd;
+use crate::ui::commands::cli_replay::cli_replay;
 use crate::ui::commands::cli_rng_gen_interactions::cli_rng_gen_interactions;
 use crate::ui::commands::cli_slice::cli_slice;
+use crate::ui::util::output::{get_json_output_lines, HibouOutputFormat};
 use crate::ui::util::printing::print_on_hibou_cli;
 
 pub fn hibou_cli() -> i32 {
@@ -43,6 +63,7 @@ pub fn hibou_cli() -> i32 {
 
     let mut ret_print : Vec<String> = vec![];
     let mut ret_code : u32 = 1;
+    let mut output_format = HibouOutputFormat::Text;
 
     if let Some(matches) = matches.subcommand_matches("draw") {
         let mut got = cli_draw(matches);
@@ -52,8 +73,8 @@ pub fn hibou_cli() -> i32 {
         let mut got = cli_puml_sd(matches);
         ret_print = got.0;
         ret_code = got.1;
-    } else if let Some(matches) = matches.subcommand_matches("puml_ap") {
-        let mut got = cli_puml_ap(matches);
+    } else if let Some(matches) = matches.subcommand_matches("cfsm") {
+        let mut got = cli_cfsm(matches);
         ret_print = got.0;
         ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("glosem") {
@@ -73,10 +94,12 @@ pub fn hibou_cli() -> i32 {
         ret_print = got.0;
         ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("get_metrics") {
+        output_format = HibouOutputFormat::from_matches(matches);
         let mut got = cli_get_metrics(matches);
         ret_print = got.0;
         ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("nfa_analyze") {
+        output_format = HibouOutputFormat::from_matches(matches);
         let mut got = cli_nfa_ana(matches);
         ret_print = got.0;
         ret_code = got.1;
@@ -87,17 +110,105 @@ pub fn hibou_cli() -> i32 {
     } else if let Some(matches) = matches.subcommand_matches("term_repr") {
 
     }*/ else if let Some(matches) = matches.subcommand_matches("explore") {
+        output_format = HibouOutputFormat::from_matches(matches);
         let mut got = cli_explore(matches);
         ret_print = got.0;
         ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("canonize") {
+        output_format = HibouOutputFormat::from_matches(matches);
         let mut got = cli_canonize(matches);
         ret_print = got.0;
         ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("certify_equiv") {
+        let mut got = cli_certify_equiv(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("check_certificate") {
+        let mut got = cli_check_certificate(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("merge") {
+        let mut got = cli_merge(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("minimize") {
+        let mut got = cli_minimize(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("unroll") {
+        let mut got = cli_unroll(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("int_diff") {
+        let mut got = cli_int_diff(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("project") {
+        let mut got = cli_project(matches);
+        ret_print = got.0;
+        ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("analyze") {
+        output_format = HibouOutputFormat::from_matches(matches);
         let mut got = cli_analyze(matches);
         ret_print = got.0;
         ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("replay") {
+        let mut got = cli_replay(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("coverage") {
+        let mut got = cli_coverage(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("ana_benchmark") {
+        let mut got = cli_ana_benchmark(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("rng_walk") {
+        let mut got = cli_rng_walk(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("gen_tests") {
+        let mut got = cli_gen_tests(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("gen_negatives") {
+        let mut got = cli_gen_negatives(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("count_traces") {
+        output_format = HibouOutputFormat::from_matches(matches);
+        let mut got = cli_count_traces(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("rng_sample") {
+        let mut got = cli_rng_sample(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("int_to_regex") {
+        output_format = HibouOutputFormat::from_matches(matches);
+        let mut got = cli_int_to_regex(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("equiv") {
+        output_format = HibouOutputFormat::from_matches(matches);
+        let mut got = cli_equiv(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("includes") {
+        output_format = HibouOutputFormat::from_matches(matches);
+        let mut got = cli_includes(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("realizability") {
+        output_format = HibouOutputFormat::from_matches(matches);
+        let mut got = cli_realizability(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("gen_monitor") {
+        let mut got = cli_gen_monitor(matches);
+        ret_print = got.0;
+        ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("slice") {
         let mut got = cli_slice(matches);
         ret_print = got.0;
@@ -123,7 +234,16 @@ pub fn hibou_cli() -> i32 {
         ret_code = 0
     }
     // ***
-    print_on_hibou_cli(ret_print);
-    return 0;
+    match output_format {
+        HibouOutputFormat::Text => {
+            print_on_hibou_cli(ret_print);
+        },
+        HibouOutputFormat::Json => {
+            for line in get_json_output_lines(ret_print,ret_code) {
+                println!("{}", line);
+            }
+        }
+    }
+    return ret_code as i32;
 }
 
@@ -0,0 +1,71 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::input::error::HibouParsingError;
+
+
+/**
+ * Co-localizations given on the command line:
+ * - "trivial" (the default) for a single component containing all lifelines
+ * - "discrete" for one component per lifeline
+ * - otherwise components separated by ';', each containing lifeline names separated by ',' (e.g. "a,b;c")
+ **/
+pub fn parse_co_localizations_arg(gen_ctx : &GeneralContext,
+                                  arg : Option<&str>) -> Result<CoLocalizations,HibouParsingError> {
+    match arg {
+        None | Some("trivial") => {
+            Ok(CoLocalizations::get_trivial_partition(gen_ctx.get_lf_num()))
+        },
+        Some("discrete") => {
+            Ok(CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num()))
+        },
+        Some(specific) => {
+            let mut colocs : Vec<BTreeSet<usize>> = vec![];
+            let mut got_lfs = hashset!{};
+            for coloc_str in specific.split(';') {
+                let mut coloc = btreeset!{};
+                for lf_name in coloc_str.split(',') {
+                    let lf_name : String = lf_name.chars().filter(|c| !c.is_whitespace()).collect();
+                    match gen_ctx.get_lf_id(&lf_name) {
+                        None => {
+                            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name) );
+                        },
+                        Some(lf_id) => {
+                            if got_lfs.contains(&lf_id) {
+                                return Err( HibouParsingError::NonDisjointTraceComponents );
+                            }
+                            got_lfs.insert(lf_id);
+                            coloc.insert(lf_id);
+                        }
+                    }
+                }
+                colocs.push(coloc);
+            }
+            for lf_id in 0..gen_ctx.get_lf_num() {
+                if !got_lfs.contains(&lf_id) {
+                    return Err( HibouParsingError::IllDefinedTraceComponents(
+                        format!("lifeline {:} is not in any co-localization", gen_ctx.get_lf_name(lf_id).unwrap())) );
+                }
+            }
+            Ok(CoLocalizations::new(colocs))
+        }
+    }
+}
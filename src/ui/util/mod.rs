@@ -15,4 +15,7 @@ limitations under the License.
 */
 
 
-pub mod printing;
\ No newline at end of file
+pub mod printing;pub mod output;
+
+pub mod colocs;
+pub mod nfa;
\ No newline at end of file
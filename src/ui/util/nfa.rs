@@ -0,0 +1,53 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::nfa_translation::get_nfa_from_logger::get_nfa_from_interaction_exploration;
+use crate::nfa_translation::multitrace_ana::nfa::get_nfa_from_interaction_terms;
+
+
+/**
+ * NFA construction given on the command line:
+ * - "exploration" (the default) from the exploration of the semantics of the interaction
+ * - "terms" from the interaction terms, with at most "max_states" states
+ * Also returns whether or not the construction is complete.
+ **/
+pub fn get_interaction_nfa_from_arg(gen_ctx : &GeneralContext,
+                                    int : &Interaction,
+                                    alphabet : &Vec<BTreeSet<TraceAction>>,
+                                    arg : Option<&str>,
+                                    max_states : usize) -> Result<(AutNFA<usize>,bool),String> {
+    match arg {
+        None | Some("exploration") => {
+            let (nfa,_) = get_nfa_from_interaction_exploration(gen_ctx,int,alphabet.clone());
+            Ok((nfa,true))
+        },
+        Some("terms") => {
+            Ok(get_nfa_from_interaction_terms(int,alphabet,max_states))
+        },
+        Some(other) => {
+            Err(format!("unknown NFA construction '{:}'", other))
+        }
+    }
+}
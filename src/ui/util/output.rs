@@ -0,0 +1,111 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use clap::ArgMatches;
+
+use crate::io::output::to_json::util::{json_object, json_string};
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+pub enum HibouOutputFormat {
+    Text,
+    Json
+}
+
+impl HibouOutputFormat {
+    pub fn from_matches(matches : &ArgMatches) -> HibouOutputFormat {
+        match matches.value_of("format") {
+            Some("json") => {
+                HibouOutputFormat::Json
+            },
+            _ => {
+                HibouOutputFormat::Text
+            }
+        }
+    }
+}
+
+/**
+ * Exit codes of the hibou executable :
+ * 0 : success (and Pass verdict for an analysis)
+ * 1 : error (e.g. while parsing the input files)
+ * 2 : WeakPass
+ * 3 : Inconc
+ * 4 : WeakFail
+ * 5 : Fail
+ **/
+pub const HIBOU_EXIT_CODE_ERROR : u32 = 1;
+
+pub fn analysis_verdict_exit_code(verdict : &AnalysisGlobalVerdict) -> u32 {
+    match verdict {
+        AnalysisGlobalVerdict::Pass => {
+            0
+        },
+        AnalysisGlobalVerdict::WeakPass => {
+            2
+        },
+        AnalysisGlobalVerdict::Inconc(_) => {
+            3
+        },
+        AnalysisGlobalVerdict::WeakFail => {
+            4
+        },
+        AnalysisGlobalVerdict::Fail => {
+            5
+        }
+    }
+}
+
+/**
+ * In the JSON output format, the commands print a single JSON object
+ * except when an error occurred, in which case the error message is wrapped in an "error" field.
+ **/
+pub fn get_json_output_lines(ret_print : Vec<String>, ret_code : u32) -> Vec<String> {
+    if ret_code == HIBOU_EXIT_CODE_ERROR {
+        return vec![json_object(vec![("error",json_string(&ret_print.join("\n")))])];
+    } else {
+        return ret_print;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::process::ana::verdict::inconc::InconcReason;
+    use super::*;
+
+    #[test]
+    fn verdict_exit_codes_test() {
+        assert_eq!(analysis_verdict_exit_code(&AnalysisGlobalVerdict::Pass),0);
+        assert_eq!(analysis_verdict_exit_code(&AnalysisGlobalVerdict::WeakPass),2);
+        assert_eq!(analysis_verdict_exit_code(&AnalysisGlobalVerdict::Inconc(InconcReason::FilteredNodes)),3);
+        assert_eq!(analysis_verdict_exit_code(&AnalysisGlobalVerdict::WeakFail),4);
+        assert_eq!(analysis_verdict_exit_code(&AnalysisGlobalVerdict::Fail),5);
+        assert_eq!(HIBOU_EXIT_CODE_ERROR,1);
+    }
+
+    #[test]
+    fn json_error_output_test() {
+        let error_print = vec!["file not found".to_string(),"\"int.hif\"".to_string()];
+        assert_eq!(get_json_output_lines(error_print,HIBOU_EXIT_CODE_ERROR),
+                   vec!["{\"error\":\"file not found\\n\\\"int.hif\\\"\"}".to_string()]);
+        // the JSON object printed by a command is left as is whatever its verdict
+        let json_print = vec!["{\"command\":\"analyze\",\"verdict\":\"Fail\"}".to_string()];
+        assert_eq!(get_json_output_lines(json_print.clone(),5),json_print);
+    }
+
+}